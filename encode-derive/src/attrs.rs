@@ -0,0 +1,185 @@
+use proc_macro2::Span;
+use proc_macro2::TokenStream;
+use quote::quote;
+use quote::ToTokens;
+use syn::Attribute;
+use syn::Expr;
+use syn::Path;
+use syn::Type;
+
+/// Attributes that apply to the whole struct or enum.
+#[derive(Default)]
+pub struct ContainerAttrs {
+    /// `#[encode(error = Type)]`
+    pub error: Option<Type>,
+    /// `#[encode(tag = Type)]`
+    pub tag: Option<Type>,
+}
+
+/// Attributes that apply to an enum variant.
+#[derive(Default)]
+pub struct VariantAttrs {
+    /// `#[encode(tag = expr)]`
+    pub tag: Option<Expr>,
+}
+
+/// The byte order of a field.
+#[derive(Clone, Copy)]
+pub enum ByteOrder {
+    /// `#[encode(le)]`
+    Little,
+    /// `#[encode(be)]`
+    Big,
+}
+
+/// Attributes that apply to a field.
+#[derive(Default)]
+pub struct FieldAttrs {
+    /// `#[encode(le)]` or `#[encode(be)]`
+    pub order: Option<ByteOrder>,
+    /// `#[encode(length_prefix = Type)]`
+    pub length_prefix: Option<Type>,
+    /// `#[encode(skip)]`
+    pub skip: bool,
+    /// `#[encode(with = path)]`
+    pub with: Option<Path>,
+}
+
+fn set<T>(slot: &mut Option<T>, value: T, span: Span, name: &str) -> syn::Result<()> {
+    if slot.replace(value).is_some() {
+        return Err(syn::Error::new(
+            span,
+            format!("duplicate `{name}` attribute"),
+        ));
+    }
+    Ok(())
+}
+
+fn encode_attrs(attrs: &[Attribute]) -> impl Iterator<Item = &Attribute> {
+    attrs.iter().filter(|attr| attr.path().is_ident("encode"))
+}
+
+impl ContainerAttrs {
+    pub fn parse(attrs: &[Attribute]) -> syn::Result<Self> {
+        let mut result = Self::default();
+        for attr in encode_attrs(attrs) {
+            attr.parse_nested_meta(|meta| {
+                let span = meta
+                    .path
+                    .get_ident()
+                    .map_or_else(Span::call_site, syn::Ident::span);
+                if meta.path.is_ident("error") {
+                    set(&mut result.error, meta.value()?.parse()?, span, "error")
+                } else if meta.path.is_ident("tag") {
+                    set(&mut result.tag, meta.value()?.parse()?, span, "tag")
+                } else {
+                    Err(meta.error("unsupported container attribute, expected `error` or `tag`"))
+                }
+            })?;
+        }
+        Ok(result)
+    }
+}
+
+impl VariantAttrs {
+    pub fn parse(attrs: &[Attribute]) -> syn::Result<Self> {
+        let mut result = Self::default();
+        for attr in encode_attrs(attrs) {
+            attr.parse_nested_meta(|meta| {
+                let span = meta
+                    .path
+                    .get_ident()
+                    .map_or_else(Span::call_site, syn::Ident::span);
+                if meta.path.is_ident("tag") {
+                    set(&mut result.tag, meta.value()?.parse()?, span, "tag")
+                } else {
+                    Err(meta.error("unsupported variant attribute, expected `tag`"))
+                }
+            })?;
+        }
+        Ok(result)
+    }
+}
+
+impl FieldAttrs {
+    pub fn parse(attrs: &[Attribute]) -> syn::Result<Self> {
+        let mut result = Self::default();
+        for attr in encode_attrs(attrs) {
+            attr.parse_nested_meta(|meta| {
+                let span = meta.path.get_ident().map_or_else(Span::call_site, syn::Ident::span);
+                if meta.path.is_ident("le") {
+                    set(&mut result.order, ByteOrder::Little, span, "le/be")
+                } else if meta.path.is_ident("be") {
+                    set(&mut result.order, ByteOrder::Big, span, "le/be")
+                } else if meta.path.is_ident("length_prefix") {
+                    let ty = meta.value()?.parse()?;
+                    set(&mut result.length_prefix, ty, span, "length_prefix")
+                } else if meta.path.is_ident("skip") {
+                    if result.skip {
+                        return Err(meta.error("duplicate `skip` attribute"));
+                    }
+                    result.skip = true;
+                    Ok(())
+                } else if meta.path.is_ident("with") {
+                    set(&mut result.with, meta.value()?.parse()?, span, "with")
+                } else {
+                    Err(meta.error(
+                        "unsupported field attribute, expected one of `le`, `be`, `length_prefix`, `skip` or `with`",
+                    ))
+                }
+            })?;
+            let combined = result.order.is_some() || result.length_prefix.is_some();
+            if (result.skip && (combined || result.with.is_some()))
+                || (result.with.is_some() && combined)
+            {
+                return Err(syn::Error::new_spanned(
+                    attr,
+                    "`skip` and `with` cannot be combined with other attributes",
+                ));
+            }
+        }
+        Ok(result)
+    }
+}
+
+/// Primitive integers wider than a byte, which have no byte order on their
+/// own.
+const WIDE_INTEGERS: &[&str] = &["u16", "u32", "u64", "u128", "i16", "i32", "i64", "i128"];
+/// Primitive integers that fit in a single byte.
+const BYTE_INTEGERS: &[&str] = &["u8", "i8"];
+
+fn is_one_of(ty: &Type, names: &[&str]) -> bool {
+    match ty {
+        Type::Path(path) if path.qself.is_none() => path
+            .path
+            .get_ident()
+            .is_some_and(|ident| names.iter().any(|name| ident == name)),
+        _ => false,
+    }
+}
+
+/// Returns the type that is actually encoded for a length prefix or a tag.
+///
+/// Primitive integers wider than a byte are encoded in big-endian (network)
+/// order. Any other type is used as-is.
+pub fn encoded_integer(ty: &Type) -> TokenStream {
+    if is_one_of(ty, WIDE_INTEGERS) {
+        quote!(::encode::combinators::BE<#ty>)
+    } else {
+        ty.to_token_stream()
+    }
+}
+
+/// Builds an expression of type [`encoded_integer`] from the given value.
+pub fn encoded_integer_value(ty: &Type, value: &Expr) -> TokenStream {
+    if is_one_of(ty, BYTE_INTEGERS) {
+        quote!({
+            let tag: #ty = #value;
+            tag
+        })
+    } else if is_one_of(ty, WIDE_INTEGERS) {
+        quote!(::encode::combinators::BE::<#ty>::new(#value))
+    } else {
+        quote!(<#ty>::new(#value))
+    }
+}
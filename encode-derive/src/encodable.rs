@@ -0,0 +1,228 @@
+use proc_macro2::TokenStream;
+use quote::format_ident;
+use quote::quote;
+use quote::ToTokens;
+use syn::Data;
+use syn::DataEnum;
+use syn::DataStruct;
+use syn::DeriveInput;
+use syn::Fields;
+use syn::Type;
+
+use crate::attrs::encoded_integer;
+use crate::attrs::encoded_integer_value;
+use crate::attrs::ByteOrder;
+use crate::attrs::ContainerAttrs;
+use crate::attrs::FieldAttrs;
+use crate::attrs::VariantAttrs;
+
+/// State shared while generating the statements of a single impl.
+struct Context {
+    /// The error type of the generated impl.
+    error: TokenStream,
+    /// Bounds that must be added to the where clause of the generated impl.
+    bounds: Vec<TokenStream>,
+}
+
+impl Context {
+    /// Encodes a value of type `ty` and converts its error into the
+    /// container error.
+    fn encode(&mut self, ty: &TokenStream, value: &TokenStream) -> TokenStream {
+        let error = &self.error;
+        self.bounds.push(quote!(#ty: ::encode::Encodable<__E>));
+        self.bounds.push(quote!(
+            #error: ::core::convert::From<<#ty as ::encode::Encodable<__E>>::Error>
+        ));
+        quote!(<#ty as ::encode::Encodable<__E>>::encode(&#value, encoder)?;)
+    }
+
+    /// Generates the statement that encodes a field, given an expression that
+    /// evaluates to a reference to it.
+    fn field(&mut self, ty: &Type, attrs: &FieldAttrs, reference: &TokenStream) -> TokenStream {
+        if attrs.skip {
+            return TokenStream::new();
+        }
+        if let Some(with) = &attrs.with {
+            return quote!(#with(#reference, encoder)?;);
+        }
+
+        let (ty, value) = match attrs.order {
+            Some(ByteOrder::Little) => (
+                quote!(::encode::combinators::LE<#ty>),
+                quote!(::encode::combinators::LE::new(*#reference)),
+            ),
+            Some(ByteOrder::Big) => (
+                quote!(::encode::combinators::BE<#ty>),
+                quote!(::encode::combinators::BE::new(*#reference)),
+            ),
+            None => (quote!(#ty), quote!(*#reference)),
+        };
+
+        match &attrs.length_prefix {
+            Some(length) => {
+                let length = encoded_integer(length);
+                let error = &self.error;
+                // Plain fields are prefixed by reference, which requires a
+                // higher ranked bound over the lifetime of the reference.
+                let (ty, value) = if attrs.order.is_some() {
+                    (ty, value)
+                } else {
+                    (quote!(&'__encode #ty), quote!(#reference))
+                };
+                self.bounds.push(quote!(
+                    for<'__encode> ::encode::combinators::LengthPrefix<#ty, #length, #error>:
+                        ::encode::Encodable<__E, Error = #error>
+                ));
+                quote!(
+                    ::encode::Encodable::<__E>::encode(
+                        &::encode::combinators::LengthPrefix::<_, #length, #error>::new(#value),
+                        encoder,
+                    )?;
+                )
+            }
+            None => self.encode(&ty, &value),
+        }
+    }
+
+    /// Generates the statements that encode the fields of a struct.
+    fn struct_body(&mut self, data: &DataStruct) -> syn::Result<TokenStream> {
+        let mut statements = Vec::new();
+        for (index, field) in data.fields.iter().enumerate() {
+            let attrs = FieldAttrs::parse(&field.attrs)?;
+            let member = field.ident.as_ref().map_or_else(
+                || syn::Index::from(index).to_token_stream(),
+                ToTokens::to_token_stream,
+            );
+            statements.push(self.field(&field.ty, &attrs, &quote!(&self.#member)));
+        }
+        Ok(quote!(#(#statements)*))
+    }
+
+    /// Generates a match over the variants of an enum, which encodes the tag
+    /// of the variant followed by its fields.
+    fn enum_body(
+        &mut self,
+        container: &ContainerAttrs,
+        data: &DataEnum,
+    ) -> syn::Result<TokenStream> {
+        let tag_ty = container
+            .tag
+            .clone()
+            .unwrap_or_else(|| syn::parse_quote!(u8));
+        let encoded_tag_ty = encoded_integer(&tag_ty);
+        let mut arms = Vec::new();
+        for variant in &data.variants {
+            let attrs = VariantAttrs::parse(&variant.attrs)?;
+            let Some(tag) = attrs
+                .tag
+                .as_ref()
+                .or(variant.discriminant.as_ref().map(|(_, tag)| tag))
+            else {
+                return Err(syn::Error::new_spanned(
+                    variant,
+                    "enum variants require an explicit discriminant or a `#[encode(tag = ...)]` attribute",
+                ));
+            };
+            let mut statements =
+                vec![self.encode(&encoded_tag_ty, &encoded_integer_value(&tag_ty, tag))];
+            let mut patterns = Vec::new();
+            for (index, field) in variant.fields.iter().enumerate() {
+                let attrs = FieldAttrs::parse(&field.attrs)?;
+                let binding = if attrs.skip {
+                    quote!(_)
+                } else {
+                    let binding = format_ident!("__field{}", index);
+                    statements.push(self.field(&field.ty, &attrs, &quote!(#binding)));
+                    quote!(#binding)
+                };
+                patterns.push(match &field.ident {
+                    Some(ident) => quote!(#ident: #binding),
+                    None => binding,
+                });
+            }
+            let ident = &variant.ident;
+            let pattern = match &variant.fields {
+                Fields::Named(_) => quote!(Self::#ident { #(#patterns),* }),
+                Fields::Unnamed(_) => quote!(Self::#ident(#(#patterns),*)),
+                Fields::Unit => quote!(Self::#ident),
+            };
+            arms.push(quote!(#pattern => { #(#statements)* }));
+        }
+        Ok(quote!(
+            match self {
+                #(#arms)*
+            }
+        ))
+    }
+}
+
+pub fn expand(input: &DeriveInput) -> syn::Result<TokenStream> {
+    let container = ContainerAttrs::parse(&input.attrs)?;
+    let error = container.error.as_ref().map_or_else(
+        || quote!(<__E as ::encode::BaseEncoder>::Error),
+        ToTokens::to_token_stream,
+    );
+    let mut context = Context {
+        error: error.clone(),
+        bounds: Vec::new(),
+    };
+    if container.error.is_some() {
+        context.bounds.push(quote!(
+            #error: ::core::convert::From<<__E as ::encode::BaseEncoder>::Error>
+        ));
+    }
+
+    let body = match &input.data {
+        Data::Struct(data) => {
+            if let Some(tag) = &container.tag {
+                return Err(syn::Error::new_spanned(
+                    tag,
+                    "the `tag` attribute is only supported on enums",
+                ));
+            }
+            context.struct_body(data)?
+        }
+        Data::Enum(data) => context.enum_body(&container, data)?,
+        Data::Union(data) => {
+            return Err(syn::Error::new_spanned(
+                data.union_token,
+                "`Encodable` cannot be derived for unions",
+            ))
+        }
+    };
+
+    let mut generics = input.generics.clone();
+    generics
+        .params
+        .push(syn::parse_quote!(__E: ::encode::ByteEncoder));
+    let (impl_generics, _, _) = generics.split_for_impl();
+    let (_, ty_generics, where_clause) = input.generics.split_for_impl();
+    let predicates = where_clause
+        .into_iter()
+        .flat_map(|clause| clause.predicates.iter());
+    // Tags and repeated field types generate the same bounds many times.
+    let mut bounds: Vec<TokenStream> = Vec::new();
+    for bound in context.bounds {
+        if !bounds.iter().any(|b| b.to_string() == bound.to_string()) {
+            bounds.push(bound);
+        }
+    }
+    let ident = &input.ident;
+
+    Ok(quote!(
+        #[automatically_derived]
+        impl #impl_generics ::encode::Encodable<__E> for #ident #ty_generics
+        where
+            #(#predicates,)*
+            #(#bounds,)*
+        {
+            type Error = #error;
+
+            #[inline]
+            fn encode(&self, encoder: &mut __E) -> ::core::result::Result<(), Self::Error> {
+                #body
+                ::core::result::Result::Ok(())
+            }
+        }
+    ))
+}
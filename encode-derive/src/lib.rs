@@ -0,0 +1,109 @@
+//! Derive macros for the [`encode`](https://docs.rs/encode) crate.
+//!
+//! This crate is not meant to be used directly. Enable the `derive` feature of
+//! `encode` and use the re-exported `encode::Encodable` derive macro instead.
+#![forbid(
+    missing_docs,
+    missing_debug_implementations,
+    unsafe_code,
+    clippy::dbg_macro,
+    clippy::exit,
+    clippy::infinite_loop,
+    clippy::mem_forget,
+    clippy::panic,
+    clippy::cargo,
+    clippy::missing_const_for_fn,
+    clippy::tabs_in_doc_comments,
+    clippy::perf
+)]
+#![warn(clippy::pedantic)]
+
+mod attrs;
+mod encodable;
+
+use proc_macro::TokenStream;
+use syn::parse_macro_input;
+use syn::DeriveInput;
+
+/// Derives `Encodable` for structs and enums.
+///
+/// The generated implementation works with any `ByteEncoder`. Struct fields
+/// are encoded in declaration order. Enums encode a tag first, followed by the
+/// fields of the variant. Every variant must declare its tag, either with an
+/// explicit discriminant or with a `#[encode(tag = ...)]` attribute.
+///
+/// # Container attributes
+///
+/// | Attribute | Description |
+/// |-----------|-------------|
+/// | `#[encode(error = Type)]` | Uses `Type` as the error type. By default, the error type of the encoder is used. |
+/// | `#[encode(tag = Type)]` | Enums only. The type used for encoding variant tags. Defaults to `u8`. |
+///
+/// # Variant attributes
+///
+/// | Attribute | Description |
+/// |-----------|-------------|
+/// | `#[encode(tag = expr)]` | The tag of the variant. Takes precedence over the discriminant. |
+///
+/// # Field attributes
+///
+/// | Attribute | Description |
+/// |-----------|-------------|
+/// | `#[encode(le)]` | Encodes the field using `combinators::LE` |
+/// | `#[encode(be)]` | Encodes the field using `combinators::BE` |
+/// | `#[encode(length_prefix = Type)]` | Encodes the field using `combinators::LengthPrefix`, with `Type` as the length |
+/// | `#[encode(skip)]` | Does not encode the field |
+/// | `#[encode(with = path)]` | Encodes the field by calling `path(&field, encoder)` |
+///
+/// Primitive integers wider than a byte have no byte order on their own, so
+/// they are encoded in big-endian (network) order when used as a tag or a
+/// length prefix. Use `LE<T>` to encode them in little-endian order instead.
+/// Any other type is used as-is, and tags are built with `Type::new(tag)`.
+///
+/// `length_prefix` requires an error type that can represent length
+/// overflows, such as [`TryFromIntError`](core::num::TryFromIntError).
+///
+/// # Example
+///
+/// ```
+/// use core::num::TryFromIntError;
+/// use encode::Encodable;
+/// use encode::combinators::LE;
+///
+/// #[derive(Encodable)]
+/// #[encode(error = TryFromIntError)]
+/// struct Header {
+///     version: u8,
+///     #[encode(le)]
+///     flags: u32,
+///     #[encode(length_prefix = u16)]
+///     name: &'static str,
+///     #[encode(skip)]
+///     cached: Option<usize>,
+/// }
+///
+/// #[derive(Encodable)]
+/// #[encode(tag = LE<u16>)]
+/// enum Message {
+///     #[encode(tag = 1)]
+///     Ping,
+///     #[encode(tag = 2)]
+///     Data(#[encode(be)] u32),
+/// }
+///
+/// let header = Header { version: 1, flags: 2, name: "hi", cached: None };
+/// let mut buf = Vec::new();
+/// header.encode(&mut buf).unwrap();
+/// assert_eq!(buf, b"\x01\x02\x00\x00\x00\x00\x02hi");
+///
+/// buf.clear();
+/// Message::Data(3).encode(&mut buf).unwrap();
+/// assert_eq!(buf, b"\x02\x00\x00\x00\x00\x03");
+/// ```
+#[proc_macro_derive(Encodable, attributes(encode))]
+pub fn derive_encodable(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    encodable::expand(&input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
@@ -0,0 +1,246 @@
+use core::convert::Infallible;
+use core::num::TryFromIntError;
+
+use encode::combinators::LE;
+use encode::encoders::InsufficientSpace;
+use encode::ByteEncoder;
+use encode::Encodable;
+
+const BUF_SIZE: usize = 64;
+
+fn encode_to_buf<'a, T>(encodable: &T, buf: &'a mut [u8]) -> Result<usize, T::Error>
+where
+    T: Encodable<&'a mut [u8]>,
+{
+    let size = buf.len();
+    let mut encoder = buf;
+    encodable.encode(&mut encoder)?;
+    Ok(size - encoder.len())
+}
+
+#[derive(Debug, PartialEq, Eq)]
+enum Error {
+    InsufficientSpace,
+    TooLarge,
+}
+
+impl From<InsufficientSpace> for Error {
+    fn from(_: InsufficientSpace) -> Self {
+        Self::InsufficientSpace
+    }
+}
+
+impl From<TryFromIntError> for Error {
+    fn from(_: TryFromIntError) -> Self {
+        Self::TooLarge
+    }
+}
+
+impl From<Infallible> for Error {
+    fn from(value: Infallible) -> Self {
+        match value {}
+    }
+}
+
+fn encode_inverted<E: ByteEncoder>(value: &u8, encoder: &mut E) -> Result<(), E::Error> {
+    encoder.put_byte(!value)
+}
+
+#[derive(Encodable)]
+struct Unit;
+
+#[derive(Encodable)]
+struct Tuple(u8, #[encode(be)] u16);
+
+#[derive(Encodable)]
+#[encode(error = Error)]
+struct Header<'a> {
+    version: u8,
+    #[encode(le)]
+    flags: u32,
+    #[encode(be)]
+    id: i16,
+    #[encode(length_prefix = u8)]
+    name: &'a str,
+    #[encode(length_prefix = LE<u16>)]
+    payload: &'a [u8],
+    #[encode(skip)]
+    #[allow(dead_code)]
+    cached: Option<usize>,
+    #[encode(with = encode_inverted)]
+    inverted: u8,
+}
+
+#[derive(Encodable)]
+struct Wrapper<T> {
+    inner: T,
+    trailer: u8,
+}
+
+#[derive(Encodable)]
+#[repr(u8)]
+enum Discriminant {
+    Zero = 0,
+    Two = 2,
+}
+
+#[derive(Encodable)]
+#[encode(error = Error, tag = LE<u16>)]
+enum Message<'a> {
+    #[encode(tag = 1)]
+    Ping,
+    #[encode(tag = 0x0201)]
+    Data(#[encode(be)] u32, &'a str),
+    #[encode(tag = 3)]
+    Named {
+        #[encode(skip)]
+        #[allow(dead_code)]
+        ignored: u8,
+        #[encode(length_prefix = u16)]
+        text: &'a str,
+    },
+}
+
+#[derive(Encodable)]
+#[encode(tag = u32)]
+enum WideTag {
+    #[encode(tag = 7)]
+    Seven,
+}
+
+#[test]
+fn assert_that_unit_structs_encode_nothing() {
+    let mut buf = [0u8; BUF_SIZE];
+    let written = encode_to_buf(&Unit, &mut buf).unwrap();
+
+    assert_eq!(written, 0);
+}
+
+#[test]
+fn assert_that_tuple_struct_fields_are_encoded_in_order() {
+    let expected = b"\x01\x02\x03";
+
+    let mut buf = [0u8; BUF_SIZE];
+    let written = encode_to_buf(&Tuple(1, 0x0203), &mut buf).unwrap();
+
+    assert_eq!(expected, &buf[..written]);
+}
+
+#[test]
+fn assert_that_field_attributes_map_onto_combinators() {
+    let expected = b"\x01\x04\x03\x02\x01\xFF\xFE\x02hi\x03\x00abc\xF0";
+    let encodable = Header {
+        version: 1,
+        flags: 0x0102_0304,
+        id: -2,
+        name: "hi",
+        payload: b"abc",
+        cached: Some(42),
+        inverted: 0x0F,
+    };
+
+    let mut buf = [0u8; BUF_SIZE];
+    let written = encode_to_buf(&encodable, &mut buf).unwrap();
+
+    assert_eq!(expected, &buf[..written]);
+}
+
+#[test]
+fn assert_that_length_prefix_overflows_are_reported() {
+    let name = "a".repeat(256);
+    let encodable = Header {
+        version: 1,
+        flags: 0,
+        id: 0,
+        name: &name,
+        payload: b"",
+        cached: None,
+        inverted: 0,
+    };
+
+    let mut buf = [0u8; 1024];
+
+    assert_eq!(encode_to_buf(&encodable, &mut buf), Err(Error::TooLarge));
+}
+
+#[test]
+fn assert_that_encoder_errors_are_propagated() {
+    let mut buf = [0u8; 2];
+
+    assert_eq!(
+        encode_to_buf(
+            &Wrapper {
+                inner: "abc",
+                trailer: 0
+            },
+            &mut buf
+        ),
+        Err(InsufficientSpace)
+    );
+}
+
+#[test]
+fn assert_that_generic_structs_can_be_encoded() {
+    let expected = b"\x00\x00\x01\x02\x03";
+    let encodable = Wrapper {
+        inner: Wrapper {
+            inner: Tuple(0, 0x0001),
+            trailer: 2,
+        },
+        trailer: 3,
+    };
+
+    let mut buf = [0u8; BUF_SIZE];
+    let written = encode_to_buf(&encodable, &mut buf).unwrap();
+
+    assert_eq!(expected, &buf[..written]);
+}
+
+#[test]
+fn assert_that_enums_are_tagged_with_their_discriminant() {
+    let mut buf = [0u8; BUF_SIZE];
+    let written = encode_to_buf(&Discriminant::Zero, &mut buf).unwrap();
+    assert_eq!(b"\x00", &buf[..written]);
+
+    let written = encode_to_buf(&Discriminant::Two, &mut buf).unwrap();
+    assert_eq!(b"\x02", &buf[..written]);
+}
+
+#[test]
+fn assert_that_enum_variants_are_tagged_with_the_tag_attribute() {
+    let mut buf = [0u8; BUF_SIZE];
+
+    let written = encode_to_buf(&Message::Ping, &mut buf).unwrap();
+    assert_eq!(b"\x01\x00", &buf[..written]);
+
+    let written = encode_to_buf(&Message::Data(5, "ok"), &mut buf).unwrap();
+    assert_eq!(b"\x01\x02\x00\x00\x00\x05ok", &buf[..written]);
+}
+
+#[test]
+fn assert_that_named_enum_variants_can_be_encoded() {
+    let encodable = Message::Named {
+        ignored: 9,
+        text: "hey",
+    };
+
+    let mut buf = [0u8; BUF_SIZE];
+    let written = encode_to_buf(&encodable, &mut buf).unwrap();
+
+    assert_eq!(b"\x03\x00\x00\x03hey", &buf[..written]);
+}
+
+#[test]
+fn assert_that_wide_integer_tags_are_big_endian() {
+    let mut buf = [0u8; BUF_SIZE];
+    let written = encode_to_buf(&WideTag::Seven, &mut buf).unwrap();
+
+    assert_eq!(b"\x00\x00\x00\x07", &buf[..written]);
+}
+
+#[test]
+fn assert_that_derived_encodables_have_an_encoded_size() {
+    use encode::EncodableSize;
+
+    assert_eq!(Tuple(1, 2).encoded_size(), Ok(3));
+}
@@ -13,7 +13,7 @@
 use core::convert::Infallible;
 use core::num::TryFromIntError;
 
-use encode::combinators::{FromError, Iter, LengthPrefix, LE};
+use encode::combinators::{AdjustedLengthPrefix, Iter, LengthOutOfRange, LengthPrefix, Tuple, LE};
 use encode::encoders::InsufficientSpace;
 use encode::{Encodable, EncodableSize};
 
@@ -29,6 +29,7 @@ use encode::{Encodable, EncodableSize};
 pub enum BsonError {
     TooLarge(TryFromIntError),
     InsufficientSpace(InsufficientSpace),
+    LengthOutOfRange(LengthOutOfRange),
 }
 
 impl core::error::Error for BsonError {}
@@ -37,6 +38,7 @@ impl core::fmt::Display for BsonError {
         match self {
             BsonError::TooLarge(_) => write!(f, "failed to encode BSON because it is too large"),
             BsonError::InsufficientSpace(err) => core::fmt::Display::fmt(err, f),
+            BsonError::LengthOutOfRange(err) => core::fmt::Display::fmt(err, f),
         }
     }
 }
@@ -50,6 +52,11 @@ impl From<InsufficientSpace> for BsonError {
         BsonError::InsufficientSpace(err)
     }
 }
+impl From<LengthOutOfRange> for BsonError {
+    fn from(err: LengthOutOfRange) -> Self {
+        BsonError::LengthOutOfRange(err)
+    }
+}
 impl From<Infallible> for BsonError {
     fn from(_: Infallible) -> Self {
         unreachable!("infallible cannot be constructed")
@@ -71,17 +78,13 @@ where
     type Error = BsonError;
 
     fn encode(&self, encoder: &mut Encoder) -> Result<(), Self::Error> {
-        let document = (
-            Iter::new(&self.e_list),
-            // u8 uses `BaseEncoder::Error`, so we need to convert it to our BsonError
-            // for our combinators to work.
-            FromError::<_, Self::Error>::new(0u8),
-        );
-        // We cannot use LengthPrefix here because we need to encode the size of
-        // the document including the size field itself.
-        let size = document.encoded_size()? + (i32::BITS / 8) as usize;
-        LE::<i32>::try_from(size)?.encode(encoder)?;
-        document.encode(encoder)?;
+        // `Iter` and `u8` have different error types, so we use `Tuple` to
+        // convert both of them into our BsonError.
+        let document = Tuple::<_, Self::Error>::new((Iter::new(&self.e_list), 0u8));
+        // The size of the document includes the size field itself.
+        AdjustedLengthPrefix::<_, LE<i32>, Self::Error>::new(document)
+            .including_prefix()
+            .encode(encoder)?;
         Ok(())
     }
 }
@@ -133,22 +136,16 @@ where
             // When all elements have the same error type, we can use a tuple
             // to encode them all at once.
             BsonElementVariant::Double(x) => (1i8, e_name, LE::new(*x)).encode(encoder)?,
-            // However, when the error types are different, we need to either
-            // transform the error type using `FromError` or encode them separately.
+            // However, when the error types are different, we use `Tuple` to
+            // convert all of them into our BsonError.
             BsonElementVariant::String(x) => {
-                2i8.encode(encoder)?;
-                e_name.encode(encoder)?;
-                BsonString(x).encode(encoder)?;
+                Tuple::<_, BsonError>::new((2i8, e_name, BsonString(x))).encode(encoder)?
             }
             BsonElementVariant::Document(x) => {
-                3i8.encode(encoder)?;
-                e_name.encode(encoder)?;
-                x.encode(encoder)?;
+                Tuple::<_, BsonError>::new((3i8, e_name, x)).encode(encoder)?
             }
             BsonElementVariant::Array(x) => {
-                4i8.encode(encoder)?;
-                e_name.encode(encoder)?;
-                x.encode(encoder)?;
+                Tuple::<_, BsonError>::new((4i8, e_name, x)).encode(encoder)?
             }
             BsonElementVariant::Binary { subtype, data } => {
                 (5i8, e_name, LE::<i32>::try_from(data.len())?, subtype, data).encode(encoder)?
@@ -160,23 +157,18 @@ where
             BsonElementVariant::Null => (10i8, e_name).encode(encoder)?,
             BsonElementVariant::Regex(x, y) => (11i8, e_name, x, 0u8, y, 0u8).encode(encoder)?,
             BsonElementVariant::DBPointer(x, y) => {
-                (12i8, e_name).encode(encoder)?;
-                BsonString(x).encode(encoder)?;
-                y.encode(encoder)?;
+                Tuple::<_, BsonError>::new((12i8, e_name, BsonString(x), y)).encode(encoder)?
             }
             BsonElementVariant::JavaScriptCode(x) => (13i8, e_name, x).encode(encoder)?,
             BsonElementVariant::Symbol(x) => {
-                (14i8, e_name).encode(encoder)?;
-                BsonString(x).encode(encoder)?;
+                Tuple::<_, BsonError>::new((14i8, e_name, BsonString(x))).encode(encoder)?
             }
             BsonElementVariant::JavaScriptCodeWithScope(x, y) => {
-                (15i8, e_name).encode(encoder)?;
-                let content = (BsonString(x), y);
-                // We cannot use LengthPrefix here because we need to encode the size of
-                // the content including the size field itself.
-                let len = content.encoded_size()? + (i32::BITS / 8) as usize;
-                LE::<i32>::try_from(len)?.encode(encoder)?;
-                content.encode(encoder)?;
+                // The size of the content includes the size field itself.
+                let content =
+                    AdjustedLengthPrefix::<_, LE<i32>, BsonError>::new((BsonString(x), y))
+                        .including_prefix();
+                Tuple::<_, BsonError>::new((15i8, e_name, content)).encode(encoder)?
             }
             BsonElementVariant::Int32(x) => (16i8, e_name, LE::new(*x)).encode(encoder)?,
             BsonElementVariant::Timestamp(x) => (17i8, e_name, LE::new(*x)).encode(encoder)?,
@@ -1,21 +1,31 @@
-//! A [JSON](https://www.json.org/json-en.html) encoder
+//! A [JSON](https://www.json.org/json-en.html) document type
 //!
-//! This example demonstrates how to implement [`Encodable`] using combinators
-//! to simplify the implementation of encoders.
+//! This example demonstrates how to implement [`Encodable`] for a recursive
+//! data type using the encodables from [`encode::formats::json`], and how the
+//! same implementation produces both compact and pretty output.
 //!
 //! Run the example with:
 //!
 //! ```sh
-//! cargo run --example json
+//! cargo run --example json --features json
 //! ```
-use core::iter::repeat;
-use encode::combinators::Iter;
-use encode::combinators::Separated;
+use encode::formats::json::CompactJson;
+use encode::formats::json::JsonArray;
+use encode::formats::json::JsonBool;
+use encode::formats::json::JsonEncoder;
+use encode::formats::json::JsonError;
+use encode::formats::json::JsonNull;
+use encode::formats::json::JsonNumber;
+use encode::formats::json::JsonObject;
+use encode::formats::json::JsonString;
+use encode::formats::json::PrettyJson;
 use encode::Encodable;
-use encode::StrEncoder;
-use std::collections::HashMap;
+use std::collections::BTreeMap;
 
 /// Our JSON data type.
+///
+/// Objects use a [`BTreeMap`] so that keys are always written in the same
+/// order.
 #[derive(Debug, Clone, PartialEq)]
 pub enum Json {
     Null,
@@ -23,159 +33,25 @@ pub enum Json {
     Number(f64),
     String(String),
     Array(Vec<Json>),
-    Object(HashMap<String, Json>),
-}
-
-/// A helper combinator to encode a string as a JSON string.
-#[derive(Debug, Clone, PartialEq, Eq)]
-pub struct JsonString<S>(pub S);
-
-impl<S: AsRef<str>, E: StrEncoder> Encodable<E> for JsonString<S> {
-    type Error = E::Error;
-
-    fn encode(&self, encoder: &mut E) -> Result<(), Self::Error> {
-        let s = self.0.as_ref();
-        '"'.encode(encoder)?;
-        for c in s.chars() {
-            match c {
-                '"' => r#"\""#.encode(encoder)?,
-                '\\' => r#"\\"#.encode(encoder)?,
-                '\x08' => r#"\b"#.encode(encoder)?,
-                '\x0c' => r#"\f"#.encode(encoder)?,
-                '\n' => r#"\n"#.encode(encoder)?,
-                '\r' => r#"\r"#.encode(encoder)?,
-                '\t' => r#"\t"#.encode(encoder)?,
-                c if c.is_control() => format_args!("\\u{:04x}", c as u32).encode(encoder)?,
-                c => c.encode(encoder)?,
-            }
-        }
-        '"'.encode(encoder)
-    }
-}
-
-/// Encodes JSON without any whitespace (compact format).
-pub struct CompactJson<'a>(pub &'a Json);
-
-impl<'a> CompactJson<'a> {
-    pub fn new(json: &'a Json) -> Self {
-        CompactJson(json)
-    }
-}
-
-impl<'a, E: StrEncoder> Encodable<E> for CompactJson<'a> {
-    type Error = E::Error;
-
-    fn encode(&self, encoder: &mut E) -> Result<(), Self::Error> {
-        match self.0 {
-            Json::Null => "null".encode(encoder),
-            Json::Bool(true) => "true".encode(encoder),
-            Json::Bool(false) => "false".encode(encoder),
-            Json::Number(n) => {
-                // JSON does not permit NaN or +/-infinity numeric literals.
-                // Encode non-finite floating-point values as `null` so the
-                // output remains valid JSON.
-                if n.is_finite() {
-                    format_args!("{n}").encode(encoder)
-                } else {
-                    "null".encode(encoder)
-                }
-            }
-            Json::String(s) => JsonString(s).encode(encoder),
-            // We use the `Separated` combinator to encode the iterator as a JSON array.
-            Json::Array(a) => (
-                '[',
-                Separated::new(a.iter().map(CompactJson::new), ','),
-                ']',
-            )
-                .encode(encoder),
-            // Notice how we can use tuples to add the bracket prefix and suffix to the object.
-            Json::Object(o) => (
-                '{',
-                Separated::new(
-                    o.iter()
-                        .map(|(k, v)| (JsonString(k), ':', CompactJson::new(v))),
-                    ',',
-                ),
-                '}',
-            )
-                .encode(encoder),
-        }
-    }
-}
-
-/// Encodes JSON with configurable indentation (pretty format).
-///
-/// The `depth` field tracks the current nesting level; start at `0` via [`PrettyJson::new`].
-/// The `indent` field controls what string is repeated per level (default: `"  "` for 2 spaces).
-/// Set it to `"\t"` for tabs or `"    "` for 4-space indentation.
-pub struct PrettyJson<'json, 'indent> {
-    pub json: &'json Json,
-    pub depth: usize,
-    pub indent: &'indent str,
-}
-
-impl<'json, 'indent> PrettyJson<'json, 'indent> {
-    pub fn new(json: &'json Json, indent: &'indent str) -> Self {
-        PrettyJson {
-            json,
-            depth: 0,
-            indent,
-        }
-    }
+    Object(BTreeMap<String, Json>),
 }
 
-impl<'json, 'indent, E: StrEncoder> Encodable<E> for PrettyJson<'json, 'indent> {
-    type Error = E::Error;
+// Every variant maps to one of the JSON encodables. Arrays and objects
+// encode their elements through this same implementation, and the
+// `JsonEncoder` decides whether the output is compact or pretty.
+impl<E: JsonEncoder> Encodable<E> for Json {
+    type Error = JsonError<E::Error>;
 
     fn encode(&self, encoder: &mut E) -> Result<(), Self::Error> {
-        match self.json {
-            Json::Array(a) if a.is_empty() => "[]".encode(encoder),
-            Json::Object(o) if o.is_empty() => "{}".encode(encoder),
-            Json::Array(a) => (
-                "[\n",
-                Separated::new(
-                    a.iter().map(|json| {
-                        (
-                            Iter::new(repeat(self.indent).take(self.depth + 1)),
-                            PrettyJson {
-                                json,
-                                depth: self.depth + 1,
-                                indent: self.indent,
-                            },
-                        )
-                    }),
-                    ",\n",
-                ),
-                '\n',
-                Iter::new(repeat(self.indent).take(self.depth)),
-                ']',
-            )
-                .encode(encoder),
-            // Notice how we can use tuples to add the bracket prefix and suffix to the object.
-            Json::Object(o) => (
-                "{\n",
-                Separated::new(
-                    o.iter().map(|(k, json)| {
-                        (
-                            Iter::new(repeat(self.indent).take(self.depth + 1)),
-                            JsonString(k),
-                            ": ",
-                            PrettyJson {
-                                json,
-                                depth: self.depth + 1,
-                                indent: self.indent,
-                            },
-                        )
-                    }),
-                    ",\n",
-                ),
-                '\n',
-                Iter::new(repeat(self.indent).take(self.depth)),
-                '}',
-            )
-                .encode(encoder),
-            // Fall back to the compact encoder for these simpler values
-            _ => CompactJson::new(self.json).encode(encoder),
+        match self {
+            Json::Null => JsonNull.encode(encoder),
+            Json::Bool(b) => JsonBool::new(*b).encode(encoder),
+            // JSON does not permit NaN or +/-infinity, so this fails with
+            // `JsonError::NonFiniteNumber` instead of writing invalid JSON.
+            Json::Number(n) => JsonNumber::new(*n).encode(encoder),
+            Json::String(s) => JsonString::new(s).encode(encoder),
+            Json::Array(a) => JsonArray::new(a).encode(encoder),
+            Json::Object(o) => JsonObject::new(o).encode(encoder),
         }
     }
 }
@@ -183,16 +59,17 @@ impl<'json, 'indent, E: StrEncoder> Encodable<E> for PrettyJson<'json, 'indent>
 // Notice how we can use our encoder to implement traits such as `Display`.
 impl std::fmt::Display for Json {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        if f.alternate() {
-            PrettyJson::new(self, "  ").encode(f)
+        let result = if f.alternate() {
+            self.encode(&mut PrettyJson::new(f))
         } else {
-            CompactJson::new(self).encode(f)
-        }
+            self.encode(&mut CompactJson::new(f))
+        };
+        result.map_err(|_| std::fmt::Error)
     }
 }
 
 fn main() -> Result<(), Box<dyn core::error::Error>> {
-    let json = Json::Object(HashMap::from([
+    let json = Json::Object(BTreeMap::from([
         ("name".into(), Json::String("John Doe".into())),
         ("age".into(), Json::Number(42.0)),
         ("is_student".into(), Json::Bool(false)),
@@ -209,7 +86,7 @@ fn main() -> Result<(), Box<dyn core::error::Error>> {
             "nested".into(),
             Json::Array(vec![
                 Json::Array(vec![]),
-                Json::Array(vec![Json::Object(HashMap::from([
+                Json::Array(vec![Json::Object(BTreeMap::from([
                     ("key".into(), Json::Null),
                     ("another".into(), Json::Array(vec![Json::Null])),
                 ]))]),
@@ -221,74 +98,80 @@ fn main() -> Result<(), Box<dyn core::error::Error>> {
     println!("{json}");
     println!("{:=^80}", "Pretty");
     println!("{json:#}");
+
+    println!("{:=^80}", "Invalid");
+    let mut buf = String::new();
+    let invalid = Json::Array(vec![Json::Number(f64::NAN)]);
+    if let Err(err) = invalid.encode(&mut CompactJson::new(&mut buf)) {
+        println!("{err}");
+    }
     Ok(())
 }
 
 #[cfg(test)]
 mod test {
-    //! Tests for the JSON encoder. Avoid multi-key object tests because the
-    //! key order is not guaranteed for [`HashMap`].
     use rstest::rstest;
 
     use super::*;
 
+    fn compact(json: &Json) -> Result<String, JsonError<std::convert::Infallible>> {
+        let mut buf = String::new();
+        json.encode(&mut CompactJson::new(&mut buf))?;
+        Ok(buf)
+    }
+
     #[rstest]
-    #[case::enabled(Json::Bool(true), b"true" as &[u8])]
-    #[case::disabled(Json::Bool(false), b"false")]
-    fn assert_booleans_are_encoded_correctly(#[case] json: Json, #[case] expected: &[u8]) {
-        let mut buf = Vec::new();
-        CompactJson::new(&json).encode(&mut buf).unwrap();
-        assert_eq!(&buf, expected);
+    #[case::enabled(Json::Bool(true), "true")]
+    #[case::disabled(Json::Bool(false), "false")]
+    fn assert_booleans_are_encoded_correctly(#[case] json: Json, #[case] expected: &str) {
+        assert_eq!(compact(&json).unwrap(), expected);
     }
 
     #[test]
     fn assert_numbers_are_encoded_correctly() {
-        let mut buf = Vec::new();
-        CompactJson::new(&Json::Number(42.0))
-            .encode(&mut buf)
-            .unwrap();
-        assert_eq!(&buf, b"42");
+        assert_eq!(compact(&Json::Number(42.0)).unwrap(), "42");
+    }
+
+    #[rstest]
+    #[case::nan(f64::NAN)]
+    #[case::infinity(f64::INFINITY)]
+    fn assert_non_finite_numbers_are_rejected(#[case] number: f64) {
+        let json = Json::Array(vec![Json::Number(number)]);
+        assert_eq!(compact(&json), Err(JsonError::NonFiniteNumber));
     }
 
     #[test]
     fn assert_strings_are_encoded_correctly() {
-        let mut buf = Vec::new();
-        CompactJson::new(&Json::String("Hello, World!".into()))
-            .encode(&mut buf)
-            .unwrap();
-        let s = String::from_utf8(buf).unwrap();
-        assert_eq!(s, "\"Hello, World!\"");
+        let json = Json::String("Hello, \"World\"!".into());
+        assert_eq!(compact(&json).unwrap(), r#""Hello, \"World\"!""#);
     }
 
     #[rstest]
     #[case::non_empty(
         Json::Array(vec![Json::Number(1.0), Json::Number(2.0), Json::Number(3.0)]),
-        b"[1,2,3]" as &[u8]
+        "[1,2,3]"
     )]
-    #[case::empty(Json::Array(vec![]), b"[]")]
-    fn assert_arrays_are_encoded_correctly(#[case] json: Json, #[case] expected: &[u8]) {
-        let mut buf = Vec::new();
-        CompactJson::new(&json).encode(&mut buf).unwrap();
-        assert_eq!(&buf, expected);
+    #[case::empty(Json::Array(vec![]), "[]")]
+    fn assert_arrays_are_encoded_correctly(#[case] json: Json, #[case] expected: &str) {
+        assert_eq!(compact(&json).unwrap(), expected);
     }
 
     #[rstest]
     #[case::non_empty(
-        Json::Object(HashMap::from([("name".into(), Json::String("John Doe".into()))])),
-        b"{\"name\":\"John Doe\"}" as &[u8]
+        Json::Object(BTreeMap::from([
+            ("name".into(), Json::String("John Doe".into())),
+            ("age".into(), Json::Number(42.0)),
+        ])),
+        r#"{"age":42,"name":"John Doe"}"#
     )]
-    #[case::empty(Json::Object(HashMap::new()), b"{}")]
-    fn assert_objects_are_encoded_correctly(#[case] json: Json, #[case] expected: &[u8]) {
-        let mut buf = Vec::new();
-        CompactJson::new(&json).encode(&mut buf).unwrap();
-        assert_eq!(&buf, expected);
+    #[case::empty(Json::Object(BTreeMap::new()), "{}")]
+    fn assert_objects_are_encoded_correctly(#[case] json: Json, #[case] expected: &str) {
+        assert_eq!(compact(&json).unwrap(), expected);
     }
 
     #[test]
     fn assert_nulls_are_encoded_correctly() {
-        let mut buf = Vec::new();
-        CompactJson::new(&Json::Null).encode(&mut buf).unwrap();
-        assert_eq!(&buf, b"null");
+        assert_eq!(compact(&Json::Null).unwrap(), "null");
     }
 
     #[test]
@@ -316,40 +199,23 @@ mod test {
 
     #[test]
     fn assert_pretty_empty_object() {
-        assert_eq!(format!("{:#}", Json::Object(HashMap::new())), "{}");
+        assert_eq!(format!("{:#}", Json::Object(BTreeMap::new())), "{}");
     }
 
     #[test]
     fn assert_pretty_object() {
-        let json = Json::Object(HashMap::from([("x".into(), Json::Number(1.0))]));
+        let json = Json::Object(BTreeMap::from([("x".into(), Json::Number(1.0))]));
         assert_eq!(format!("{json:#}"), "{\n  \"x\": 1\n}");
     }
 
-    #[test]
-    fn assert_pretty_tabs() {
-        let json = Json::Array(vec![Json::Number(1.0), Json::Number(2.0)]);
-        let mut buf = String::new();
-        PrettyJson {
-            json: &json,
-            depth: 0,
-            indent: "\t",
-        }
-        .encode(&mut buf)
-        .unwrap();
-        assert_eq!(buf, "[\n\t1,\n\t2\n]");
-    }
-
-    #[test]
-    fn assert_pretty_four_spaces() {
+    #[rstest]
+    #[case::tabs("\t", "[\n\t1,\n\t2\n]")]
+    #[case::four_spaces("    ", "[\n    1,\n    2\n]")]
+    fn assert_pretty_custom_indent(#[case] indent: &str, #[case] expected: &str) {
         let json = Json::Array(vec![Json::Number(1.0), Json::Number(2.0)]);
         let mut buf = String::new();
-        PrettyJson {
-            json: &json,
-            depth: 0,
-            indent: "    ",
-        }
-        .encode(&mut buf)
-        .unwrap();
-        assert_eq!(buf, "[\n    1,\n    2\n]");
+        json.encode(&mut PrettyJson::new(&mut buf).with_indent(indent))
+            .unwrap();
+        assert_eq!(buf, expected);
     }
 }
@@ -0,0 +1,72 @@
+use super::Checksum;
+use crate::combinators::BE;
+
+/// The modulus of both Adler-32 sums.
+const MOD: u32 = 65521;
+
+/// The number of bytes that can be summed before the sums must be reduced to
+/// avoid overflowing.
+const CHUNK: usize = 5552;
+
+/// The Adler-32 checksum, as used by zlib.
+///
+/// The checksum is encoded in big-endian order.
+#[doc(alias("adler", "zlib"))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Adler32 {
+    a: u32,
+    b: u32,
+}
+
+impl Adler32 {
+    /// Creates a new [`Adler32`] checksum.
+    #[inline]
+    #[must_use]
+    pub const fn new() -> Self {
+        Self { a: 1, b: 0 }
+    }
+}
+
+impl Default for Adler32 {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Checksum for Adler32 {
+    type Output = BE<u32>;
+
+    #[inline]
+    fn update(&mut self, bytes: &[u8]) {
+        for chunk in bytes.chunks(CHUNK) {
+            for &byte in chunk {
+                self.a += u32::from(byte);
+                self.b += self.a;
+            }
+            self.a %= MOD;
+            self.b %= MOD;
+        }
+    }
+
+    #[inline]
+    fn finish(&self) -> Self::Output {
+        BE::new((self.b << 16) | self.a)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case::check(b"123456789", 0x091E_01DE)]
+    #[case::empty(b"", 1)]
+    #[case::wikipedia(b"Wikipedia", 0x11E6_0398)]
+    #[case::large(&[0xFF; 10_000], 0xB623_EB2B)]
+    fn assert_that_adler32_matches_known_values(#[case] bytes: &[u8], #[case] expected: u32) {
+        assert_eq!(Adler32::compute(bytes), BE::new(expected));
+    }
+}
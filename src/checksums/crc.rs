@@ -0,0 +1,286 @@
+use super::Checksum;
+use crate::combinators::BE;
+use crate::combinators::LE;
+
+/// Builds the lookup table of a CRC whose bits are processed most significant
+/// bit first.
+macro_rules! msb_first_table {
+    ($T:ty, $poly:expr) => {{
+        let mut table = [0; 256];
+        let mut i = 0;
+        while i < 256 {
+            #[allow(clippy::cast_possible_truncation)]
+            let mut crc = (i as $T) << (<$T>::BITS - 8);
+            let mut bit = 0;
+            while bit < 8 {
+                crc = if crc >> (<$T>::BITS - 1) == 1 {
+                    (crc << 1) ^ $poly
+                } else {
+                    crc << 1
+                };
+                bit += 1;
+            }
+            table[i] = crc;
+            i += 1;
+        }
+        table
+    }};
+}
+
+/// Builds the lookup table of a CRC whose bits are processed least
+/// significant bit first, given its reversed polynomial.
+macro_rules! lsb_first_table {
+    ($T:ty, $poly:expr) => {{
+        let mut table = [0; 256];
+        let mut i = 0;
+        while i < 256 {
+            #[allow(clippy::cast_possible_truncation)]
+            let mut crc = i as $T;
+            let mut bit = 0;
+            while bit < 8 {
+                crc = if crc & 1 == 1 {
+                    (crc >> 1) ^ $poly
+                } else {
+                    crc >> 1
+                };
+                bit += 1;
+            }
+            table[i] = crc;
+            i += 1;
+        }
+        table
+    }};
+}
+
+/// The CRC-8 checksum with polynomial `0x07`, as used by `SMBus`.
+///
+/// The checksum is encoded as a single byte.
+#[doc(alias("crc", "crc-8", "smbus"))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Crc8 {
+    crc: u8,
+}
+
+impl Crc8 {
+    const TABLE: [u8; 256] = msb_first_table!(u8, 0x07);
+
+    /// Creates a new [`Crc8`] checksum.
+    #[inline]
+    #[must_use]
+    pub const fn new() -> Self {
+        Self { crc: 0 }
+    }
+}
+
+impl Checksum for Crc8 {
+    type Output = u8;
+
+    #[inline]
+    fn update(&mut self, bytes: &[u8]) {
+        for &byte in bytes {
+            self.crc = Self::TABLE[usize::from(self.crc ^ byte)];
+        }
+    }
+
+    #[inline]
+    fn finish(&self) -> Self::Output {
+        self.crc
+    }
+}
+
+/// The CRC-16/CCITT-FALSE checksum, also known as CRC-16/IBM-3740 and
+/// CRC-16/AUTOSAR.
+///
+/// The checksum is encoded in big-endian order.
+#[doc(alias("crc", "crc-16", "ccitt", "ibm-3740", "autosar"))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Crc16Ccitt {
+    crc: u16,
+}
+
+impl Crc16Ccitt {
+    const TABLE: [u16; 256] = msb_first_table!(u16, 0x1021);
+
+    /// Creates a new [`Crc16Ccitt`] checksum.
+    #[inline]
+    #[must_use]
+    pub const fn new() -> Self {
+        Self { crc: 0xFFFF }
+    }
+}
+
+impl Default for Crc16Ccitt {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Checksum for Crc16Ccitt {
+    type Output = BE<u16>;
+
+    #[inline]
+    fn update(&mut self, bytes: &[u8]) {
+        for &byte in bytes {
+            let index = (self.crc >> 8) ^ u16::from(byte);
+            self.crc = (self.crc << 8) ^ Self::TABLE[usize::from(index)];
+        }
+    }
+
+    #[inline]
+    fn finish(&self) -> Self::Output {
+        BE::new(self.crc)
+    }
+}
+
+/// The CRC-16/MODBUS checksum, as used by Modbus RTU.
+///
+/// The checksum is encoded in little-endian order.
+#[doc(alias("crc", "crc-16", "modbus"))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Crc16Modbus {
+    crc: u16,
+}
+
+impl Crc16Modbus {
+    const TABLE: [u16; 256] = lsb_first_table!(u16, 0xA001);
+
+    /// Creates a new [`Crc16Modbus`] checksum.
+    #[inline]
+    #[must_use]
+    pub const fn new() -> Self {
+        Self { crc: 0xFFFF }
+    }
+}
+
+impl Default for Crc16Modbus {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Checksum for Crc16Modbus {
+    type Output = LE<u16>;
+
+    #[inline]
+    fn update(&mut self, bytes: &[u8]) {
+        for &byte in bytes {
+            let index = (self.crc ^ u16::from(byte)) & 0xFF;
+            self.crc = (self.crc >> 8) ^ Self::TABLE[usize::from(index)];
+        }
+    }
+
+    #[inline]
+    fn finish(&self) -> Self::Output {
+        LE::new(self.crc)
+    }
+}
+
+macro_rules! impl_crc32 {
+    ($(#[$meta:meta])* $name:ident => $poly:expr) => {
+        $(#[$meta])*
+        ///
+        /// The checksum is encoded in little-endian order.
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+        pub struct $name {
+            crc: u32,
+        }
+
+        impl $name {
+            const TABLE: [u32; 256] = lsb_first_table!(u32, $poly);
+
+            #[doc = concat!("Creates a new [`", stringify!($name), "`] checksum.")]
+            #[inline]
+            #[must_use]
+            pub const fn new() -> Self {
+                Self { crc: u32::MAX }
+            }
+        }
+
+        impl Default for $name {
+            #[inline]
+            fn default() -> Self {
+                Self::new()
+            }
+        }
+
+        impl Checksum for $name {
+            type Output = LE<u32>;
+
+            #[inline]
+            fn update(&mut self, bytes: &[u8]) {
+                for &byte in bytes {
+                    let index = (self.crc ^ u32::from(byte)) & 0xFF;
+                    self.crc = (self.crc >> 8) ^ Self::TABLE[index as usize];
+                }
+            }
+
+            #[inline]
+            fn finish(&self) -> Self::Output {
+                LE::new(!self.crc)
+            }
+        }
+    };
+}
+
+impl_crc32! {
+    /// The CRC-32 checksum, as used by Ethernet, ZIP and PNG.
+    #[doc(alias("crc", "crc-32", "ieee"))]
+    Crc32 => 0xEDB8_8320
+}
+
+impl_crc32! {
+    /// The CRC-32C (Castagnoli) checksum, as used by iSCSI, SCTP and ext4.
+    #[doc(alias("crc", "crc-32c", "castagnoli"))]
+    Crc32c => 0x82F6_3B78
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    const CHECK: &[u8] = b"123456789";
+
+    #[test]
+    fn assert_that_crc8_matches_its_check_value() {
+        assert_eq!(Crc8::compute(CHECK), 0xF4);
+    }
+
+    #[test]
+    fn assert_that_crc16_ccitt_matches_its_check_value() {
+        assert_eq!(Crc16Ccitt::compute(CHECK), BE::new(0x29B1));
+    }
+
+    #[test]
+    fn assert_that_crc16_modbus_matches_its_check_value() {
+        assert_eq!(Crc16Modbus::compute(CHECK), LE::new(0x4B37));
+    }
+
+    #[rstest]
+    #[case::check(CHECK, 0xCBF4_3926)]
+    #[case::empty(b"", 0)]
+    #[case::fox(b"The quick brown fox jumps over the lazy dog", 0x414F_A339)]
+    fn assert_that_crc32_matches_known_values(#[case] bytes: &[u8], #[case] expected: u32) {
+        assert_eq!(Crc32::compute(bytes), LE::new(expected));
+    }
+
+    #[rstest]
+    #[case::check(CHECK, 0xE306_9283)]
+    #[case::empty(b"", 0)]
+    #[case::zeros(&[0; 32], 0x8A91_36AA)]
+    fn assert_that_crc32c_matches_known_values(#[case] bytes: &[u8], #[case] expected: u32) {
+        assert_eq!(Crc32c::compute(bytes), LE::new(expected));
+    }
+
+    #[test]
+    fn assert_that_crcs_can_be_updated_incrementally() {
+        let mut crc = Crc16Ccitt::new();
+        for byte in CHECK {
+            crc.update(&[*byte]);
+        }
+        assert_eq!(crc.finish(), Crc16Ccitt::compute(CHECK));
+    }
+}
@@ -0,0 +1,55 @@
+use super::Checksum;
+use crate::combinators::BE;
+
+/// The Fletcher-16 checksum.
+///
+/// The checksum is encoded in big-endian order, with the second sum in the
+/// most significant byte.
+#[doc(alias("fletcher"))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Fletcher16 {
+    sum1: u16,
+    sum2: u16,
+}
+
+impl Fletcher16 {
+    /// Creates a new [`Fletcher16`] checksum.
+    #[inline]
+    #[must_use]
+    pub const fn new() -> Self {
+        Self { sum1: 0, sum2: 0 }
+    }
+}
+
+impl Checksum for Fletcher16 {
+    type Output = BE<u16>;
+
+    #[inline]
+    fn update(&mut self, bytes: &[u8]) {
+        for &byte in bytes {
+            self.sum1 = (self.sum1 + u16::from(byte)) % 255;
+            self.sum2 = (self.sum2 + self.sum1) % 255;
+        }
+    }
+
+    #[inline]
+    fn finish(&self) -> Self::Output {
+        BE::new((self.sum2 << 8) | self.sum1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case::abcde(b"abcde", 0xC8F0)]
+    #[case::abcdef(b"abcdef", 0x2057)]
+    #[case::abcdefgh(b"abcdefgh", 0x0627)]
+    #[case::empty(b"", 0)]
+    fn assert_that_fletcher16_matches_known_values(#[case] bytes: &[u8], #[case] expected: u16) {
+        assert_eq!(Fletcher16::compute(bytes), BE::new(expected));
+    }
+}
@@ -0,0 +1,74 @@
+//! Checksums that can be computed while encoding.
+//!
+//! [`Checksummed`]: crate::combinators::Checksummed
+//! [`ChecksumEncoder`]: crate::encoders::ChecksumEncoder
+//!
+//! This module provides the [`Checksum`] trait and implementations of common
+//! checksum algorithms. Checksums are computed incrementally, so they can be
+//! updated as bytes flow through a [`ChecksumEncoder`] without buffering the
+//! encoded data. The [`Checksummed`] combinator uses them to append a checksum
+//! trailer to an encodable.
+//!
+//! # Supported Checksums
+//!
+//! | Type | Algorithm | Encoded as |
+//! |------|-----------|------------|
+//! | [`Crc8`] | CRC-8 (polynomial `0x07`) | [`u8`] |
+//! | [`Crc16Ccitt`] | CRC-16/CCITT-FALSE | [`BE<u16>`](crate::combinators::BE) |
+//! | [`Crc16Modbus`] | CRC-16/MODBUS | [`LE<u16>`](crate::combinators::LE) |
+//! | [`Crc32`] | CRC-32 (IEEE 802.3) | [`LE<u32>`](crate::combinators::LE) |
+//! | [`Crc32c`] | CRC-32C (Castagnoli) | [`LE<u32>`](crate::combinators::LE) |
+//! | [`Adler32`] | Adler-32 | [`BE<u32>`](crate::combinators::BE) |
+//! | [`Fletcher16`] | Fletcher-16 | [`BE<u16>`](crate::combinators::BE) |
+
+mod adler32;
+mod crc;
+mod fletcher16;
+
+pub use adler32::Adler32;
+pub use crc::Crc16Ccitt;
+pub use crc::Crc16Modbus;
+pub use crc::Crc32;
+pub use crc::Crc32c;
+pub use crc::Crc8;
+pub use fletcher16::Fletcher16;
+
+/// A trait for checksums that can be computed incrementally.
+///
+/// The [`Default`] value of a checksum, if any, must be its initial state.
+///
+/// # Example
+///
+/// ```
+/// use encode::checksums::{Checksum, Crc32};
+///
+/// let mut crc = Crc32::new();
+/// crc.update(b"1234");
+/// crc.update(b"56789");
+/// assert_eq!(*crc.finish(), 0xCBF4_3926);
+/// assert_eq!(*Crc32::compute(b"123456789"), 0xCBF4_3926);
+/// ```
+pub trait Checksum {
+    /// The value of the checksum.
+    ///
+    /// This is usually an encodable number that already defines the byte
+    /// order the checksum is transmitted in, such as
+    /// [`BE<u16>`](crate::combinators::BE).
+    type Output;
+
+    /// Updates the checksum with the given bytes.
+    fn update(&mut self, bytes: &[u8]);
+    /// Returns the checksum of all the bytes seen so far.
+    fn finish(&self) -> Self::Output;
+    /// Computes the checksum of the given bytes.
+    #[inline]
+    #[must_use]
+    fn compute(bytes: &[u8]) -> Self::Output
+    where
+        Self: Default,
+    {
+        let mut checksum = Self::default();
+        checksum.update(bytes);
+        checksum.finish()
+    }
+}
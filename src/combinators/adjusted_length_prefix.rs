@@ -0,0 +1,425 @@
+use core::borrow::Borrow;
+use core::fmt::Debug;
+use core::marker::PhantomData;
+use core::ops::Deref;
+
+use super::LengthOutOfRange;
+use crate::encoders::SizeEncoder;
+use crate::EncodableSize;
+
+/// Encodes a length prefixed value, where the encoded length is adjusted
+/// before being written.
+///
+/// Works like [`LengthPrefix`](super::LengthPrefix), but the written length
+/// can also account for bytes other than the value itself:
+///
+/// - [`including_prefix`](Self::including_prefix) adds the size of the
+///   length prefix itself, as required by formats such as
+///   [BSON](https://bsonspec.org/spec.html) or the `PostgreSQL` wire protocol.
+///   Variable-length prefixes are supported.
+/// - [`with_adjustment`](Self::with_adjustment) adds a constant number of
+///   bytes to the total, after the size of the prefix, for formats where the
+///   length covers fields that are encoded elsewhere. Negative adjustments are allowed for formats where the length
+///   excludes some bytes of the value, such as a trailing checksum.
+///
+/// If the adjusted length is negative or overflows a [`usize`], encoding fails
+/// with [`LengthOutOfRange`].
+///
+/// # Examples
+///
+/// ```rust
+/// # #[cfg(feature = "alloc")] {
+/// use encode::Encodable;
+/// use encode::combinators::{AdjustedLengthPrefix, LengthOutOfRange, BE};
+/// use core::num::TryFromIntError;
+///
+/// #[derive(Debug, PartialEq)]
+/// enum Error {
+///     TooLarge,
+///     OutOfRange,
+/// }
+/// # impl From<TryFromIntError> for Error { fn from(_: TryFromIntError) -> Self { Error::TooLarge } }
+/// # impl From<LengthOutOfRange> for Error { fn from(_: LengthOutOfRange) -> Self { Error::OutOfRange } }
+/// # impl From<core::convert::Infallible> for Error { fn from(err: core::convert::Infallible) -> Self { match err {} } }
+///
+/// let mut buf = Vec::new();
+/// AdjustedLengthPrefix::<_, BE<u32>, Error>::new("hello")
+///     .including_prefix()
+///     .encode(&mut buf)
+///     .unwrap();
+/// assert_eq!(&buf, b"\x00\x00\x00\x09hello", "4 bytes for the length and 5 for the string");
+///
+/// buf.clear();
+/// AdjustedLengthPrefix::<_, u8, Error>::new("hello")
+///     .with_adjustment(2)
+///     .encode(&mut buf)
+///     .unwrap();
+/// assert_eq!(&buf, b"\x07hello");
+///
+/// buf.clear();
+/// AdjustedLengthPrefix::<_, u8, Error>::new("hello")
+///     .with_adjustment(-2)
+///     .encode(&mut buf)
+///     .unwrap();
+/// assert_eq!(&buf, b"\x03hello");
+///
+/// let negative = AdjustedLengthPrefix::<_, u8, Error>::new("hello").with_adjustment(-6);
+/// assert_eq!(negative.encode(&mut buf), Err(Error::OutOfRange));
+/// # }
+/// ```
+#[doc(alias("length", "prefix", "TLV"))]
+pub struct AdjustedLengthPrefix<Encodable, Length, Error> {
+    encodable: Encodable,
+    including_prefix: bool,
+    adjustment: isize,
+    phantom: PhantomData<(Length, Error)>,
+}
+
+impl<Encodable, Length, Error> AdjustedLengthPrefix<Encodable, Length, Error> {
+    /// Creates a new [`AdjustedLengthPrefix`] combinator.
+    ///
+    /// Until adjusted, it encodes the same length as a
+    /// [`LengthPrefix`](super::LengthPrefix).
+    #[inline]
+    #[must_use]
+    pub const fn new(encodable: Encodable) -> Self {
+        Self {
+            encodable,
+            including_prefix: false,
+            adjustment: 0,
+            phantom: PhantomData,
+        }
+    }
+    /// Includes the size of the length prefix itself in the encoded length.
+    #[inline]
+    #[must_use]
+    pub const fn including_prefix(mut self) -> Self {
+        self.including_prefix = true;
+        self
+    }
+    /// Adds a constant number of bytes to the encoded length.
+    ///
+    /// The adjustment can be negative to exclude bytes of the value from the
+    /// length.
+    #[inline]
+    #[must_use]
+    pub const fn with_adjustment(mut self, adjustment: isize) -> Self {
+        self.adjustment = adjustment;
+        self
+    }
+    /// Consumes the combinator and returns the inner encodable.
+    #[inline]
+    #[must_use]
+    pub fn into_inner(self) -> Encodable {
+        self.encodable
+    }
+}
+
+impl<Encodable, Length, Error> AsRef<Encodable> for AdjustedLengthPrefix<Encodable, Length, Error> {
+    #[inline]
+    fn as_ref(&self) -> &Encodable {
+        &self.encodable
+    }
+}
+
+impl<Encodable, Length, Error> Deref for AdjustedLengthPrefix<Encodable, Length, Error> {
+    type Target = Encodable;
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        self.as_ref()
+    }
+}
+
+impl<Encodable, Length, Error> Borrow<Encodable>
+    for AdjustedLengthPrefix<Encodable, Length, Error>
+{
+    #[inline]
+    fn borrow(&self) -> &Encodable {
+        &self.encodable
+    }
+}
+
+impl<Encodable, Length, Encoder, Error> crate::Encodable<Encoder>
+    for AdjustedLengthPrefix<Encodable, Length, Error>
+where
+    Encoder: crate::BaseEncoder,
+    Encodable: crate::Encodable<Encoder> + EncodableSize,
+    Length: crate::Encodable<Encoder> + EncodableSize + TryFrom<usize>,
+    Error: From<<Length as crate::Encodable<Encoder>>::Error>
+        + From<<Length as crate::Encodable<SizeEncoder>>::Error>
+        + From<<Length as TryFrom<usize>>::Error>
+        + From<<Encodable as crate::Encodable<Encoder>>::Error>
+        + From<<Encodable as crate::Encodable<SizeEncoder>>::Error>
+        + From<Encoder::Error>
+        + From<LengthOutOfRange>,
+{
+    type Error = Error;
+
+    #[inline]
+    fn encode(&self, encoder: &mut Encoder) -> Result<(), Self::Error> {
+        let value_size = self.encodable.encoded_size()?;
+        let length = |prefix_size: usize| {
+            value_size
+                .checked_add(prefix_size)
+                .and_then(|len| len.checked_add_signed(self.adjustment))
+                .ok_or(LengthOutOfRange)
+        };
+
+        let mut prefix_size = 0;
+        if self.including_prefix {
+            // The size of variable-length prefixes depends on the length
+            // itself, so start from the smallest prefix and grow it until its
+            // size settles.
+            prefix_size = Length::try_from(0)?.encoded_size()?;
+            loop {
+                let size = Length::try_from(length(prefix_size)?)?.encoded_size()?;
+                if size == prefix_size {
+                    break;
+                }
+                prefix_size = size;
+            }
+        }
+
+        let len_encoder = Length::try_from(length(prefix_size)?)?;
+        len_encoder.encode(encoder)?;
+        self.encodable.encode(encoder)?;
+        Ok(())
+    }
+}
+
+// Manual trait implementations because the derive macro does not support
+// phantom data fields.
+impl<Encodable, Length, Error> Debug for AdjustedLengthPrefix<Encodable, Length, Error>
+where
+    Encodable: Debug,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("AdjustedLengthPrefix")
+            .field("encodable", &self.encodable)
+            .field("including_prefix", &self.including_prefix)
+            .field("adjustment", &self.adjustment)
+            .finish()
+    }
+}
+impl<Encodable, Length, Error> Clone for AdjustedLengthPrefix<Encodable, Length, Error>
+where
+    Encodable: Clone,
+{
+    #[inline]
+    fn clone(&self) -> Self {
+        Self {
+            encodable: self.encodable.clone(),
+            including_prefix: self.including_prefix,
+            adjustment: self.adjustment,
+            phantom: PhantomData,
+        }
+    }
+}
+impl<Encodable, Length, Error> Copy for AdjustedLengthPrefix<Encodable, Length, Error> where
+    Encodable: Copy
+{
+}
+impl<Encodable, Length, Error> Default for AdjustedLengthPrefix<Encodable, Length, Error>
+where
+    Encodable: Default,
+{
+    #[inline]
+    fn default() -> Self {
+        Self::new(Default::default())
+    }
+}
+impl<Encodable, Length, Error> PartialEq for AdjustedLengthPrefix<Encodable, Length, Error>
+where
+    Encodable: PartialEq,
+{
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.encodable == other.encodable
+            && self.including_prefix == other.including_prefix
+            && self.adjustment == other.adjustment
+    }
+}
+impl<Encodable, Length, Error> Eq for AdjustedLengthPrefix<Encodable, Length, Error> where
+    Encodable: Eq
+{
+}
+impl<Encodable, Length, Error> PartialOrd for AdjustedLengthPrefix<Encodable, Length, Error>
+where
+    Encodable: PartialOrd,
+{
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+        match self.encodable.partial_cmp(&other.encodable) {
+            Some(core::cmp::Ordering::Equal) => {}
+            ordering => return ordering,
+        }
+        Some(
+            (self.including_prefix, self.adjustment)
+                .cmp(&(other.including_prefix, other.adjustment)),
+        )
+    }
+}
+impl<Encodable, Length, Error> Ord for AdjustedLengthPrefix<Encodable, Length, Error>
+where
+    Encodable: Ord,
+{
+    #[inline]
+    fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+        self.encodable.cmp(&other.encodable).then_with(|| {
+            (self.including_prefix, self.adjustment)
+                .cmp(&(other.including_prefix, other.adjustment))
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::borrow::Borrow;
+    use core::num::TryFromIntError;
+
+    use rstest::rstest;
+
+    use super::*;
+    use crate::combinators::Uleb128;
+    use crate::combinators::LE;
+    use crate::Encodable;
+
+    const BUF_SIZE: usize = 256;
+
+    #[derive(Debug, PartialEq)]
+    enum Error {
+        InsufficientSpace,
+        TooLarge,
+        OutOfRange,
+    }
+
+    impl From<LengthOutOfRange> for Error {
+        fn from(_: LengthOutOfRange) -> Self {
+            Error::OutOfRange
+        }
+    }
+
+    impl From<crate::encoders::InsufficientSpace> for Error {
+        fn from(_: crate::encoders::InsufficientSpace) -> Self {
+            Error::InsufficientSpace
+        }
+    }
+
+    impl From<TryFromIntError> for Error {
+        fn from(_: TryFromIntError) -> Self {
+            Error::TooLarge
+        }
+    }
+
+    impl From<core::convert::Infallible> for Error {
+        fn from(err: core::convert::Infallible) -> Self {
+            match err {}
+        }
+    }
+
+    #[rstest]
+    #[case::unadjusted(false, 0, b"\x05\x00\x00\x00hello")]
+    #[case::including_prefix(true, 0, b"\x09\x00\x00\x00hello")]
+    #[case::adjusted(false, 3, b"\x08\x00\x00\x00hello")]
+    #[case::both(true, 3, b"\x0C\x00\x00\x00hello")]
+    #[case::negative(false, -2, b"\x03\x00\x00\x00hello")]
+    #[case::negative_including_prefix(true, -5, b"\x04\x00\x00\x00hello")]
+    #[case::cancels_the_prefix(true, -9, b"\x00\x00\x00\x00hello")]
+    fn assert_that_the_length_is_adjusted(
+        #[case] including_prefix: bool,
+        #[case] adjustment: isize,
+        #[case] expected: &[u8],
+    ) {
+        let mut encodable =
+            AdjustedLengthPrefix::<_, LE<i32>, Error>::new("hello").with_adjustment(adjustment);
+        if including_prefix {
+            encodable = encodable.including_prefix();
+        }
+
+        let mut buf = [0u8; BUF_SIZE];
+        let mut encoder = &mut buf as &mut [u8];
+        encodable.encode(&mut encoder).unwrap();
+        let written = BUF_SIZE - encoder.len();
+
+        assert_eq!(&buf[..written], expected);
+    }
+
+    #[rstest]
+    #[case::fits_in_one_byte(126, &[0x7F])]
+    #[case::grows_with_the_prefix(127, &[0x81, 0x01])]
+    #[case::two_bytes(128, &[0x82, 0x01])]
+    fn assert_that_variable_length_prefixes_include_their_own_size(
+        #[case] len: usize,
+        #[case] expected_prefix: &[u8],
+    ) {
+        let body = [0xAAu8; 128];
+        let encodable =
+            AdjustedLengthPrefix::<_, Uleb128<u32>, Error>::new(&body[..len]).including_prefix();
+
+        let mut buf = [0u8; BUF_SIZE];
+        let mut encoder = &mut buf as &mut [u8];
+        encodable.encode(&mut encoder).unwrap();
+        let written = BUF_SIZE - encoder.len();
+
+        assert_eq!(&buf[..expected_prefix.len()], expected_prefix);
+        assert_eq!(written, len + expected_prefix.len());
+    }
+
+    #[test]
+    fn assert_that_length_overflows_are_reported() {
+        let encodable = AdjustedLengthPrefix::<_, u8, Error>::new([0u8; 255]).including_prefix();
+
+        let mut buf = [0u8; BUF_SIZE];
+        let mut encoder = &mut buf as &mut [u8];
+
+        assert_eq!(encodable.encode(&mut encoder), Err(Error::TooLarge));
+    }
+
+    #[test]
+    fn assert_that_negative_lengths_are_reported() {
+        let encodable = AdjustedLengthPrefix::<_, u8, Error>::new("hello").with_adjustment(-6);
+
+        let mut buf = [0u8; BUF_SIZE];
+        let mut encoder = &mut buf as &mut [u8];
+
+        assert_eq!(encodable.encode(&mut encoder), Err(Error::OutOfRange));
+    }
+
+    #[test]
+    fn assert_that_the_adjustment_applies_to_the_prefix_and_value() {
+        let encodable = AdjustedLengthPrefix::<_, u8, Error>::new("hello")
+            .including_prefix()
+            .with_adjustment(-6);
+
+        let mut buf = [0u8; BUF_SIZE];
+        let mut encoder = &mut buf as &mut [u8];
+        encodable.encode(&mut encoder).unwrap();
+        let written = BUF_SIZE - encoder.len();
+
+        assert_eq!(&buf[..written], b"\x00hello");
+    }
+
+    #[test]
+    fn assert_that_adjusted_length_prefix_accessors_work() {
+        let lp = AdjustedLengthPrefix::<u8, u8, Error>::new(42u8);
+        let borrowed: &u8 = lp.borrow();
+        assert_eq!(*borrowed, 42u8);
+        assert_eq!(lp.as_ref(), &42u8);
+        assert_eq!(*lp, 42u8);
+        assert_eq!(lp.into_inner(), 42u8);
+    }
+
+    #[test]
+    fn assert_that_adjusted_length_prefix_equality_considers_the_adjustment() {
+        let lp = AdjustedLengthPrefix::<u8, u8, Error>::new(42u8);
+        assert_eq!(lp, Clone::clone(&lp));
+        assert_ne!(lp, lp.including_prefix());
+        assert_ne!(lp, lp.with_adjustment(1));
+        assert!(lp < lp.with_adjustment(1));
+        assert_eq!(lp, AdjustedLengthPrefix::new(42u8));
+        assert_eq!(
+            AdjustedLengthPrefix::<u8, u8, Error>::default(),
+            AdjustedLengthPrefix::new(0)
+        );
+    }
+}
@@ -0,0 +1,119 @@
+use super::pad::put_fill;
+use crate::ByteEncoder;
+use crate::Encodable;
+use crate::PositionedEncoder;
+
+/// Pads the output with a fill byte until its position is a multiple of `N`.
+///
+/// [`Align`] can only be encoded into a [`PositionedEncoder`], such as
+/// [`Counting`](crate::encoders::Counting). The position is relative to the
+/// start of the output of the encoder, so the padding depends on where the
+/// data ends up. For this reason [`Align`] does not implement
+/// [`EncodableSize`](crate::EncodableSize), and cannot be nested inside
+/// combinators that measure their contents up front, such as
+/// [`LengthPrefix`](crate::combinators::LengthPrefix). The fill byte defaults
+/// to `0`.
+///
+/// # Examples
+///
+/// ```rust
+/// use encode::Encodable;
+/// use encode::combinators::Align;
+/// use encode::encoders::Counting;
+///
+/// let mut buf = [0xFFu8; 8];
+/// let mut encoder = Counting::new(&mut buf as &mut [u8]);
+/// ("abc", Align::<4>::new(), 'd', Align::<4>::new()).encode(&mut encoder).unwrap();
+/// assert_eq!(buf, *b"abc\0d\0\0\0");
+/// ```
+#[doc(alias("alignment", "padding"))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Align<const N: usize> {
+    fill: u8,
+}
+
+impl<const N: usize> Align<N> {
+    /// Creates a new [`Align`] combinator.
+    #[inline]
+    #[must_use]
+    pub const fn new() -> Self {
+        Self { fill: 0 }
+    }
+    /// Sets the byte used for padding.
+    #[inline]
+    #[must_use]
+    pub const fn with_fill(mut self, fill: u8) -> Self {
+        self.fill = fill;
+        self
+    }
+    /// Returns the number of bytes needed to align `position` to `N`.
+    #[inline]
+    #[must_use]
+    pub const fn padding(position: usize) -> usize {
+        const {
+            assert!(N > 0, "cannot align to a multiple of zero");
+        }
+        (N - position % N) % N
+    }
+}
+
+impl<E, const N: usize> Encodable<E> for Align<N>
+where
+    E: ByteEncoder + PositionedEncoder,
+{
+    type Error = E::Error;
+
+    #[inline]
+    fn encode(&self, encoder: &mut E) -> Result<(), Self::Error> {
+        let padding = Self::padding(encoder.position());
+        put_fill(encoder, self.fill, padding)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+    use crate::encoders::Counting;
+
+    const BUF_SIZE: usize = 1024;
+
+    #[rstest]
+    #[case::aligned(0, 0)]
+    #[case::one_past(1, 3)]
+    #[case::one_before(3, 1)]
+    #[case::next_block(4, 0)]
+    #[case::far(13, 3)]
+    fn assert_that_the_padding_reaches_the_next_multiple(
+        #[case] position: usize,
+        #[case] expected: usize,
+    ) {
+        assert_eq!(Align::<4>::padding(position), expected);
+    }
+
+    #[test]
+    fn assert_that_blocks_are_padded_with_the_fill_byte() {
+        let mut buf = [0u8; BUF_SIZE];
+        let mut encoder = Counting::new(&mut buf as &mut [u8]);
+        ("header", Align::<512>::new().with_fill(b' '))
+            .encode(&mut encoder)
+            .unwrap();
+
+        assert_eq!(encoder.written(), 512);
+        assert_eq!(&buf[..6], b"header");
+        assert!(buf[6..512].iter().all(|&byte| byte == b' '));
+    }
+
+    #[test]
+    fn assert_that_alignment_is_relative_to_the_start_of_the_output() {
+        let mut buf = [0u8; BUF_SIZE];
+        let mut encoder = Counting::new(&mut buf as &mut [u8]);
+        ("abcde", Align::<8>::new(), 'x', Align::<2>::new())
+            .encode(&mut encoder)
+            .unwrap();
+
+        assert_eq!(encoder.written(), 10);
+        assert_eq!(&buf[..10], b"abcde\0\0\0x\0");
+    }
+}
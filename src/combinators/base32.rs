@@ -0,0 +1,277 @@
+use core::borrow::Borrow;
+use core::fmt::Debug;
+use core::marker::PhantomData;
+use core::ops::Deref;
+
+use crate::encoders::Base32Alphabet;
+use crate::encoders::Base32Encoder;
+use crate::StrEncoder;
+
+/// Encodes the bytes of an encodable as Base32 text.
+///
+/// The encodable is encoded through a [`Base32Encoder`], so its bytes are
+/// converted on the fly without an intermediate allocation, and the output
+/// only requires a [`StrEncoder`].
+///
+/// By default the alphabet of [RFC 4648](https://www.rfc-editor.org/rfc/rfc4648)
+/// is used and the output is padded with `=`. Use
+/// [`with_alphabet`](Base32::with_alphabet) for the lowercase or Crockford
+/// alphabets, and [`with_padding`](Base32::with_padding) to leave out the
+/// padding.
+///
+/// # Examples
+///
+/// ```rust
+/// # #[cfg(feature = "alloc")] {
+/// use core::convert::Infallible;
+/// use encode::Encodable;
+/// use encode::combinators::Base32;
+/// use encode::encoders::Base32Alphabet;
+///
+/// // A TOTP secret, as found in `otpauth://` URIs.
+/// let mut text = String::new();
+/// Base32::<_, Infallible>::new(b"12345678901234567890")
+///     .with_padding(false)
+///     .encode(&mut text)
+///     .unwrap();
+/// assert_eq!(text, "GEZDGNBVGY3TQOJQGEZDGNBVGY3TQOJQ");
+///
+/// text.clear();
+/// Base32::<_, Infallible>::new("foobar")
+///     .with_alphabet(Base32Alphabet::Crockford)
+///     .encode(&mut text)
+///     .unwrap();
+/// assert_eq!(text, "CSQPYRK1E8");
+/// # }
+/// ```
+#[doc(alias("rfc4648", "crockford", "b32"))]
+pub struct Base32<Encodable, Error> {
+    encodable: Encodable,
+    alphabet: Base32Alphabet,
+    padding: bool,
+    phantom: PhantomData<Error>,
+}
+
+impl<Encodable, Error> Base32<Encodable, Error> {
+    /// Creates a new [`Base32`] combinator that uses the RFC 4648 alphabet
+    /// with padding.
+    #[inline]
+    #[must_use]
+    pub const fn new(encodable: Encodable) -> Self {
+        Self {
+            encodable,
+            alphabet: Base32Alphabet::Rfc4648,
+            padding: true,
+            phantom: PhantomData,
+        }
+    }
+    /// Sets the alphabet used by the combinator.
+    #[inline]
+    #[must_use]
+    pub const fn with_alphabet(mut self, alphabet: Base32Alphabet) -> Self {
+        self.alphabet = alphabet;
+        self
+    }
+    /// Sets whether the output is padded with `=`.
+    ///
+    /// The [`Crockford`](Base32Alphabet::Crockford) alphabet is never padded.
+    #[inline]
+    #[must_use]
+    pub const fn with_padding(mut self, padding: bool) -> Self {
+        self.padding = padding;
+        self
+    }
+    /// Consumes the combinator and returns the inner encodable.
+    #[inline]
+    #[must_use]
+    pub fn into_inner(self) -> Encodable {
+        self.encodable
+    }
+}
+
+impl<Encodable, Error> From<Encodable> for Base32<Encodable, Error> {
+    #[inline]
+    fn from(value: Encodable) -> Self {
+        Self::new(value)
+    }
+}
+
+impl<Encodable, Error> AsRef<Encodable> for Base32<Encodable, Error> {
+    #[inline]
+    fn as_ref(&self) -> &Encodable {
+        &self.encodable
+    }
+}
+
+impl<Encodable, Error> Deref for Base32<Encodable, Error> {
+    type Target = Encodable;
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        self.as_ref()
+    }
+}
+
+impl<Encodable, Error> Borrow<Encodable> for Base32<Encodable, Error> {
+    #[inline]
+    fn borrow(&self) -> &Encodable {
+        &self.encodable
+    }
+}
+
+impl<Encodable, Encoder, Error> crate::Encodable<Encoder> for Base32<Encodable, Error>
+where
+    Encoder: StrEncoder,
+    for<'a> Encodable: crate::Encodable<Base32Encoder<'a, Encoder>>,
+    for<'a> Error: From<<Encodable as crate::Encodable<Base32Encoder<'a, Encoder>>>::Error>,
+    Error: From<Encoder::Error>,
+{
+    type Error = Error;
+
+    #[inline]
+    fn encode(&self, encoder: &mut Encoder) -> Result<(), Self::Error> {
+        let mut base32 = Base32Encoder::new(encoder)
+            .with_alphabet(self.alphabet)
+            .with_padding(self.padding);
+        self.encodable.encode(&mut base32)?;
+        base32.finish()?;
+        Ok(())
+    }
+}
+
+// Manual trait implementations because the derive macro does not support
+// phantom data fields.
+impl<Encodable, Error> Debug for Base32<Encodable, Error>
+where
+    Encodable: Debug,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Base32")
+            .field("encodable", &self.encodable)
+            .field("alphabet", &self.alphabet)
+            .field("padding", &self.padding)
+            .finish()
+    }
+}
+impl<Encodable, Error> Clone for Base32<Encodable, Error>
+where
+    Encodable: Clone,
+{
+    #[inline]
+    fn clone(&self) -> Self {
+        Self::new(self.encodable.clone())
+            .with_alphabet(self.alphabet)
+            .with_padding(self.padding)
+    }
+}
+impl<Encodable, Error> Copy for Base32<Encodable, Error> where Encodable: Copy {}
+impl<Encodable, Error> Default for Base32<Encodable, Error>
+where
+    Encodable: Default,
+{
+    #[inline]
+    fn default() -> Self {
+        Self::new(Default::default())
+    }
+}
+impl<Encodable, Error> PartialEq for Base32<Encodable, Error>
+where
+    Encodable: PartialEq,
+{
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.encodable == other.encodable
+            && self.alphabet == other.alphabet
+            && self.padding == other.padding
+    }
+}
+impl<Encodable, Error> Eq for Base32<Encodable, Error> where Encodable: Eq {}
+
+#[cfg(test)]
+mod tests {
+    use core::borrow::Borrow;
+    use core::convert::Infallible;
+
+    use rstest::rstest;
+
+    use super::*;
+    use crate::combinators::BE;
+    use crate::encoders::InsufficientSpace;
+    use crate::Encodable;
+    use crate::EncodableSize;
+
+    const BUF_SIZE: usize = 64;
+
+    #[rstest]
+    #[case::rfc4648(Base32Alphabet::Rfc4648, true, b"MZXW6YQAAE======")]
+    #[case::rfc4648_unpadded(Base32Alphabet::Rfc4648, false, b"MZXW6YQAAE")]
+    #[case::lowercase(Base32Alphabet::Rfc4648Lowercase, true, b"mzxw6yqaae======")]
+    #[case::crockford(Base32Alphabet::Crockford, true, b"CSQPYRG004")]
+    fn assert_that_byte_encodables_are_written_as_base32(
+        #[case] alphabet: Base32Alphabet,
+        #[case] padding: bool,
+        #[case] expected: &[u8],
+    ) {
+        let encodable = Base32::<_, InsufficientSpace>::new(("foob", BE::new(1u16)))
+            .with_alphabet(alphabet)
+            .with_padding(padding);
+
+        let mut buf = [0u8; BUF_SIZE];
+        let mut encoder = &mut buf as &mut [u8];
+        encodable.encode(&mut encoder).unwrap();
+        let written = BUF_SIZE - encoder.len();
+
+        assert_eq!(&buf[..written], expected);
+    }
+
+    #[rstest]
+    #[case::padded(true, 16)]
+    #[case::unpadded(false, 10)]
+    fn assert_that_the_encoded_size_is_exact(#[case] padding: bool, #[case] expected: usize) {
+        let encodable = Base32::<_, Infallible>::new("foobar").with_padding(padding);
+        assert_eq!(encodable.encoded_size(), Ok(expected));
+    }
+
+    #[test]
+    fn assert_that_encoder_errors_are_propagated() {
+        let mut buf = [0u8; 4];
+        let mut encoder = &mut buf as &mut [u8];
+
+        assert_eq!(
+            Base32::<_, InsufficientSpace>::new("hello").encode(&mut encoder),
+            Err(InsufficientSpace)
+        );
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn assert_that_inner_errors_are_converted() {
+        use alloc::string::String;
+
+        #[derive(Debug, PartialEq)]
+        struct Error;
+
+        impl From<Infallible> for Error {
+            fn from(err: Infallible) -> Self {
+                match err {}
+            }
+        }
+
+        let mut text = String::new();
+        assert_eq!(Base32::<_, Error>::new(&b"f"[..]).encode(&mut text), Ok(()));
+        assert_eq!(text, "MY======");
+    }
+
+    #[test]
+    fn assert_that_base32_accessors_work() {
+        let base32 = Base32::<u8, Infallible>::from(42u8);
+        let borrowed: &u8 = base32.borrow();
+        assert_eq!(*borrowed, 42u8);
+        assert_eq!(base32.as_ref(), &42u8);
+        assert_eq!(*base32, 42u8);
+        assert_eq!(base32, Clone::clone(&base32));
+        assert_ne!(base32, base32.with_padding(false));
+        assert_ne!(base32, base32.with_alphabet(Base32Alphabet::Crockford));
+        assert_eq!(base32.into_inner(), 42u8);
+        assert_eq!(Base32::<u8, Infallible>::default().into_inner(), 0u8);
+    }
+}
@@ -0,0 +1,301 @@
+use core::borrow::Borrow;
+use core::fmt::Debug;
+use core::marker::PhantomData;
+use core::ops::Deref;
+
+use crate::encoders::Base58Encoder;
+use crate::encoders::Base58Error;
+use crate::StrEncoder;
+
+/// Encodes the bytes of an encodable as
+/// [Base58](https://en.wikipedia.org/wiki/Binary-to-text_encoding#Base58)
+/// text, using the Bitcoin alphabet.
+///
+/// The encodable is encoded through a [`Base58Encoder`], which keeps the
+/// digits in a bounded buffer instead of allocating, so values longer than
+/// 93 bytes fail with [`Base58Error::CapacityExceeded`]. The output only requires
+/// a [`StrEncoder`].
+///
+/// With [`with_check`](Base58::with_check), the checksum trailer of
+/// `Base58Check` is computed and appended to the value before it is encoded.
+/// This requires the `sha2` feature.
+///
+/// # Examples
+///
+/// ```rust
+/// # #[cfg(feature = "alloc")] {
+/// use core::convert::Infallible;
+/// use encode::Encodable;
+/// use encode::combinators::Base58;
+/// use encode::encoders::Base58Error;
+///
+/// let mut text = String::new();
+/// Base58::<_, Base58Error<Infallible>>::new("Hello World!").encode(&mut text).unwrap();
+/// assert_eq!(text, "2NEpo7TZRRrLZSi2U");
+/// # }
+/// ```
+///
+/// ```rust
+/// # #[cfg(all(feature = "alloc", feature = "sha2"))] {
+/// use core::convert::Infallible;
+/// use encode::Encodable;
+/// use encode::combinators::Base58;
+/// use encode::encoders::Base58Error;
+///
+/// // A version byte followed by a public key hash is a Bitcoin address.
+/// let mut text = String::new();
+/// Base58::<_, Base58Error<Infallible>>::new((0u8, [0u8; 20]))
+///     .with_check()
+///     .encode(&mut text)
+///     .unwrap();
+/// assert_eq!(text, "1111111111111111111114oLvT2");
+/// # }
+/// ```
+#[doc(alias("base58check", "b58"))]
+pub struct Base58<Encodable, Error> {
+    encodable: Encodable,
+    check: bool,
+    phantom: PhantomData<Error>,
+}
+
+impl<Encodable, Error> Base58<Encodable, Error> {
+    /// Creates a new [`Base58`] combinator.
+    #[inline]
+    #[must_use]
+    pub const fn new(encodable: Encodable) -> Self {
+        Self {
+            encodable,
+            check: false,
+            phantom: PhantomData,
+        }
+    }
+    /// Makes the combinator append the `Base58Check` checksum to the value.
+    #[cfg(feature = "sha2")]
+    #[inline]
+    #[must_use]
+    pub const fn with_check(mut self) -> Self {
+        self.check = true;
+        self
+    }
+    /// Consumes the combinator and returns the inner encodable.
+    #[inline]
+    #[must_use]
+    pub fn into_inner(self) -> Encodable {
+        self.encodable
+    }
+}
+
+impl<Encodable, Error> From<Encodable> for Base58<Encodable, Error> {
+    #[inline]
+    fn from(value: Encodable) -> Self {
+        Self::new(value)
+    }
+}
+
+impl<Encodable, Error> AsRef<Encodable> for Base58<Encodable, Error> {
+    #[inline]
+    fn as_ref(&self) -> &Encodable {
+        &self.encodable
+    }
+}
+
+impl<Encodable, Error> Deref for Base58<Encodable, Error> {
+    type Target = Encodable;
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        self.as_ref()
+    }
+}
+
+impl<Encodable, Error> Borrow<Encodable> for Base58<Encodable, Error> {
+    #[inline]
+    fn borrow(&self) -> &Encodable {
+        &self.encodable
+    }
+}
+
+impl<Encodable, Encoder, Error> crate::Encodable<Encoder> for Base58<Encodable, Error>
+where
+    Encoder: StrEncoder,
+    for<'a> Encodable: crate::Encodable<Base58Encoder<'a, Encoder>>,
+    for<'a> Error: From<<Encodable as crate::Encodable<Base58Encoder<'a, Encoder>>>::Error>,
+    Error: From<Base58Error<Encoder::Error>> + From<Encoder::Error>,
+{
+    type Error = Error;
+
+    #[inline]
+    fn encode(&self, encoder: &mut Encoder) -> Result<(), Self::Error> {
+        #[cfg(feature = "sha2")]
+        let mut base58 = if self.check {
+            Base58Encoder::new(encoder).with_check()
+        } else {
+            Base58Encoder::new(encoder)
+        };
+        #[cfg(not(feature = "sha2"))]
+        let mut base58 = Base58Encoder::new(encoder);
+        self.encodable.encode(&mut base58)?;
+        base58.finish()?;
+        Ok(())
+    }
+}
+
+// Manual trait implementations because the derive macro does not support
+// phantom data fields.
+impl<Encodable, Error> Debug for Base58<Encodable, Error>
+where
+    Encodable: Debug,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Base58")
+            .field("encodable", &self.encodable)
+            .field("check", &self.check)
+            .finish()
+    }
+}
+impl<Encodable, Error> Clone for Base58<Encodable, Error>
+where
+    Encodable: Clone,
+{
+    #[inline]
+    fn clone(&self) -> Self {
+        Self {
+            encodable: self.encodable.clone(),
+            check: self.check,
+            phantom: PhantomData,
+        }
+    }
+}
+impl<Encodable, Error> Copy for Base58<Encodable, Error> where Encodable: Copy {}
+impl<Encodable, Error> Default for Base58<Encodable, Error>
+where
+    Encodable: Default,
+{
+    #[inline]
+    fn default() -> Self {
+        Self::new(Default::default())
+    }
+}
+impl<Encodable, Error> PartialEq for Base58<Encodable, Error>
+where
+    Encodable: PartialEq,
+{
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.encodable == other.encodable && self.check == other.check
+    }
+}
+impl<Encodable, Error> Eq for Base58<Encodable, Error> where Encodable: Eq {}
+
+#[cfg(test)]
+mod tests {
+    use core::borrow::Borrow;
+    use core::convert::Infallible;
+
+    use rstest::rstest;
+
+    use super::*;
+    use crate::combinators::BE;
+    use crate::encoders::InsufficientSpace;
+    use crate::Encodable;
+    use crate::EncodableSize;
+
+    const BUF_SIZE: usize = 256;
+
+    type Bytes = (u8, BE<u32>);
+
+    #[rstest]
+    #[case::plain(Base58::new((0u8, BE::new(0x0102_0304))), b"12VfUX")]
+    #[cfg_attr(
+        feature = "sha2",
+        case::checked(Base58::new((0u8, BE::new(0x0102_0304))).with_check(), b"1An6UhWF92g")
+    )]
+    fn assert_that_byte_encodables_are_written_as_base58(
+        #[case] encodable: Base58<Bytes, Base58Error<InsufficientSpace>>,
+        #[case] expected: &[u8],
+    ) {
+        let mut buf = [0u8; BUF_SIZE];
+        let mut encoder = &mut buf as &mut [u8];
+        encodable.encode(&mut encoder).unwrap();
+        let written = BUF_SIZE - encoder.len();
+
+        assert_eq!(&buf[..written], expected);
+    }
+
+    #[rstest]
+    #[case::plain(Base58::new("Hello World!"), 17)]
+    #[cfg_attr(feature = "sha2", case::checked(Base58::new("Hello World!").with_check(), 22))]
+    fn assert_that_the_encoded_size_is_exact(
+        #[case] encodable: Base58<&str, Base58Error<Infallible>>,
+        #[case] expected: usize,
+    ) {
+        assert_eq!(encodable.encoded_size(), Ok(expected));
+    }
+
+    #[test]
+    fn assert_that_long_values_exceed_the_buffer() {
+        let mut buf = [0u8; BUF_SIZE];
+        let mut encoder = &mut buf as &mut [u8];
+
+        assert_eq!(
+            Base58::<_, Base58Error<InsufficientSpace>>::new([0xFFu8; 94]).encode(&mut encoder),
+            Err(Base58Error::CapacityExceeded)
+        );
+    }
+
+    #[test]
+    fn assert_that_encoder_errors_are_propagated() {
+        let mut buf = [0u8; 4];
+        let mut encoder = &mut buf as &mut [u8];
+
+        assert_eq!(
+            Base58::<_, Base58Error<InsufficientSpace>>::new("hello").encode(&mut encoder),
+            Err(Base58Error::Encoder(InsufficientSpace))
+        );
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn assert_that_inner_errors_are_converted() {
+        use alloc::string::String;
+
+        #[derive(Debug, PartialEq)]
+        enum Error {
+            TooLong,
+        }
+
+        impl From<Base58Error<Infallible>> for Error {
+            fn from(err: Base58Error<Infallible>) -> Self {
+                match err {
+                    Base58Error::Encoder(err) => match err {},
+                    Base58Error::CapacityExceeded => Error::TooLong,
+                }
+            }
+        }
+
+        impl From<Infallible> for Error {
+            fn from(err: Infallible) -> Self {
+                match err {}
+            }
+        }
+
+        let mut text = String::new();
+        assert_eq!(
+            Base58::<_, Error>::new([0xFFu8; 94]).encode(&mut text),
+            Err(Error::TooLong)
+        );
+    }
+
+    #[test]
+    fn assert_that_base58_accessors_work() {
+        let base58 = Base58::<u8, Infallible>::from(42u8);
+        let borrowed: &u8 = base58.borrow();
+        assert_eq!(*borrowed, 42u8);
+        assert_eq!(base58.as_ref(), &42u8);
+        assert_eq!(*base58, 42u8);
+        assert_eq!(base58, Clone::clone(&base58));
+        #[cfg(feature = "sha2")]
+        assert_ne!(base58, base58.with_check());
+        assert_eq!(base58.into_inner(), 42u8);
+        assert_eq!(Base58::<u8, Infallible>::default().into_inner(), 0u8);
+    }
+}
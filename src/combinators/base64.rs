@@ -0,0 +1,300 @@
+use core::borrow::Borrow;
+use core::fmt::Debug;
+use core::marker::PhantomData;
+use core::ops::Deref;
+
+use crate::encoders::Base64Alphabet;
+use crate::encoders::Base64Encoder;
+use crate::StrEncoder;
+
+/// Encodes the bytes of an encodable as Base64 text.
+///
+/// The encodable is encoded through a [`Base64Encoder`], so its bytes are
+/// converted on the fly without an intermediate allocation. The output only
+/// requires a [`StrEncoder`], so the same value can be written as text into
+/// a [`String`](alloc::string::String) or a
+/// [`Formatter`](core::fmt::Formatter), as well as into any byte encoder.
+///
+/// By default the standard alphabet is used and the output is padded with
+/// `=`, as described in [RFC 4648](https://www.rfc-editor.org/rfc/rfc4648).
+/// Use [`with_alphabet`](Base64::with_alphabet) and
+/// [`with_padding`](Base64::with_padding) for the URL-safe and unpadded
+/// variants.
+///
+/// # Examples
+///
+/// ```rust
+/// # #[cfg(feature = "alloc")] {
+/// use core::convert::Infallible;
+/// use encode::Encodable;
+/// use encode::combinators::Base64;
+/// use encode::encoders::Base64Alphabet;
+///
+/// let mut text = String::new();
+/// Base64::<_, Infallible>::new("hello?").encode(&mut text).unwrap();
+/// assert_eq!(text, "aGVsbG8/");
+///
+/// let mut buf = Vec::new();
+/// Base64::<_, Infallible>::new("hello?")
+///     .with_alphabet(Base64Alphabet::UrlSafe)
+///     .encode(&mut buf)
+///     .unwrap();
+/// assert_eq!(&buf, b"aGVsbG8_");
+///
+/// buf.clear();
+/// Base64::<_, Infallible>::new("hi").with_padding(false).encode(&mut buf).unwrap();
+/// assert_eq!(&buf, b"aGk");
+/// # }
+/// ```
+#[doc(alias("base64url", "b64"))]
+pub struct Base64<Encodable, Error> {
+    encodable: Encodable,
+    alphabet: Base64Alphabet,
+    padding: bool,
+    phantom: PhantomData<Error>,
+}
+
+impl<Encodable, Error> Base64<Encodable, Error> {
+    /// Creates a new [`Base64`] combinator that uses the standard alphabet
+    /// with padding.
+    #[inline]
+    #[must_use]
+    pub const fn new(encodable: Encodable) -> Self {
+        Self {
+            encodable,
+            alphabet: Base64Alphabet::Standard,
+            padding: true,
+            phantom: PhantomData,
+        }
+    }
+    /// Sets the alphabet used by the combinator.
+    #[inline]
+    #[must_use]
+    pub const fn with_alphabet(mut self, alphabet: Base64Alphabet) -> Self {
+        self.alphabet = alphabet;
+        self
+    }
+    /// Sets whether the output is padded with `=`.
+    #[inline]
+    #[must_use]
+    pub const fn with_padding(mut self, padding: bool) -> Self {
+        self.padding = padding;
+        self
+    }
+    /// Consumes the combinator and returns the inner encodable.
+    #[inline]
+    #[must_use]
+    pub fn into_inner(self) -> Encodable {
+        self.encodable
+    }
+}
+
+impl<Encodable, Error> From<Encodable> for Base64<Encodable, Error> {
+    #[inline]
+    fn from(value: Encodable) -> Self {
+        Self::new(value)
+    }
+}
+
+impl<Encodable, Error> AsRef<Encodable> for Base64<Encodable, Error> {
+    #[inline]
+    fn as_ref(&self) -> &Encodable {
+        &self.encodable
+    }
+}
+
+impl<Encodable, Error> Deref for Base64<Encodable, Error> {
+    type Target = Encodable;
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        self.as_ref()
+    }
+}
+
+impl<Encodable, Error> Borrow<Encodable> for Base64<Encodable, Error> {
+    #[inline]
+    fn borrow(&self) -> &Encodable {
+        &self.encodable
+    }
+}
+
+impl<Encodable, Encoder, Error> crate::Encodable<Encoder> for Base64<Encodable, Error>
+where
+    Encoder: StrEncoder,
+    for<'a> Encodable: crate::Encodable<Base64Encoder<'a, Encoder>>,
+    for<'a> Error: From<<Encodable as crate::Encodable<Base64Encoder<'a, Encoder>>>::Error>,
+    Error: From<Encoder::Error>,
+{
+    type Error = Error;
+
+    #[inline]
+    fn encode(&self, encoder: &mut Encoder) -> Result<(), Self::Error> {
+        let mut base64 = Base64Encoder::new(encoder)
+            .with_alphabet(self.alphabet)
+            .with_padding(self.padding);
+        self.encodable.encode(&mut base64)?;
+        base64.finish()?;
+        Ok(())
+    }
+}
+
+// Manual trait implementations because the derive macro does not support
+// phantom data fields.
+impl<Encodable, Error> Debug for Base64<Encodable, Error>
+where
+    Encodable: Debug,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Base64")
+            .field("encodable", &self.encodable)
+            .field("alphabet", &self.alphabet)
+            .field("padding", &self.padding)
+            .finish()
+    }
+}
+impl<Encodable, Error> Clone for Base64<Encodable, Error>
+where
+    Encodable: Clone,
+{
+    #[inline]
+    fn clone(&self) -> Self {
+        Self::new(self.encodable.clone())
+            .with_alphabet(self.alphabet)
+            .with_padding(self.padding)
+    }
+}
+impl<Encodable, Error> Copy for Base64<Encodable, Error> where Encodable: Copy {}
+impl<Encodable, Error> Default for Base64<Encodable, Error>
+where
+    Encodable: Default,
+{
+    #[inline]
+    fn default() -> Self {
+        Self::new(Default::default())
+    }
+}
+impl<Encodable, Error> PartialEq for Base64<Encodable, Error>
+where
+    Encodable: PartialEq,
+{
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.encodable == other.encodable
+            && self.alphabet == other.alphabet
+            && self.padding == other.padding
+    }
+}
+impl<Encodable, Error> Eq for Base64<Encodable, Error> where Encodable: Eq {}
+
+#[cfg(test)]
+mod tests {
+    use core::borrow::Borrow;
+    use core::convert::Infallible;
+
+    use rstest::rstest;
+
+    use super::*;
+    use crate::combinators::BE;
+    use crate::encoders::InsufficientSpace;
+    use crate::Encodable;
+    use crate::EncodableSize;
+
+    const BUF_SIZE: usize = 64;
+
+    #[rstest]
+    #[case::standard(Base64Alphabet::Standard, true, b"+/+/AAE=")]
+    #[case::standard_unpadded(Base64Alphabet::Standard, false, b"+/+/AAE")]
+    #[case::url_safe(Base64Alphabet::UrlSafe, true, b"-_-_AAE=")]
+    #[case::url_safe_unpadded(Base64Alphabet::UrlSafe, false, b"-_-_AAE")]
+    fn assert_that_byte_encodables_are_written_as_base64(
+        #[case] alphabet: Base64Alphabet,
+        #[case] padding: bool,
+        #[case] expected: &[u8],
+    ) {
+        let encodable = Base64::<_, InsufficientSpace>::new((b"\xFB\xFF\xBF", BE::new(1u16)))
+            .with_alphabet(alphabet)
+            .with_padding(padding);
+
+        let mut buf = [0u8; BUF_SIZE];
+        let mut encoder = &mut buf as &mut [u8];
+        encodable.encode(&mut encoder).unwrap();
+        let written = BUF_SIZE - encoder.len();
+
+        assert_eq!(&buf[..written], expected);
+    }
+
+    #[test]
+    fn assert_that_base64_can_be_written_into_a_formatter() {
+        struct Token<'a>(&'a str);
+
+        impl core::fmt::Display for Token<'_> {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                Base64::<_, core::fmt::Error>::new(self.0).encode(f)
+            }
+        }
+
+        let mut buf = [0u8; BUF_SIZE];
+        let mut encoder = &mut buf as &mut [u8];
+        format_args!("Basic {}", Token("user:pass"))
+            .encode(&mut encoder)
+            .unwrap();
+        let written = BUF_SIZE - encoder.len();
+
+        assert_eq!(&buf[..written], b"Basic dXNlcjpwYXNz");
+    }
+
+    #[rstest]
+    #[case::padded(true, 8)]
+    #[case::unpadded(false, 7)]
+    fn assert_that_the_encoded_size_is_exact(#[case] padding: bool, #[case] expected: usize) {
+        let encodable = Base64::<_, Infallible>::new("hello").with_padding(padding);
+        assert_eq!(encodable.encoded_size(), Ok(expected));
+    }
+
+    #[test]
+    fn assert_that_encoder_errors_are_propagated() {
+        let mut buf = [0u8; 4];
+        let mut encoder = &mut buf as &mut [u8];
+
+        assert_eq!(
+            Base64::<_, InsufficientSpace>::new("hello").encode(&mut encoder),
+            Err(InsufficientSpace)
+        );
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn assert_that_inner_errors_are_converted() {
+        use alloc::string::String;
+
+        #[derive(Debug, PartialEq)]
+        struct Error;
+
+        impl From<Infallible> for Error {
+            fn from(err: Infallible) -> Self {
+                match err {}
+            }
+        }
+
+        let mut text = String::new();
+        assert_eq!(
+            Base64::<_, Error>::new(&b"\xFB\xFF"[..]).encode(&mut text),
+            Ok(())
+        );
+        assert_eq!(text, "+/8=");
+    }
+
+    #[test]
+    fn assert_that_base64_accessors_work() {
+        let base64 = Base64::<u8, Infallible>::from(42u8);
+        let borrowed: &u8 = base64.borrow();
+        assert_eq!(*borrowed, 42u8);
+        assert_eq!(base64.as_ref(), &42u8);
+        assert_eq!(*base64, 42u8);
+        assert_eq!(base64, Clone::clone(&base64));
+        assert_ne!(base64, base64.with_padding(false));
+        assert_ne!(base64, base64.with_alphabet(Base64Alphabet::UrlSafe));
+        assert_eq!(base64.into_inner(), 42u8);
+        assert_eq!(Base64::<u8, Infallible>::default().into_inner(), 0u8);
+    }
+}
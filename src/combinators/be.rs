@@ -2,11 +2,19 @@ use core::borrow::Borrow;
 use core::num::NonZero;
 use core::ops::Deref;
 
+use super::PrefixWidth;
+use crate::decoders::NonZeroError;
+use crate::ByteDecoder;
 use crate::ByteEncoder;
+use crate::Decodable;
 use crate::Encodable;
 
 /// Encodes a number in big-endian order.
 ///
+/// Numbers can also be decoded from big-endian order. Decoding a
+/// [`NonZero`] number fails with [`NonZeroError::Zero`] if the decoded value is
+/// zero.
+///
 /// # Examples
 ///
 /// ```rust
@@ -19,6 +27,15 @@ use crate::Encodable;
 /// assert_eq!(&buf, &[0, 1], "Encoding a u16 in big-endian order means the most significant byte comes first");
 /// # }
 /// ```
+///
+/// ```rust
+/// use encode::Decodable;
+/// use encode::combinators::BE;
+///
+/// let mut input: &[u8] = &[0, 1];
+/// let num = BE::<u16>::decode(&mut input).unwrap();
+/// assert_eq!(*num, 1);
+/// ```
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
 #[repr(transparent)]
 pub struct BE<E> {
@@ -85,6 +102,17 @@ macro_rules! impl_encodeable_be_for_num {
                     encoder.put_slice(&self.num.to_be_bytes())
                 }
             }
+            impl<'de, D: ByteDecoder<'de>> Decodable<D> for BE<$T>
+            {
+                type Error = D::Error;
+
+                #[inline]
+                fn decode(decoder:&mut D) -> Result<Self, Self::Error> {
+                    <[u8; core::mem::size_of::<$T>()]>::decode(decoder)
+                        .map(<$T>::from_be_bytes)
+                        .map(Self::new)
+                }
+            }
         )*
 
     }
@@ -114,6 +142,16 @@ macro_rules! impl_encodeable_be_for_nonzero_num {
                     encoder.put_slice(&self.num.get().to_be_bytes())
                 }
             }
+            impl<'de, D: ByteDecoder<'de>> Decodable<D> for BE<NonZero<$T>>
+            {
+                type Error = NonZeroError<D::Error>;
+
+                #[inline]
+                fn decode(decoder:&mut D) -> Result<Self, Self::Error> {
+                    let num = BE::<$T>::decode(decoder)?.into_inner();
+                    NonZero::new(num).map(Self::new).ok_or(NonZeroError::Zero)
+                }
+            }
         )*
 
     }
@@ -122,6 +160,9 @@ macro_rules! impl_encodeable_be_for_nonzero_num {
 macro_rules! impl_try_from_be_for_num {
     ($($T:ty)*) => {
         $(
+            impl PrefixWidth for BE<$T> {
+                const FIXED_WIDTH: Option<usize> = Some(core::mem::size_of::<$T>());
+            }
             impl TryFrom<usize> for BE<$T> {
                 type Error = core::num::TryFromIntError;
 
@@ -130,6 +171,14 @@ macro_rules! impl_try_from_be_for_num {
                     <$T>::try_from(value).map(Self::new)
                 }
             }
+            impl TryFrom<BE<$T>> for usize {
+                type Error = core::num::TryFromIntError;
+
+                #[inline]
+                fn try_from(value: BE<$T>) -> Result<Self, Self::Error> {
+                    usize::try_from(value.num).map_err(Into::into)
+                }
+            }
         )*
     };
 }
@@ -146,6 +195,7 @@ mod tests {
     use rstest::rstest;
 
     use super::*;
+    use crate::decoders::UnexpectedEnd;
     use crate::Encodable;
 
     const BUF_SIZE: usize = 32;
@@ -223,7 +273,7 @@ mod tests {
     #[case::succeeds(5usize, Some(5u8))]
     #[case::overflows(256usize, None)]
     fn assert_that_be_u8_try_from_usize(#[case] val: usize, #[case] expected: Option<u8>) {
-        let result: Result<u8, TryFromIntError> = BE::<u8>::try_from(val).map(|be| be.into_inner());
+        let result: Result<u8, TryFromIntError> = BE::<u8>::try_from(val).map(BE::into_inner);
 
         match expected {
             Some(expected) => assert_eq!(result.unwrap(), expected),
@@ -257,4 +307,44 @@ mod tests {
         let val: NonZero<u16> = be.into();
         assert_eq!(val.get(), 42u16);
     }
+
+    #[test]
+    fn assert_that_u16_can_be_decoded_in_big_endian() {
+        let mut decoder = &[0x00u8, 0x01u8] as &[u8];
+        assert_eq!(BE::<u16>::decode(&mut decoder), Ok(BE::new(1u16)));
+        assert!(decoder.is_empty());
+    }
+
+    #[test]
+    fn assert_that_f64_can_be_decoded_in_big_endian() {
+        let bytes = 1.5f64.to_be_bytes();
+        let mut decoder = &bytes as &[u8];
+        assert_eq!(BE::<f64>::decode(&mut decoder), Ok(BE::new(1.5f64)));
+    }
+
+    #[test]
+    fn assert_that_decoding_a_truncated_number_fails() {
+        let mut decoder = &[0x01u8] as &[u8];
+        assert_eq!(BE::<u32>::decode(&mut decoder), Err(UnexpectedEnd));
+    }
+
+    #[rstest]
+    #[case::non_zero(&[0x00u8, 0x01u8], Ok(1))]
+    #[case::zero(&[0x00u8, 0x00u8], Err(NonZeroError::Zero))]
+    #[case::truncated(&[0x01u8], Err(NonZeroError::Decoder(UnexpectedEnd)))]
+    fn assert_that_nonzero_u16_can_be_decoded_in_big_endian(
+        #[case] input: &[u8],
+        #[case] expected: Result<u16, NonZeroError<UnexpectedEnd>>,
+    ) {
+        let mut decoder = input;
+        let result = BE::<NonZero<u16>>::decode(&mut decoder).map(|be| be.get());
+        assert_eq!(result, expected);
+    }
+
+    #[rstest]
+    #[case::fits(BE::new(5i64), Some(5usize))]
+    #[case::negative(BE::new(-1i64), None)]
+    fn assert_that_usize_try_from_be_works(#[case] be: BE<i64>, #[case] expected: Option<usize>) {
+        assert_eq!(usize::try_from(be).ok(), expected);
+    }
 }
@@ -0,0 +1,314 @@
+use core::borrow::Borrow;
+use core::fmt::Debug;
+use core::marker::PhantomData;
+use core::ops::Deref;
+
+use super::WidthOverflow;
+use super::BE;
+use super::LE;
+use crate::ByteEncoder;
+use crate::Encodable;
+
+/// Packs a list of `(value, width)` fields into an integer of type `T`.
+///
+/// The first field is placed in the most significant bits of the integer,
+/// and each following field right after it. Bits not covered by any field
+/// are zero. The packed integer has no byte order on its own, so wrap the
+/// [`Bitfield`] in [`LE`] or [`BE`] to encode it.
+///
+/// Values are never truncated: if a value does not fit into its width, or
+/// the widths add up to more bits than `T` has, [`WidthOverflow`] is
+/// returned.
+///
+/// # Examples
+///
+/// ```rust
+/// use encode::Encodable;
+/// use encode::combinators::{Bitfield, WidthOverflow, BE};
+/// use encode::encoders::InsufficientSpace;
+///
+/// #[derive(Debug, PartialEq)]
+/// enum Error {
+///     InsufficientSpace,
+///     WidthOverflow,
+/// }
+/// # impl From<InsufficientSpace> for Error { fn from(_: InsufficientSpace) -> Self { Error::InsufficientSpace } }
+/// # impl From<WidthOverflow> for Error { fn from(_: WidthOverflow) -> Self { Error::WidthOverflow } }
+///
+/// let mut buf = [0u8; 2];
+/// let mut encoder = &mut buf as &mut [u8];
+/// // An IPv4 version and header length, followed by the DSCP and ECN fields.
+/// let header = Bitfield::<u16, _, Error>::new([(4, 4), (5, 4), (46, 6), (0, 2)]);
+/// BE::new(header).encode(&mut encoder).unwrap();
+/// assert_eq!(buf, [0x45, 0xB8]);
+///
+/// let overflowing = Bitfield::<u8, _, Error>::new([(4, 2)]);
+/// assert_eq!(overflowing.pack(), Err(WidthOverflow));
+/// ```
+#[doc(alias("bitflags", "packed"))]
+#[repr(transparent)]
+pub struct Bitfield<T, F, Error> {
+    fields: F,
+    phantom: PhantomData<(T, Error)>,
+}
+
+impl<T, F, Error> Bitfield<T, F, Error> {
+    /// Creates a new [`Bitfield`] combinator.
+    #[inline]
+    #[must_use]
+    pub const fn new(fields: F) -> Self {
+        Self {
+            fields,
+            phantom: PhantomData,
+        }
+    }
+    /// Consumes the combinator and returns the fields.
+    #[inline]
+    #[must_use]
+    pub fn into_inner(self) -> F {
+        self.fields
+    }
+}
+
+impl<T, F, Error> AsRef<F> for Bitfield<T, F, Error> {
+    #[inline]
+    fn as_ref(&self) -> &F {
+        &self.fields
+    }
+}
+
+impl<T, F, Error> Deref for Bitfield<T, F, Error> {
+    type Target = F;
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        self.as_ref()
+    }
+}
+
+impl<T, F, Error> Borrow<F> for Bitfield<T, F, Error> {
+    #[inline]
+    fn borrow(&self) -> &F {
+        &self.fields
+    }
+}
+
+macro_rules! impl_bitfield_for_num {
+    ($($T:ty)*) => {
+        $(
+            impl<F, Error> Bitfield<$T, F, Error>
+            where
+                F: AsRef<[(u64, u32)]>,
+            {
+                /// Packs the fields into an integer.
+                ///
+                /// # Errors
+                ///
+                /// Returns [`WidthOverflow`] if a value does not fit into its
+                /// width, or if the fields do not fit into the integer.
+                #[inline]
+                pub fn pack(&self) -> Result<$T, WidthOverflow> {
+                    let mut packed = 0u64;
+                    let mut used = 0u32;
+                    for &(value, width) in self.fields.as_ref() {
+                        used = used
+                            .checked_add(width)
+                            .filter(|&used| used <= <$T>::BITS)
+                            .ok_or(WidthOverflow)?;
+                        if value.checked_shr(width).unwrap_or(0) != 0 {
+                            return Err(WidthOverflow);
+                        }
+                        packed = packed.checked_shl(width).unwrap_or(0) | value;
+                    }
+                    let packed = packed.checked_shl(<$T>::BITS - used).unwrap_or(0);
+                    <$T>::try_from(packed).map_err(|_| WidthOverflow)
+                }
+            }
+            impl<E, F, Error> Encodable<E> for LE<Bitfield<$T, F, Error>>
+            where
+                E: ByteEncoder,
+                F: AsRef<[(u64, u32)]>,
+                Error: From<E::Error> + From<WidthOverflow>,
+            {
+                type Error = Error;
+
+                #[inline]
+                fn encode(&self, encoder: &mut E) -> Result<(), Self::Error> {
+                    LE::new(self.pack()?).encode(encoder)?;
+                    Ok(())
+                }
+            }
+            impl<E, F, Error> Encodable<E> for BE<Bitfield<$T, F, Error>>
+            where
+                E: ByteEncoder,
+                F: AsRef<[(u64, u32)]>,
+                Error: From<E::Error> + From<WidthOverflow>,
+            {
+                type Error = Error;
+
+                #[inline]
+                fn encode(&self, encoder: &mut E) -> Result<(), Self::Error> {
+                    BE::new(self.pack()?).encode(encoder)?;
+                    Ok(())
+                }
+            }
+        )*
+    };
+}
+
+impl_bitfield_for_num!(u8 u16 u32 u64);
+
+// Manual trait implementations because the derive macro does not support
+// phantom data fields.
+impl<T, F, Error> Debug for Bitfield<T, F, Error>
+where
+    F: Debug,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Bitfield")
+            .field("fields", &self.fields)
+            .finish()
+    }
+}
+impl<T, F, Error> Clone for Bitfield<T, F, Error>
+where
+    F: Clone,
+{
+    #[inline]
+    fn clone(&self) -> Self {
+        Self::new(self.fields.clone())
+    }
+}
+impl<T, F, Error> Copy for Bitfield<T, F, Error> where F: Copy {}
+impl<T, F, Error> Default for Bitfield<T, F, Error>
+where
+    F: Default,
+{
+    #[inline]
+    fn default() -> Self {
+        Self::new(Default::default())
+    }
+}
+impl<T, F, Error> PartialEq for Bitfield<T, F, Error>
+where
+    F: PartialEq,
+{
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.fields == other.fields
+    }
+}
+impl<T, F, Error> Eq for Bitfield<T, F, Error> where F: Eq {}
+impl<T, F, Error> PartialOrd for Bitfield<T, F, Error>
+where
+    F: PartialOrd,
+{
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+        self.fields.partial_cmp(&other.fields)
+    }
+}
+impl<T, F, Error> Ord for Bitfield<T, F, Error>
+where
+    F: Ord,
+{
+    #[inline]
+    fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+        self.fields.cmp(&other.fields)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::borrow::Borrow;
+
+    use rstest::rstest;
+
+    use super::*;
+    use crate::encoders::InsufficientSpace;
+
+    const BUF_SIZE: usize = 16;
+
+    #[derive(Debug, PartialEq)]
+    enum Error {
+        InsufficientSpace,
+        WidthOverflow,
+    }
+
+    impl From<InsufficientSpace> for Error {
+        fn from(_: InsufficientSpace) -> Self {
+            Error::InsufficientSpace
+        }
+    }
+
+    impl From<WidthOverflow> for Error {
+        fn from(_: WidthOverflow) -> Self {
+            Error::WidthOverflow
+        }
+    }
+
+    #[rstest]
+    #[case::empty(&[], Ok(0))]
+    #[case::single_bit(&[(1, 1)], Ok(0x8000))]
+    #[case::nibbles(&[(0xA, 4), (0xB, 4), (0xC, 4), (0xD, 4)], Ok(0xABCD))]
+    #[case::zero_width(&[(0, 0), (0xFFFF, 16)], Ok(0xFFFF))]
+    #[case::value_too_wide(&[(0b100, 2)], Err(WidthOverflow))]
+    #[case::value_for_zero_width(&[(1, 0)], Err(WidthOverflow))]
+    #[case::fields_too_wide(&[(0, 8), (0, 9)], Err(WidthOverflow))]
+    #[case::width_overflow(&[(0, u32::MAX), (0, 1)], Err(WidthOverflow))]
+    fn assert_that_fields_are_packed_msb_first(
+        #[case] fields: &[(u64, u32)],
+        #[case] expected: Result<u16, WidthOverflow>,
+    ) {
+        assert_eq!(Bitfield::<u16, _, Error>::new(fields).pack(), expected);
+    }
+
+    #[test]
+    fn assert_that_full_width_fields_are_packed() {
+        let bitfield = Bitfield::<u64, _, Error>::new([(u64::MAX, 64)]);
+        assert_eq!(bitfield.pack(), Ok(u64::MAX));
+    }
+
+    #[test]
+    fn assert_that_bitfields_are_encoded_in_the_given_byte_order() {
+        let bitfield = Bitfield::<u32, _, Error>::new([(0x12, 8), (0x345, 12), (0x678, 12)]);
+
+        let mut buf = [0u8; BUF_SIZE];
+        let mut encoder = &mut buf as &mut [u8];
+        LE::new(bitfield).encode(&mut encoder).unwrap();
+        BE::new(bitfield).encode(&mut encoder).unwrap();
+        let written = BUF_SIZE - encoder.len();
+
+        assert_eq!(
+            &buf[..written],
+            &[0x78, 0x56, 0x34, 0x12, 0x12, 0x34, 0x56, 0x78]
+        );
+    }
+
+    #[test]
+    fn assert_that_encoding_errors_are_reported() {
+        let mut buf = [0u8; 1];
+        let mut encoder = &mut buf as &mut [u8];
+
+        let overflowing = Bitfield::<u8, _, Error>::new([(2, 1)]);
+        assert_eq!(
+            BE::new(overflowing).encode(&mut encoder),
+            Err(Error::WidthOverflow)
+        );
+        let too_large = Bitfield::<u16, _, Error>::new([(1, 1)]);
+        assert_eq!(
+            LE::new(too_large).encode(&mut encoder),
+            Err(Error::InsufficientSpace)
+        );
+    }
+
+    #[test]
+    fn assert_that_bitfield_accessors_work() {
+        let bitfield = Bitfield::<u8, _, Error>::new([(1, 1)]);
+        let borrowed: &[(u64, u32); 1] = bitfield.borrow();
+        assert_eq!(borrowed, &[(1, 1)]);
+        assert_eq!(bitfield.as_ref(), &[(1, 1)]);
+        assert_eq!(*bitfield, [(1, 1)]);
+        assert_eq!(bitfield, Clone::clone(&bitfield));
+        assert_eq!(bitfield.into_inner(), [(1, 1)]);
+    }
+}
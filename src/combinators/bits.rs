@@ -0,0 +1,173 @@
+use core::borrow::Borrow;
+use core::ops::Deref;
+
+use crate::BitLevelEncoder;
+use crate::Encodable;
+
+/// Encodes the `N` least significant bits of a number.
+///
+/// [`Bits`] can only be encoded into a [`BitLevelEncoder`], such as
+/// [`BitEncoder`](crate::encoders::BitEncoder). The remaining bits of the
+/// number are ignored. Using an `N` wider than the number fails to compile.
+///
+/// # Examples
+///
+/// ```rust
+/// use encode::Encodable;
+/// use encode::combinators::Bits;
+/// use encode::encoders::BitEncoder;
+///
+/// let mut buf = [0u8; 2];
+/// let mut encoder = BitEncoder::new(&mut buf as &mut [u8]);
+/// // An MPEG-TS style header: a flag, a 13 bit identifier and 2 reserved bits.
+/// (Bits::<bool, 1>::new(true), Bits::<u16, 13>::new(0x1FFF), Bits::<u8, 2>::new(0))
+///     .encode(&mut encoder)
+///     .unwrap();
+/// encoder.finish().unwrap();
+/// assert_eq!(buf, [0xFF, 0xFC]);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+#[repr(transparent)]
+pub struct Bits<T, const N: u32> {
+    num: T,
+}
+
+impl<T, const N: u32> Bits<T, N> {
+    /// Creates a new [`Bits`] combinator.
+    #[inline]
+    #[must_use]
+    pub const fn new(num: T) -> Self {
+        Self { num }
+    }
+    /// Consumes the [`Bits`] combinator and returns the inner value.
+    #[inline]
+    #[must_use]
+    pub fn into_inner(self) -> T {
+        self.num
+    }
+}
+
+impl<T, const N: u32> AsRef<T> for Bits<T, N> {
+    #[inline]
+    fn as_ref(&self) -> &T {
+        &self.num
+    }
+}
+impl<T, const N: u32> Borrow<T> for Bits<T, N> {
+    #[inline]
+    fn borrow(&self) -> &T {
+        &self.num
+    }
+}
+impl<T, const N: u32> Deref for Bits<T, N> {
+    type Target = T;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.num
+    }
+}
+
+macro_rules! impl_bits_for_num {
+    ($($T:ty => $BITS:literal)*) => {
+        $(
+            impl<E: BitLevelEncoder, const N: u32> Encodable<E> for Bits<$T, N> {
+                type Error = E::Error;
+
+                #[inline]
+                fn encode(&self, encoder: &mut E) -> Result<(), Self::Error> {
+                    const {
+                        assert!(N <= $BITS, "the number of bits is wider than the number");
+                    }
+                    encoder.put_bits(u64::from(self.num), N)
+                }
+            }
+        )*
+    };
+}
+
+impl_bits_for_num!(bool => 1 u8 => 8 u16 => 16 u32 => 32 u64 => 64);
+
+#[cfg(test)]
+mod tests {
+    use core::borrow::Borrow;
+
+    use rstest::rstest;
+
+    use super::*;
+    use crate::encoders::BitEncoder;
+    use crate::encoders::BitSizeEncoder;
+    use crate::encoders::Lsb0;
+
+    const BUF_SIZE: usize = 16;
+
+    #[rstest]
+    #[case::nibbles(0xA, 0x5, &[0xA5])]
+    #[case::ignores_high_bits(0xFA, 0xF5, &[0xA5])]
+    fn assert_that_bits_are_packed_msb_first(
+        #[case] high: u8,
+        #[case] low: u8,
+        #[case] expected: &[u8],
+    ) {
+        let mut buf = [0u8; BUF_SIZE];
+        let mut encoder = BitEncoder::new(&mut buf as &mut [u8]);
+        (Bits::<u8, 4>::new(high), Bits::<u8, 4>::new(low))
+            .encode(&mut encoder)
+            .unwrap();
+        let written = BUF_SIZE - encoder.finish().unwrap().len();
+
+        assert_eq!(&buf[..written], expected);
+    }
+
+    #[test]
+    fn assert_that_bits_are_packed_lsb_first() {
+        let mut buf = [0u8; BUF_SIZE];
+        let mut encoder = BitEncoder::<_, Lsb0>::with_order(&mut buf as &mut [u8]);
+        (Bits::<bool, 1>::new(true), Bits::<u16, 10>::new(0x3FE))
+            .encode(&mut encoder)
+            .unwrap();
+        let written = BUF_SIZE - encoder.finish().unwrap().len();
+
+        assert_eq!(&buf[..written], &[0xFD, 0x07]);
+    }
+
+    #[test]
+    fn assert_that_mixed_widths_are_packed_msb_first() {
+        let mut buf = [0u8; BUF_SIZE];
+        let mut encoder = BitEncoder::new(&mut buf as &mut [u8]);
+        (
+            Bits::<bool, 1>::new(false),
+            Bits::<u8, 3>::new(0b101),
+            Bits::<u16, 12>::new(0xABC),
+            Bits::<u64, 64>::new(u64::MAX),
+        )
+            .encode(&mut encoder)
+            .unwrap();
+        let written = BUF_SIZE - encoder.finish().unwrap().len();
+
+        assert_eq!(
+            &buf[..written],
+            &[0x5A, 0xBC, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF]
+        );
+    }
+
+    #[test]
+    fn assert_that_bits_are_counted() {
+        let mut encoder = BitSizeEncoder::new();
+        (Bits::<u32, 17>::new(0), Bits::<u8, 0>::new(0xFF))
+            .encode(&mut encoder)
+            .unwrap();
+
+        assert_eq!(encoder.bits(), 17);
+    }
+
+    #[test]
+    fn assert_that_bits_accessors_work() {
+        let bits = Bits::<u16, 9>::new(42);
+        let borrowed: &u16 = bits.borrow();
+        assert_eq!(*borrowed, 42);
+        assert_eq!(bits.as_ref(), &42);
+        assert_eq!(*bits, 42);
+        assert_eq!(bits.into_inner(), 42);
+    }
+}
@@ -0,0 +1,293 @@
+use core::borrow::Borrow;
+use core::fmt::Debug;
+use core::marker::PhantomData;
+use core::ops::Deref;
+
+use crate::checksums::Checksum;
+use crate::encoders::ChecksumEncoder;
+use crate::ByteEncoder;
+
+/// Encodes a value followed by a [`Checksum`] of its encoded bytes.
+///
+/// The checksum is computed while the value is being encoded, using a
+/// [`ChecksumEncoder`], so the value is only encoded once and works with any
+/// [`ByteEncoder`]. The checksum is encoded as its
+/// [`Output`](Checksum::Output), which defines its byte order.
+///
+/// # Examples
+///
+/// ```rust
+/// # #[cfg(feature = "alloc")] {
+/// use encode::Encodable;
+/// use encode::checksums::Crc16Modbus;
+/// use encode::combinators::Checksummed;
+/// use core::convert::Infallible;
+///
+/// // A Modbus RTU request reading two holding registers from device 1.
+/// let frame = Checksummed::<_, Crc16Modbus, Infallible>::new(b"\x01\x03\x00\x00\x00\x02");
+///
+/// let mut buf = Vec::new();
+/// frame.encode(&mut buf).unwrap();
+/// assert_eq!(&buf, b"\x01\x03\x00\x00\x00\x02\xC4\x0B");
+/// # }
+/// ```
+#[doc(alias("crc", "checksum", "trailer"))]
+#[repr(transparent)]
+pub struct Checksummed<Encodable, C, Error> {
+    encodable: Encodable,
+    phantom: PhantomData<(C, Error)>,
+}
+
+impl<Encodable, C, Error> Checksummed<Encodable, C, Error> {
+    /// Creates a new [`Checksummed`] combinator.
+    #[inline]
+    #[must_use]
+    pub const fn new(encodable: Encodable) -> Self {
+        Self {
+            encodable,
+            phantom: PhantomData,
+        }
+    }
+    /// Consumes the combinator and returns the inner encodable.
+    #[inline]
+    #[must_use]
+    pub fn into_inner(self) -> Encodable {
+        self.encodable
+    }
+}
+
+impl<Encodable, C, Error> From<Encodable> for Checksummed<Encodable, C, Error> {
+    #[inline]
+    fn from(value: Encodable) -> Self {
+        Self::new(value)
+    }
+}
+
+impl<Encodable, C, Error> AsRef<Encodable> for Checksummed<Encodable, C, Error> {
+    #[inline]
+    fn as_ref(&self) -> &Encodable {
+        &self.encodable
+    }
+}
+
+impl<Encodable, C, Error> Deref for Checksummed<Encodable, C, Error> {
+    type Target = Encodable;
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        self.as_ref()
+    }
+}
+
+impl<Encodable, C, Error> Borrow<Encodable> for Checksummed<Encodable, C, Error> {
+    #[inline]
+    fn borrow(&self) -> &Encodable {
+        &self.encodable
+    }
+}
+
+impl<Encodable, C, Encoder, Error> crate::Encodable<Encoder> for Checksummed<Encodable, C, Error>
+where
+    Encoder: ByteEncoder,
+    C: Checksum + Default,
+    C::Output: crate::Encodable<Encoder>,
+    for<'a> Encodable: crate::Encodable<ChecksumEncoder<'a, Encoder, C>>,
+    for<'a> Error: From<<Encodable as crate::Encodable<ChecksumEncoder<'a, Encoder, C>>>::Error>,
+    Error: From<<C::Output as crate::Encodable<Encoder>>::Error> + From<Encoder::Error>,
+{
+    type Error = Error;
+
+    #[inline]
+    fn encode(&self, encoder: &mut Encoder) -> Result<(), Self::Error> {
+        let mut checksum_encoder = ChecksumEncoder::new(encoder, C::default());
+        self.encodable.encode(&mut checksum_encoder)?;
+        let (encoder, checksum) = checksum_encoder.into_parts();
+        checksum.finish().encode(encoder)?;
+        Ok(())
+    }
+}
+
+// Manual trait implementations because the derive macro does not support
+// phantom data fields.
+impl<Encodable, C, Error> Debug for Checksummed<Encodable, C, Error>
+where
+    Encodable: Debug,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Checksummed")
+            .field("encodable", &self.encodable)
+            .finish()
+    }
+}
+impl<Encodable, C, Error> Clone for Checksummed<Encodable, C, Error>
+where
+    Encodable: Clone,
+{
+    #[inline]
+    fn clone(&self) -> Self {
+        Self::new(self.encodable.clone())
+    }
+}
+impl<Encodable, C, Error> Copy for Checksummed<Encodable, C, Error> where Encodable: Copy {}
+impl<Encodable, C, Error> Default for Checksummed<Encodable, C, Error>
+where
+    Encodable: Default,
+{
+    #[inline]
+    fn default() -> Self {
+        Self::new(Default::default())
+    }
+}
+impl<Encodable, C, Error> PartialEq for Checksummed<Encodable, C, Error>
+where
+    Encodable: PartialEq,
+{
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.encodable == other.encodable
+    }
+}
+impl<Encodable, C, Error> Eq for Checksummed<Encodable, C, Error> where Encodable: Eq {}
+impl<Encodable, C, Error> PartialOrd for Checksummed<Encodable, C, Error>
+where
+    Encodable: PartialOrd,
+{
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+        self.encodable.partial_cmp(&other.encodable)
+    }
+}
+impl<Encodable, C, Error> Ord for Checksummed<Encodable, C, Error>
+where
+    Encodable: Ord,
+{
+    #[inline]
+    fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+        self.encodable.cmp(&other.encodable)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::borrow::Borrow;
+    use core::num::TryFromIntError;
+
+    use super::*;
+    use crate::checksums::Adler32;
+    use crate::checksums::Crc32;
+    use crate::checksums::Crc8;
+    use crate::combinators::LengthPrefix;
+    use crate::encoders::InsufficientSpace;
+    use crate::encoders::SizeEncoder;
+    use crate::Encodable;
+    use crate::EncodableSize;
+
+    const BUF_SIZE: usize = 64;
+
+    #[derive(Debug, PartialEq)]
+    enum Error {
+        InsufficientSpace,
+        TooLarge,
+    }
+
+    impl From<InsufficientSpace> for Error {
+        fn from(_: InsufficientSpace) -> Self {
+            Error::InsufficientSpace
+        }
+    }
+
+    impl From<TryFromIntError> for Error {
+        fn from(_: TryFromIntError) -> Self {
+            Error::TooLarge
+        }
+    }
+
+    impl From<core::convert::Infallible> for Error {
+        fn from(err: core::convert::Infallible) -> Self {
+            match err {}
+        }
+    }
+
+    #[test]
+    fn assert_that_the_checksum_is_appended() {
+        let encodable = Checksummed::<_, Crc32, InsufficientSpace>::new("123456789");
+
+        let mut buf = [0u8; BUF_SIZE];
+        let mut encoder = &mut buf as &mut [u8];
+        encodable.encode(&mut encoder).unwrap();
+        let written = BUF_SIZE - encoder.len();
+
+        assert_eq!(&buf[..written], b"123456789\x26\x39\xF4\xCB");
+    }
+
+    #[test]
+    fn assert_that_inner_errors_are_converted() {
+        let encodable = Checksummed::<_, Crc32, Error>::new("123456789");
+
+        let mut buf = [0u8; 8];
+        let mut encoder = &mut buf as &mut [u8];
+
+        assert_eq!(
+            encodable.encode(&mut encoder),
+            Err(Error::InsufficientSpace)
+        );
+    }
+
+    #[test]
+    fn assert_that_nested_encodables_are_checksummed() {
+        let encodable =
+            Checksummed::<_, Adler32, Error>::new(LengthPrefix::<_, u8, Error>::new("Wikipedia"));
+
+        let mut buf = [0u8; BUF_SIZE];
+        let mut encoder = &mut buf as &mut [u8];
+        encodable.encode(&mut encoder).unwrap();
+        let written = BUF_SIZE - encoder.len();
+
+        let checksum = Adler32::compute(b"\x09Wikipedia").into_inner();
+        assert_eq!(&buf[..written - 4], b"\x09Wikipedia");
+        assert_eq!(&buf[written - 4..written], &checksum.to_be_bytes());
+    }
+
+    #[test]
+    fn assert_that_the_size_includes_the_checksum() {
+        let encodable = Checksummed::<_, Crc32, core::convert::Infallible>::new("hello");
+        assert_eq!(encodable.encoded_size(), Ok(9));
+
+        let mut encoder = SizeEncoder::new();
+        Checksummed::<_, Crc8, core::convert::Infallible>::new(encodable)
+            .encode(&mut encoder)
+            .unwrap();
+        assert_eq!(encoder.size(), 10);
+    }
+
+    #[test]
+    fn assert_that_encoder_errors_are_propagated() {
+        let encodable = Checksummed::<_, Crc32, InsufficientSpace>::new("hello");
+
+        let mut buf = [0u8; 8];
+        let mut encoder = &mut buf as &mut [u8];
+
+        assert_eq!(encodable.encode(&mut encoder), Err(InsufficientSpace));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn assert_that_checksums_can_be_written_to_non_seekable_encoders() {
+        let encodable = Checksummed::<_, Crc8, std::io::Error>::new("123456789");
+
+        let mut encoder = crate::encoders::IoEncoder(alloc::vec::Vec::new());
+        encodable.encode(&mut encoder).unwrap();
+
+        assert_eq!(encoder.0, b"123456789\xF4");
+    }
+
+    #[test]
+    fn assert_that_checksummed_accessors_work() {
+        let checksummed = Checksummed::<u8, Crc8, InsufficientSpace>::new(42u8);
+        let borrowed: &u8 = checksummed.borrow();
+        assert_eq!(*borrowed, 42u8);
+        assert_eq!(checksummed.as_ref(), &42u8);
+        assert_eq!(*checksummed, 42u8);
+        assert_eq!(checksummed, Clone::clone(&checksummed));
+        assert_eq!(checksummed.into_inner(), 42u8);
+    }
+}
@@ -0,0 +1,197 @@
+use core::borrow::Borrow;
+use core::ops::Deref;
+
+use super::PrefixWidth;
+use crate::ByteEncoder;
+use crate::Encodable;
+
+/// Encodes a number using Bitcoin's
+/// [CompactSize](https://en.bitcoin.it/wiki/Protocol_documentation#Variable_length_integer)
+/// variable-length encoding.
+///
+/// Numbers below `0xFD` are encoded as a single byte. Larger numbers are
+/// encoded as a marker byte (`0xFD`, `0xFE` or `0xFF`) followed by the number
+/// as a little-endian [`u16`], [`u32`] or [`u64`] respectively.
+///
+/// Because it implements [`TryFrom<usize>`], it can also be used as the length
+/// of a [`LengthPrefix`](super::LengthPrefix).
+///
+/// # Examples
+///
+/// ```rust
+/// # #[cfg(feature = "alloc")] {
+/// use encode::Encodable;
+/// use encode::combinators::CompactSize;
+///
+/// let mut buf = Vec::new();
+/// CompactSize::new(515).encode(&mut buf).unwrap();
+/// assert_eq!(&buf, &[0xFD, 0x03, 0x02]);
+/// assert_eq!(CompactSize::new(515).encoded_len(), 3);
+/// # }
+/// ```
+#[doc(alias("varint", "var_int", "bitcoin"))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+#[repr(transparent)]
+pub struct CompactSize {
+    num: u64,
+}
+
+impl CompactSize {
+    /// Creates a new [`CompactSize`] combinator.
+    #[inline]
+    #[must_use]
+    pub const fn new(num: u64) -> Self {
+        Self { num }
+    }
+    /// Consumes the [`CompactSize`] combinator and returns the inner value.
+    #[inline]
+    #[must_use]
+    pub const fn into_inner(self) -> u64 {
+        self.num
+    }
+    /// Returns the number of bytes the number is encoded into.
+    #[inline]
+    #[must_use]
+    pub const fn encoded_len(&self) -> usize {
+        match self.num {
+            0..0xFD => 1,
+            0xFD..=0xFFFF => 3,
+            0x1_0000..=0xFFFF_FFFF => 5,
+            _ => 9,
+        }
+    }
+}
+
+impl AsRef<u64> for CompactSize {
+    #[inline]
+    fn as_ref(&self) -> &u64 {
+        &self.num
+    }
+}
+impl Borrow<u64> for CompactSize {
+    #[inline]
+    fn borrow(&self) -> &u64 {
+        &self.num
+    }
+}
+impl Deref for CompactSize {
+    type Target = u64;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.num
+    }
+}
+
+impl From<u64> for CompactSize {
+    #[inline]
+    fn from(num: u64) -> Self {
+        Self { num }
+    }
+}
+impl From<CompactSize> for u64 {
+    #[inline]
+    fn from(value: CompactSize) -> Self {
+        value.num
+    }
+}
+impl PrefixWidth for CompactSize {
+    const FIXED_WIDTH: Option<usize> = None;
+}
+impl TryFrom<usize> for CompactSize {
+    type Error = core::num::TryFromIntError;
+
+    #[inline]
+    fn try_from(value: usize) -> Result<Self, Self::Error> {
+        u64::try_from(value).map(Self::new)
+    }
+}
+impl TryFrom<CompactSize> for usize {
+    type Error = core::num::TryFromIntError;
+
+    #[inline]
+    fn try_from(value: CompactSize) -> Result<Self, Self::Error> {
+        usize::try_from(value.num)
+    }
+}
+
+impl<E: ByteEncoder> Encodable<E> for CompactSize {
+    type Error = E::Error;
+
+    #[inline]
+    #[allow(clippy::cast_possible_truncation)]
+    fn encode(&self, encoder: &mut E) -> Result<(), Self::Error> {
+        let mut buf = [0u8; 9];
+        let len = self.encoded_len();
+        match len {
+            1 => buf[0] = self.num as u8,
+            3 => buf[0] = 0xFD,
+            5 => buf[0] = 0xFE,
+            _ => buf[0] = 0xFF,
+        }
+        if len > 1 {
+            buf[1..len].copy_from_slice(&self.num.to_le_bytes()[..len - 1]);
+        }
+        encoder.put_slice(&buf[..len])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::borrow::Borrow;
+
+    use rstest::rstest;
+
+    use super::*;
+    use crate::EncodableSize;
+
+    const BUF_SIZE: usize = 32;
+
+    #[rstest]
+    #[case::zero(0, &[0x00])]
+    #[case::largest_single_byte(0xFC, &[0xFC])]
+    #[case::smallest_u16(0xFD, &[0xFD, 0xFD, 0x00])]
+    #[case::largest_u16(0xFFFF, &[0xFD, 0xFF, 0xFF])]
+    #[case::smallest_u32(0x1_0000, &[0xFE, 0x00, 0x00, 0x01, 0x00])]
+    #[case::largest_u32(0xFFFF_FFFF, &[0xFE, 0xFF, 0xFF, 0xFF, 0xFF])]
+    #[case::smallest_u64(0x1_0000_0000, &[0xFF, 0x00, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00])]
+    #[case::max(u64::MAX, &[0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF])]
+    fn assert_that_compact_sizes_can_be_encoded(#[case] num: u64, #[case] expected: &[u8]) {
+        let encodable = CompactSize::new(num);
+
+        let mut buf = [0u8; BUF_SIZE];
+        let mut encoder = &mut buf as &mut [u8];
+        encodable.encode(&mut encoder).unwrap();
+        let written = BUF_SIZE - encoder.len();
+
+        assert_eq!(&buf[..written], expected);
+        assert_eq!(encodable.encoded_len(), expected.len());
+        assert_eq!(encodable.encoded_size(), Ok(expected.len()));
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn assert_that_compact_size_can_be_used_as_a_length_prefix() {
+        use crate::combinators::LengthPrefix;
+
+        let encodable =
+            LengthPrefix::<_, CompactSize, core::num::TryFromIntError>::new([0xAAu8; 300]);
+        let mut buf = alloc::vec::Vec::new();
+        encodable.encode(&mut buf).unwrap();
+
+        assert_eq!(buf.len(), 303);
+        assert_eq!(&buf[..3], &[0xFD, 0x2C, 0x01]);
+    }
+
+    #[test]
+    fn assert_that_compact_size_accessors_work() {
+        let compact = CompactSize::try_from(42usize).unwrap();
+        let borrowed: &u64 = compact.borrow();
+        assert_eq!(*borrowed, 42);
+        assert_eq!(compact.as_ref(), &42);
+        assert_eq!(*compact, 42);
+        assert_eq!(u64::from(compact), 42);
+        assert_eq!(usize::try_from(compact), Ok(42));
+        assert_eq!(compact.into_inner(), 42);
+    }
+}
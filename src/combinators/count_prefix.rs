@@ -0,0 +1,257 @@
+use core::borrow::Borrow;
+use core::fmt::Debug;
+use core::marker::PhantomData;
+use core::ops::Deref;
+
+/// Encodes a sequence of encodables, prefixed by the number of elements.
+///
+/// Unlike [`LengthPrefix`](super::LengthPrefix), which writes the size of the
+/// value in bytes, this combinator writes how many elements the sequence
+/// contains, as used by `MessagePack` arrays or Java serialization. The
+/// elements are then encoded in order, like [`Iter`](super::Iter) does.
+///
+/// The elements are counted with [`Iterator::count`] on a clone of the
+/// iterator before anything is written, because [`Iterator::size_hint`] is not
+/// guaranteed to be correct. Iterators that know their length, such as those
+/// of slices, count in constant time.
+///
+/// # Example
+///
+/// ```
+/// # #[cfg(feature = "alloc")] {
+/// use core::num::TryFromIntError;
+/// use encode::Encodable;
+/// use encode::combinators::{CountPrefix, BE};
+///
+/// let mut buf = Vec::new();
+/// CountPrefix::<_, u8, TryFromIntError>::new([BE::new(1u16), BE::new(2u16)]).encode(&mut buf).unwrap();
+/// assert_eq!(&buf, b"\x02\x00\x01\x00\x02");
+/// # }
+/// ```
+#[doc(alias("count", "prefix", "array"))]
+#[repr(transparent)]
+pub struct CountPrefix<I, Length, Error> {
+    encodable_iter: I,
+    phantom: PhantomData<(Length, Error)>,
+}
+
+impl<I, Length, Error> CountPrefix<I, Length, Error> {
+    /// Creates a new [`CountPrefix`] combinator.
+    #[inline]
+    #[must_use]
+    pub const fn new(encodable_iter: I) -> Self {
+        Self {
+            encodable_iter,
+            phantom: PhantomData,
+        }
+    }
+    /// Consumes the [`CountPrefix`] combinator and returns the inner value.
+    #[inline]
+    #[must_use]
+    pub fn into_inner(self) -> I {
+        self.encodable_iter
+    }
+}
+
+impl<I, Length, Error> AsRef<I> for CountPrefix<I, Length, Error> {
+    #[inline]
+    fn as_ref(&self) -> &I {
+        &self.encodable_iter
+    }
+}
+impl<I, Length, Error> Borrow<I> for CountPrefix<I, Length, Error> {
+    #[inline]
+    fn borrow(&self) -> &I {
+        &self.encodable_iter
+    }
+}
+impl<I, Length, Error> Deref for CountPrefix<I, Length, Error> {
+    type Target = I;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.encodable_iter
+    }
+}
+
+impl<EncodableIter, Length, Encoder, Error> crate::Encodable<Encoder>
+    for CountPrefix<EncodableIter, Length, Error>
+where
+    EncodableIter: IntoIterator + Clone,
+    EncodableIter::Item: crate::Encodable<Encoder>,
+    Length: crate::Encodable<Encoder> + TryFrom<usize>,
+    Encoder: crate::BaseEncoder,
+    Error: From<<Length as crate::Encodable<Encoder>>::Error>
+        + From<<Length as TryFrom<usize>>::Error>
+        + From<<EncodableIter::Item as crate::Encodable<Encoder>>::Error>
+        + From<Encoder::Error>,
+{
+    type Error = Error;
+
+    #[inline]
+    fn encode(&self, encoder: &mut Encoder) -> Result<(), Self::Error> {
+        let count = self.encodable_iter.clone().into_iter().count();
+
+        Length::try_from(count)?.encode(encoder)?;
+        for encodable in self.encodable_iter.clone() {
+            encodable.encode(encoder)?;
+        }
+        Ok(())
+    }
+}
+
+// Manual trait implementations because the derive macro does not support
+// phantom data fields.
+impl<I: Debug, Length, Error> Debug for CountPrefix<I, Length, Error> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("CountPrefix")
+            .field("encodable_iter", &self.encodable_iter)
+            .finish()
+    }
+}
+impl<I: Clone, Length, Error> Clone for CountPrefix<I, Length, Error> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self::new(self.encodable_iter.clone())
+    }
+}
+impl<I: Copy, Length, Error> Copy for CountPrefix<I, Length, Error> {}
+impl<I: Default, Length, Error> Default for CountPrefix<I, Length, Error> {
+    #[inline]
+    fn default() -> Self {
+        Self::new(Default::default())
+    }
+}
+impl<I: PartialEq, Length, Error> PartialEq for CountPrefix<I, Length, Error> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.encodable_iter == other.encodable_iter
+    }
+}
+impl<I: Eq, Length, Error> Eq for CountPrefix<I, Length, Error> {}
+impl<I: PartialOrd, Length, Error> PartialOrd for CountPrefix<I, Length, Error> {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+        self.encodable_iter.partial_cmp(&other.encodable_iter)
+    }
+}
+impl<I: Ord, Length, Error> Ord for CountPrefix<I, Length, Error> {
+    #[inline]
+    fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+        self.encodable_iter.cmp(&other.encodable_iter)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::borrow::Borrow;
+    use core::num::TryFromIntError;
+
+    use rstest::rstest;
+
+    use super::*;
+    use crate::combinators::BE;
+    use crate::encoders::InsufficientSpace;
+    use crate::Encodable;
+
+    const BUF_SIZE: usize = 32;
+
+    #[derive(Debug, PartialEq)]
+    enum Error {
+        InsufficientSpace,
+        TooLarge,
+    }
+
+    impl From<InsufficientSpace> for Error {
+        fn from(_: InsufficientSpace) -> Self {
+            Error::InsufficientSpace
+        }
+    }
+
+    impl From<TryFromIntError> for Error {
+        fn from(_: TryFromIntError) -> Self {
+            Error::TooLarge
+        }
+    }
+
+    #[rstest]
+    #[case::non_empty(&[1u8, 2, 3], b"\x03\x01\x02\x03")]
+    #[case::empty(&[], b"\x00")]
+    fn assert_that_count_prefix_encodes_the_number_of_elements(
+        #[case] items: &[u8],
+        #[case] expected: &[u8],
+    ) {
+        let encodable = CountPrefix::<_, u8, Error>::new(items);
+
+        let mut buf = [0u8; BUF_SIZE];
+        let mut encoder = &mut buf as &mut [u8];
+        encodable.encode(&mut encoder).unwrap();
+        let written = BUF_SIZE - encoder.len();
+
+        assert_eq!(&buf[..written], expected);
+    }
+
+    #[test]
+    fn assert_that_iterators_without_an_exact_size_are_counted() {
+        let items = [1u8, 2, 3, 4, 5, 6];
+        let encodable =
+            CountPrefix::<_, BE<u16>, Error>::new(items.iter().filter(|item| *item % 2 == 0));
+
+        let mut buf = [0u8; BUF_SIZE];
+        let mut encoder = &mut buf as &mut [u8];
+        encodable.encode(&mut encoder).unwrap();
+        let written = BUF_SIZE - encoder.len();
+
+        assert_eq!(&buf[..written], b"\x00\x03\x02\x04\x06");
+    }
+
+    #[test]
+    fn assert_that_size_hints_are_not_trusted() {
+        /// An iterator whose exact-looking size hint is wrong.
+        #[derive(Clone)]
+        struct Lying(core::ops::Range<u8>);
+
+        impl Iterator for Lying {
+            type Item = u8;
+
+            fn next(&mut self) -> Option<u8> {
+                self.0.next()
+            }
+
+            fn size_hint(&self) -> (usize, Option<usize>) {
+                (1, Some(1))
+            }
+        }
+
+        let encodable = CountPrefix::<_, u8, Error>::new(Lying(0..3));
+
+        let mut buf = [0u8; BUF_SIZE];
+        let mut encoder = &mut buf as &mut [u8];
+        encodable.encode(&mut encoder).unwrap();
+        let written = BUF_SIZE - encoder.len();
+
+        assert_eq!(&buf[..written], b"\x03\x00\x01\x02");
+    }
+
+    #[test]
+    fn assert_that_count_overflows_are_reported() {
+        let items = [0u8; 256];
+        let encodable = CountPrefix::<_, u8, Error>::new(&items);
+
+        let mut buf = [0u8; BUF_SIZE];
+        let mut encoder = &mut buf as &mut [u8];
+
+        assert_eq!(encodable.encode(&mut encoder), Err(Error::TooLarge));
+    }
+
+    #[test]
+    fn assert_that_count_prefix_accessors_work() {
+        let encodable = CountPrefix::<_, u8, Error>::new([1u8, 2, 3]);
+        let borrowed: &[u8; 3] = encodable.borrow();
+        assert_eq!(borrowed, &[1u8, 2, 3]);
+        assert_eq!(encodable.as_ref(), &[1u8, 2, 3]);
+        assert_eq!(encodable.len(), 3);
+        assert_eq!(encodable, Clone::clone(&encodable));
+        assert_eq!(encodable.into_inner(), [1u8, 2, 3]);
+    }
+}
@@ -0,0 +1,101 @@
+/// An error that occurs when a number is too large to be encoded as a
+/// [`QuicVarInt`](crate::combinators::QuicVarInt).
+///
+/// QUIC variable-length integers can only represent numbers up to
+/// 2<sup>62</sup> - 1.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct QuicVarIntOutOfRange;
+
+impl core::error::Error for QuicVarIntOutOfRange {}
+impl core::fmt::Display for QuicVarIntOutOfRange {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(
+            f,
+            "The number is too large to be encoded as a QUIC variable-length integer"
+        )
+    }
+}
+
+/// An error that occurs when an adjusted length cannot be represented.
+///
+/// Returned by [`AdjustedLengthPrefix`](crate::combinators::AdjustedLengthPrefix)
+/// when adding the adjustment or the size of the prefix overflows a [`usize`],
+/// or when a negative adjustment is larger than the size of the value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct LengthOutOfRange;
+
+impl core::error::Error for LengthOutOfRange {}
+impl core::fmt::Display for LengthOutOfRange {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(f, "The adjusted length is negative or too large")
+    }
+}
+
+/// An error that occurs when a value does not fit into its declared bit width.
+///
+/// Returned by [`Bitfield`](crate::combinators::Bitfield) when a field value
+/// has bits set beyond its width, or when the widths of all the fields add up
+/// to more bits than the packed integer has.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct WidthOverflow;
+
+impl core::error::Error for WidthOverflow {}
+impl core::fmt::Display for WidthOverflow {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(f, "The value does not fit into its declared bit width")
+    }
+}
+
+/// An error that occurs when a value does not fit into its fixed-size field.
+///
+/// Returned by [`Pad`](crate::combinators::Pad),
+/// [`PadTo`](crate::combinators::PadTo) and
+/// [`FixedWidth`](crate::combinators::FixedWidth) when the encoded value is
+/// larger than its field.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct FieldOverflow;
+
+impl core::error::Error for FieldOverflow {}
+impl core::fmt::Display for FieldOverflow {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(f, "The value does not fit into its fixed-size field")
+    }
+}
+
+#[cfg(all(test, feature = "alloc"))]
+mod tests {
+    use alloc::string::ToString;
+
+    use super::*;
+
+    #[test]
+    fn assert_that_quic_var_int_out_of_range_displays_correctly() {
+        let err = QuicVarIntOutOfRange;
+        let msg = err.to_string();
+        assert_eq!(
+            msg,
+            "The number is too large to be encoded as a QUIC variable-length integer"
+        );
+    }
+
+    #[test]
+    fn assert_that_length_out_of_range_displays_correctly() {
+        let err = LengthOutOfRange;
+        let msg = err.to_string();
+        assert_eq!(msg, "The adjusted length is negative or too large");
+    }
+
+    #[test]
+    fn assert_that_width_overflow_displays_correctly() {
+        let err = WidthOverflow;
+        let msg = err.to_string();
+        assert_eq!(msg, "The value does not fit into its declared bit width");
+    }
+
+    #[test]
+    fn assert_that_field_overflow_displays_correctly() {
+        let err = FieldOverflow;
+        let msg = err.to_string();
+        assert_eq!(msg, "The value does not fit into its fixed-size field");
+    }
+}
@@ -0,0 +1,393 @@
+use core::borrow::Borrow;
+use core::ffi::CStr;
+use core::fmt::Debug;
+use core::marker::PhantomData;
+use core::ops::Deref;
+
+use super::pad::put_fill;
+use super::FieldOverflow;
+use crate::ByteEncoder;
+use crate::Encodable;
+
+/// What [`FixedWidth`] does with a value that is longer than its field.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub enum OverflowPolicy {
+    /// Returns [`FieldOverflow`] without writing anything.
+    #[default]
+    Error,
+    /// Truncates the value to the longest prefix that fits into the field and
+    /// does not split a UTF-8 sequence.
+    TruncateUtf8,
+    /// Truncates the value to the size of the field, even in the middle of a
+    /// UTF-8 sequence.
+    TruncateBytes,
+}
+
+impl OverflowPolicy {
+    /// Returns the prefix of `bytes` that fits into a field of `len` bytes.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`FieldOverflow`] if `bytes` is longer than `len` and the
+    /// policy is [`OverflowPolicy::Error`].
+    #[inline]
+    pub fn fit(self, bytes: &[u8], len: usize) -> Result<&[u8], FieldOverflow> {
+        if bytes.len() <= len {
+            return Ok(bytes);
+        }
+        match self {
+            Self::Error => Err(FieldOverflow),
+            Self::TruncateBytes => Ok(&bytes[..len]),
+            Self::TruncateUtf8 => {
+                // Continuation bytes look like `0b10xx_xxxx`, so the cut is
+                // moved back until it lands on the start of a sequence.
+                let mut end = len;
+                while end > 0 && bytes[end] & 0xC0 == 0x80 {
+                    end -= 1;
+                }
+                Ok(&bytes[..end])
+            }
+        }
+    }
+}
+
+/// Encodes a string or byte slice into a field of exactly `N` bytes.
+///
+/// Shorter values are padded with a fill byte, which defaults to `0`. What
+/// happens to longer values is decided by the [`OverflowPolicy`], which
+/// defaults to returning [`FieldOverflow`]. This is the layout of the string
+/// fields of C structs and of many legacy formats such as tar headers, dBase
+/// files or `ID3v1` tags.
+///
+/// [`FixedWidth`] can be used with [`&str`](str), [`&[u8]`](slice) and
+/// [`&CStr`](CStr), as well as their owned counterparts when the `alloc`
+/// feature is enabled. The null terminator of a C string is not written:
+/// the padding takes its place if the string is shorter than the field.
+///
+/// # Examples
+///
+/// ```rust
+/// use encode::Encodable;
+/// use encode::combinators::{FieldOverflow, FixedWidth, OverflowPolicy};
+/// use encode::encoders::InsufficientSpace;
+///
+/// #[derive(Debug, PartialEq)]
+/// enum Error {
+///     InsufficientSpace,
+///     FieldOverflow,
+/// }
+/// # impl From<InsufficientSpace> for Error { fn from(_: InsufficientSpace) -> Self { Error::InsufficientSpace } }
+/// # impl From<FieldOverflow> for Error { fn from(_: FieldOverflow) -> Self { Error::FieldOverflow } }
+///
+/// let mut buf = [0u8; 8];
+/// let mut encoder = &mut buf as &mut [u8];
+/// // A space padded field, as found in ID3v1 tags.
+/// let artist = FixedWidth::<_, 8, Error>::new("Björk").with_fill(b' ');
+/// artist.encode(&mut encoder).unwrap();
+/// assert_eq!(&buf, "Björk  ".as_bytes());
+///
+/// let mut encoder = &mut buf as &mut [u8];
+/// let title = FixedWidth::<_, 4, Error>::new("Jóga");
+/// assert_eq!(title.encode(&mut encoder), Err(Error::FieldOverflow));
+///
+/// let mut encoder = &mut buf as &mut [u8];
+/// let title = title.with_policy(OverflowPolicy::TruncateUtf8);
+/// title.encode(&mut encoder).unwrap();
+/// assert_eq!(&buf[..4], b"J\xC3\xB3g");
+/// ```
+#[doc(alias("fixed", "char_array", "truncate"))]
+pub struct FixedWidth<S, const N: usize, Error> {
+    value: S,
+    fill: u8,
+    policy: OverflowPolicy,
+    phantom: PhantomData<Error>,
+}
+
+impl<S, const N: usize, Error> FixedWidth<S, N, Error> {
+    /// Creates a new [`FixedWidth`] combinator.
+    #[inline]
+    #[must_use]
+    pub const fn new(value: S) -> Self {
+        Self {
+            value,
+            fill: 0,
+            policy: OverflowPolicy::Error,
+            phantom: PhantomData,
+        }
+    }
+    /// Sets the byte used for padding.
+    #[inline]
+    #[must_use]
+    pub const fn with_fill(mut self, fill: u8) -> Self {
+        self.fill = fill;
+        self
+    }
+    /// Sets what happens to values that are longer than the field.
+    #[inline]
+    #[must_use]
+    pub const fn with_policy(mut self, policy: OverflowPolicy) -> Self {
+        self.policy = policy;
+        self
+    }
+    /// Consumes the combinator and returns the inner value.
+    #[inline]
+    #[must_use]
+    pub fn into_inner(self) -> S {
+        self.value
+    }
+    /// Writes `bytes` and the padding into the encoder.
+    #[inline]
+    fn encode_bytes<E>(&self, bytes: &[u8], encoder: &mut E) -> Result<(), Error>
+    where
+        E: ByteEncoder,
+        Error: From<E::Error> + From<FieldOverflow>,
+    {
+        let bytes = self.policy.fit(bytes, N)?;
+        encoder.put_slice(bytes)?;
+        put_fill(encoder, self.fill, N - bytes.len())?;
+        Ok(())
+    }
+}
+
+impl<S, const N: usize, Error> AsRef<S> for FixedWidth<S, N, Error> {
+    #[inline]
+    fn as_ref(&self) -> &S {
+        &self.value
+    }
+}
+
+impl<S, const N: usize, Error> Deref for FixedWidth<S, N, Error> {
+    type Target = S;
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        self.as_ref()
+    }
+}
+
+impl<S, const N: usize, Error> Borrow<S> for FixedWidth<S, N, Error> {
+    #[inline]
+    fn borrow(&self) -> &S {
+        &self.value
+    }
+}
+
+macro_rules! impl_fixed_width_for {
+    ($($(#[$attr:meta])* $T:ty => $to_bytes:ident,)*) => {
+        $(
+            $(#[$attr])*
+            impl<E, const N: usize, Error> Encodable<E> for FixedWidth<$T, N, Error>
+            where
+                E: ByteEncoder,
+                Error: From<E::Error> + From<FieldOverflow>,
+            {
+                type Error = Error;
+
+                #[inline]
+                fn encode(&self, encoder: &mut E) -> Result<(), Self::Error> {
+                    self.encode_bytes(self.value.$to_bytes(), encoder)
+                }
+            }
+        )*
+    };
+}
+
+impl_fixed_width_for! {
+    &str => as_bytes,
+    &[u8] => as_ref,
+    &CStr => to_bytes,
+    #[cfg(feature = "alloc")]
+    alloc::string::String => as_bytes,
+    #[cfg(feature = "alloc")]
+    alloc::vec::Vec<u8> => as_slice,
+    #[cfg(feature = "alloc")]
+    alloc::ffi::CString => as_bytes,
+}
+
+// Manual trait implementations because the derive macro does not support
+// phantom data fields.
+impl<S, const N: usize, Error> Debug for FixedWidth<S, N, Error>
+where
+    S: Debug,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("FixedWidth")
+            .field("value", &self.value)
+            .field("fill", &self.fill)
+            .field("policy", &self.policy)
+            .finish()
+    }
+}
+impl<S, const N: usize, Error> Clone for FixedWidth<S, N, Error>
+where
+    S: Clone,
+{
+    #[inline]
+    fn clone(&self) -> Self {
+        Self::new(self.value.clone())
+            .with_fill(self.fill)
+            .with_policy(self.policy)
+    }
+}
+impl<S, const N: usize, Error> Copy for FixedWidth<S, N, Error> where S: Copy {}
+impl<S, const N: usize, Error> Default for FixedWidth<S, N, Error>
+where
+    S: Default,
+{
+    #[inline]
+    fn default() -> Self {
+        Self::new(Default::default())
+    }
+}
+impl<S, const N: usize, Error> PartialEq for FixedWidth<S, N, Error>
+where
+    S: PartialEq,
+{
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.value == other.value && self.fill == other.fill && self.policy == other.policy
+    }
+}
+impl<S, const N: usize, Error> Eq for FixedWidth<S, N, Error> where S: Eq {}
+
+#[cfg(test)]
+mod tests {
+    use core::borrow::Borrow;
+
+    use rstest::rstest;
+
+    use super::*;
+    use crate::encoders::InsufficientSpace;
+    use crate::EncodableSize;
+
+    const BUF_SIZE: usize = 16;
+
+    #[derive(Debug, PartialEq)]
+    enum Error {
+        InsufficientSpace,
+        FieldOverflow,
+    }
+
+    impl From<InsufficientSpace> for Error {
+        fn from(_: InsufficientSpace) -> Self {
+            Error::InsufficientSpace
+        }
+    }
+
+    impl From<FieldOverflow> for Error {
+        fn from(_: FieldOverflow) -> Self {
+            Error::FieldOverflow
+        }
+    }
+
+    impl From<core::convert::Infallible> for Error {
+        fn from(err: core::convert::Infallible) -> Self {
+            match err {}
+        }
+    }
+
+    #[rstest]
+    #[case::fits(OverflowPolicy::Error, "abc".as_bytes(), Ok(&b"abc"[..]))]
+    #[case::exact(OverflowPolicy::Error, "abcd".as_bytes(), Ok(&b"abcd"[..]))]
+    #[case::too_long(OverflowPolicy::Error, "abcde".as_bytes(), Err(FieldOverflow))]
+    #[case::ascii(OverflowPolicy::TruncateUtf8, "abcde".as_bytes(), Ok(&b"abcd"[..]))]
+    #[case::on_boundary(OverflowPolicy::TruncateUtf8, "abcé".as_bytes(), Ok(&b"abc"[..]))]
+    #[case::four_byte(OverflowPolicy::TruncateUtf8, "a🦀".as_bytes(), Ok(&b"a"[..]))]
+    #[case::only_multibyte(OverflowPolicy::TruncateUtf8, "🦀🦀".as_bytes(), Ok("🦀".as_bytes()))]
+    #[case::bytes(OverflowPolicy::TruncateBytes, "abcé".as_bytes(), Ok(&b"abc\xC3"[..]))]
+    fn assert_that_values_are_fitted_according_to_the_policy(
+        #[case] policy: OverflowPolicy,
+        #[case] value: &[u8],
+        #[case] expected: Result<&[u8], FieldOverflow>,
+    ) {
+        assert_eq!(policy.fit(value, 4), expected);
+    }
+
+    #[test]
+    fn assert_that_truncating_to_an_empty_field_writes_nothing() {
+        assert_eq!(
+            OverflowPolicy::TruncateUtf8.fit("é".as_bytes(), 0),
+            Ok(&b""[..])
+        );
+    }
+
+    #[test]
+    fn assert_that_every_source_type_is_padded() {
+        let mut buf = [0xFFu8; BUF_SIZE];
+        let mut encoder = &mut buf as &mut [u8];
+        (
+            FixedWidth::<_, 4, Error>::new("ab"),
+            FixedWidth::<_, 4, Error>::new(&b"cd"[..]).with_fill(b' '),
+            FixedWidth::<_, 4, Error>::new(c"ef"),
+            FixedWidth::<_, 2, Error>::new(c"gh"),
+        )
+            .encode(&mut encoder)
+            .unwrap();
+        let written = BUF_SIZE - encoder.len();
+
+        assert_eq!(&buf[..written], b"ab\0\0cd  ef\0\0gh");
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn assert_that_owned_values_can_be_encoded() {
+        use alloc::{ffi::CString, string::String, vec::Vec};
+
+        let mut buf = Vec::new();
+        (
+            FixedWidth::<_, 3, Error>::new(String::from("a")),
+            FixedWidth::<_, 3, Error>::new(Vec::from(*b"bc")),
+            FixedWidth::<_, 3, Error>::new(CString::from(c"def")),
+        )
+            .encode(&mut buf)
+            .unwrap();
+
+        assert_eq!(&buf, b"a\0\0bc\0def");
+    }
+
+    #[test]
+    fn assert_that_overflowing_values_write_nothing() {
+        let mut buf = [0u8; BUF_SIZE];
+        let mut encoder = &mut buf as &mut [u8];
+
+        assert_eq!(
+            FixedWidth::<_, 2, Error>::new("abc").encode(&mut encoder),
+            Err(Error::FieldOverflow)
+        );
+        assert_eq!(encoder.len(), BUF_SIZE);
+    }
+
+    #[test]
+    fn assert_that_encoder_errors_are_propagated() {
+        let mut buf = [0u8; 2];
+        let mut encoder = &mut buf as &mut [u8];
+
+        assert_eq!(
+            FixedWidth::<_, 4, Error>::new("a").encode(&mut encoder),
+            Err(Error::InsufficientSpace)
+        );
+    }
+
+    #[test]
+    fn assert_that_the_encoded_size_is_the_field_width() {
+        let field =
+            FixedWidth::<_, 100, Error>::new("abcdefgh").with_policy(OverflowPolicy::TruncateBytes);
+        assert_eq!(field.encoded_size(), Ok(100));
+        let field =
+            FixedWidth::<_, 3, Error>::new("abcdefgh").with_policy(OverflowPolicy::TruncateBytes);
+        assert_eq!(field.encoded_size(), Ok(3));
+    }
+
+    #[test]
+    fn assert_that_fixed_width_accessors_work() {
+        let field = FixedWidth::<_, 4, Error>::new("ab");
+        let borrowed: &&str = field.borrow();
+        assert_eq!(*borrowed, "ab");
+        assert_eq!(field.as_ref(), &"ab");
+        assert_eq!(*field, "ab");
+        assert_eq!(field, Clone::clone(&field));
+        assert_ne!(field, field.with_fill(b' '));
+        assert_ne!(field, field.with_policy(OverflowPolicy::TruncateUtf8));
+        assert_eq!(field.into_inner(), "ab");
+        assert_eq!(FixedWidth::<&str, 4, Error>::default().into_inner(), "");
+    }
+}
@@ -0,0 +1,334 @@
+use core::borrow::Borrow;
+use core::fmt::Debug;
+use core::marker::PhantomData;
+use core::ops::Deref;
+
+use crate::encoders::CobsEncoder;
+use crate::encoders::HdlcEncoder;
+use crate::encoders::SlipEncoder;
+use crate::ByteEncoder;
+
+macro_rules! impl_framing_combinator {
+    ($(
+        $(#[$attr:meta])*
+        $Name:ident => $Encoder:ident, opening: $opening:expr;
+    )*) => {
+        $(
+            $(#[$attr])*
+            #[repr(transparent)]
+            pub struct $Name<Encodable, Error> {
+                encodable: Encodable,
+                phantom: PhantomData<Error>,
+            }
+
+            impl<Encodable, Error> $Name<Encodable, Error> {
+                #[doc = concat!("Creates a new [`", stringify!($Name), "`] combinator.")]
+                #[inline]
+                #[must_use]
+                pub const fn new(encodable: Encodable) -> Self {
+                    Self {
+                        encodable,
+                        phantom: PhantomData,
+                    }
+                }
+                /// Consumes the combinator and returns the inner encodable.
+                #[inline]
+                #[must_use]
+                pub fn into_inner(self) -> Encodable {
+                    self.encodable
+                }
+            }
+
+            impl<Encodable, Error> From<Encodable> for $Name<Encodable, Error> {
+                #[inline]
+                fn from(value: Encodable) -> Self {
+                    Self::new(value)
+                }
+            }
+
+            impl<Encodable, Error> AsRef<Encodable> for $Name<Encodable, Error> {
+                #[inline]
+                fn as_ref(&self) -> &Encodable {
+                    &self.encodable
+                }
+            }
+
+            impl<Encodable, Error> Deref for $Name<Encodable, Error> {
+                type Target = Encodable;
+                #[inline]
+                fn deref(&self) -> &Self::Target {
+                    self.as_ref()
+                }
+            }
+
+            impl<Encodable, Error> Borrow<Encodable> for $Name<Encodable, Error> {
+                #[inline]
+                fn borrow(&self) -> &Encodable {
+                    &self.encodable
+                }
+            }
+
+            impl<Encodable, Encoder, Error> crate::Encodable<Encoder> for $Name<Encodable, Error>
+            where
+                Encoder: ByteEncoder,
+                for<'a> Encodable: crate::Encodable<$Encoder<'a, Encoder>>,
+                for<'a> Error: From<<Encodable as crate::Encodable<$Encoder<'a, Encoder>>>::Error>,
+                Error: From<Encoder::Error>,
+            {
+                type Error = Error;
+
+                #[inline]
+                fn encode(&self, encoder: &mut Encoder) -> Result<(), Self::Error> {
+                    let opening: Option<u8> = $opening;
+                    if let Some(delimiter) = opening {
+                        encoder.put_byte(delimiter)?;
+                    }
+                    let mut framer = $Encoder::new(encoder);
+                    self.encodable.encode(&mut framer)?;
+                    framer.finish()?;
+                    Ok(())
+                }
+            }
+
+            // Manual trait implementations because the derive macro does not support
+            // phantom data fields.
+            impl<Encodable, Error> Debug for $Name<Encodable, Error>
+            where
+                Encodable: Debug,
+            {
+                fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                    f.debug_struct(stringify!($Name))
+                        .field("encodable", &self.encodable)
+                        .finish()
+                }
+            }
+            impl<Encodable, Error> Clone for $Name<Encodable, Error>
+            where
+                Encodable: Clone,
+            {
+                #[inline]
+                fn clone(&self) -> Self {
+                    Self::new(self.encodable.clone())
+                }
+            }
+            impl<Encodable, Error> Copy for $Name<Encodable, Error> where Encodable: Copy {}
+            impl<Encodable, Error> Default for $Name<Encodable, Error>
+            where
+                Encodable: Default,
+            {
+                #[inline]
+                fn default() -> Self {
+                    Self::new(Default::default())
+                }
+            }
+            impl<Encodable, Error> PartialEq for $Name<Encodable, Error>
+            where
+                Encodable: PartialEq,
+            {
+                #[inline]
+                fn eq(&self, other: &Self) -> bool {
+                    self.encodable == other.encodable
+                }
+            }
+            impl<Encodable, Error> Eq for $Name<Encodable, Error> where Encodable: Eq {}
+            impl<Encodable, Error> PartialOrd for $Name<Encodable, Error>
+            where
+                Encodable: PartialOrd,
+            {
+                #[inline]
+                fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+                    self.encodable.partial_cmp(&other.encodable)
+                }
+            }
+            impl<Encodable, Error> Ord for $Name<Encodable, Error>
+            where
+                Encodable: Ord,
+            {
+                #[inline]
+                fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+                    self.encodable.cmp(&other.encodable)
+                }
+            }
+        )*
+    };
+}
+
+impl_framing_combinator! {
+    /// Encodes a value as a frame stuffed with
+    /// [COBS](https://en.wikipedia.org/wiki/Consistent_Overhead_Byte_Stuffing),
+    /// followed by a `0x00` delimiter.
+    ///
+    /// The value is stuffed on the fly with a [`CobsEncoder`], so it is only
+    /// encoded once, no allocation is needed, and the exact stuffed size can
+    /// be computed with [`EncodableSize`](crate::EncodableSize).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use encode::Encodable;
+    /// use encode::combinators::Cobs;
+    /// use encode::encoders::InsufficientSpace;
+    ///
+    /// let mut buf = [0u8; 8];
+    /// let mut encoder = &mut buf as &mut [u8];
+    /// Cobs::<_, InsufficientSpace>::new(b"\x11\x22\x00\x33").encode(&mut encoder).unwrap();
+    /// assert_eq!(&buf[..6], b"\x03\x11\x22\x02\x33\x00");
+    /// ```
+    #[doc(alias("cobs", "framing", "byte_stuffing"))]
+    Cobs => CobsEncoder, opening: None;
+
+    /// Encodes a value as a [SLIP](https://www.rfc-editor.org/rfc/rfc1055)
+    /// frame.
+    ///
+    /// The frame starts and ends with the `0xC0` delimiter, as recommended by
+    /// RFC 1055 to flush any line noise, and the value is escaped on the fly
+    /// with a [`SlipEncoder`]. No allocation is needed, and the exact escaped
+    /// size can be computed with [`EncodableSize`](crate::EncodableSize).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use encode::Encodable;
+    /// use encode::combinators::Slip;
+    /// use encode::encoders::InsufficientSpace;
+    ///
+    /// let mut buf = [0u8; 8];
+    /// let mut encoder = &mut buf as &mut [u8];
+    /// Slip::<_, InsufficientSpace>::new(b"\x01\xC0\x02").encode(&mut encoder).unwrap();
+    /// assert_eq!(&buf[..6], b"\xC0\x01\xDB\xDC\x02\xC0");
+    /// ```
+    #[doc(alias("slip", "framing", "byte_stuffing"))]
+    Slip => SlipEncoder, opening: Some(SlipEncoder::<()>::END);
+
+    /// Encodes a value as an HDLC-like frame, as used by PPP
+    /// ([RFC 1662](https://www.rfc-editor.org/rfc/rfc1662)).
+    ///
+    /// The frame starts and ends with the `0x7E` flag sequence, and the value
+    /// is escaped on the fly with an [`HdlcEncoder`]. No allocation is needed,
+    /// and the exact escaped size can be computed with
+    /// [`EncodableSize`](crate::EncodableSize).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use encode::Encodable;
+    /// use encode::combinators::Hdlc;
+    /// use encode::encoders::InsufficientSpace;
+    ///
+    /// let mut buf = [0u8; 8];
+    /// let mut encoder = &mut buf as &mut [u8];
+    /// Hdlc::<_, InsufficientSpace>::new(b"\x01\x7E\x02").encode(&mut encoder).unwrap();
+    /// assert_eq!(&buf[..6], b"\x7E\x01\x7D\x5E\x02\x7E");
+    /// ```
+    #[doc(alias("hdlc", "ppp", "framing", "byte_stuffing"))]
+    Hdlc => HdlcEncoder, opening: Some(HdlcEncoder::<()>::FLAG);
+}
+
+#[cfg(test)]
+mod tests {
+    use core::borrow::Borrow;
+    use core::convert::Infallible;
+    use core::num::TryFromIntError;
+
+    use super::*;
+    use crate::combinators::LengthPrefix;
+    use crate::encoders::InsufficientSpace;
+    use crate::Encodable;
+    use crate::EncodableSize;
+
+    const BUF_SIZE: usize = 64;
+
+    #[derive(Debug, PartialEq)]
+    enum Error {
+        InsufficientSpace,
+        TooLarge,
+    }
+
+    impl From<InsufficientSpace> for Error {
+        fn from(_: InsufficientSpace) -> Self {
+            Error::InsufficientSpace
+        }
+    }
+
+    impl From<TryFromIntError> for Error {
+        fn from(_: TryFromIntError) -> Self {
+            Error::TooLarge
+        }
+    }
+
+    impl From<Infallible> for Error {
+        fn from(err: Infallible) -> Self {
+            match err {}
+        }
+    }
+
+    #[test]
+    fn assert_that_values_are_framed() {
+        let value = LengthPrefix::<_, u8, Error>::new(&b"\x00\xC0\x7E"[..]);
+
+        let mut buf = [0u8; BUF_SIZE];
+        let mut encoder = &mut buf as &mut [u8];
+        (
+            Cobs::<_, Error>::new(value),
+            Slip::<_, Error>::new(value),
+            Hdlc::<_, Error>::new(value),
+        )
+            .encode(&mut encoder)
+            .unwrap();
+        let written = BUF_SIZE - encoder.len();
+
+        assert_eq!(
+            &buf[..written],
+            b"\x02\x03\x03\xC0\x7E\x00\
+              \xC0\x03\x00\xDB\xDC\x7E\xC0\
+              \x7E\x03\x00\xC0\x7D\x5E\x7E"
+        );
+    }
+
+    #[test]
+    fn assert_that_the_framed_size_is_exact() {
+        let value = &b"\x00\xC0\x7E\x7D"[..];
+        assert_eq!(Cobs::<_, Infallible>::new(value).encoded_size(), Ok(6));
+        assert_eq!(Slip::<_, Infallible>::new(value).encoded_size(), Ok(7));
+        assert_eq!(Hdlc::<_, Infallible>::new(value).encoded_size(), Ok(8));
+    }
+
+    #[test]
+    fn assert_that_errors_are_propagated() {
+        let mut buf = [0u8; 4];
+        let mut encoder = &mut buf as &mut [u8];
+        assert_eq!(
+            Slip::<_, InsufficientSpace>::new(&b"\xC0\xC0"[..]).encode(&mut encoder),
+            Err(InsufficientSpace)
+        );
+
+        let mut encoder = &mut buf as &mut [u8];
+        let too_large = LengthPrefix::<_, u8, Error>::new(&[0u8; 256][..]);
+        assert_eq!(
+            Cobs::<_, Error>::new(too_large).encode(&mut encoder),
+            Err(Error::TooLarge)
+        );
+    }
+
+    #[test]
+    fn assert_that_inner_errors_are_converted() {
+        let mut buf = [0u8; 2];
+        let mut encoder = &mut buf as &mut [u8];
+        assert_eq!(
+            Cobs::<_, Error>::new(&b"ab"[..]).encode(&mut encoder),
+            Err(Error::InsufficientSpace)
+        );
+    }
+
+    #[test]
+    fn assert_that_framing_accessors_work() {
+        let cobs = Cobs::<u8, Error>::from(42u8);
+        let borrowed: &u8 = cobs.borrow();
+        assert_eq!(*borrowed, 42u8);
+        assert_eq!(cobs.as_ref(), &42u8);
+        assert_eq!(*cobs, 42u8);
+        assert_eq!(cobs, Clone::clone(&cobs));
+        assert_eq!(cobs.into_inner(), 42u8);
+        assert_eq!(Hdlc::<u8, Error>::default().into_inner(), 0u8);
+    }
+}
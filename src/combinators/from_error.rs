@@ -177,7 +177,7 @@ mod tests {
     #[test]
     fn assert_that_from_error_clone_works() {
         let fe = FromError::<_, Infallible>::new(42u8);
-        let clone = fe.clone();
+        let clone = Clone::clone(&fe);
         assert_eq!(*fe, *clone);
     }
 
@@ -0,0 +1,258 @@
+use core::borrow::Borrow;
+use core::fmt::Debug;
+use core::marker::PhantomData;
+use core::ops::Deref;
+
+use crate::encoders::HexEncoder;
+use crate::StrEncoder;
+
+/// Encodes the bytes of an encodable as hexadecimal text.
+///
+/// The encodable is encoded through a [`HexEncoder`], so its bytes are
+/// converted on the fly without an intermediate allocation. The output only
+/// requires a [`StrEncoder`], so the same value can be written as text into
+/// a [`String`](alloc::string::String) or a
+/// [`Formatter`](core::fmt::Formatter), as well as into any byte encoder.
+/// Digits are lowercase unless [`uppercase`](Hex::uppercase) is called.
+///
+/// # Examples
+///
+/// ```rust
+/// use core::convert::Infallible;
+/// use core::fmt;
+/// use encode::Encodable;
+/// use encode::combinators::{Hex, BE};
+///
+/// struct Id(u32);
+///
+/// impl fmt::Display for Id {
+///     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+///         Hex::<_, fmt::Error>::new(BE::new(self.0)).uppercase().encode(f)
+///     }
+/// }
+///
+/// # #[cfg(feature = "alloc")] {
+/// assert_eq!(Id(0xC0FFEE).to_string(), "00C0FFEE");
+///
+/// let mut buf = Vec::new();
+/// Hex::<_, Infallible>::new(b"\x01\xAB").encode(&mut buf).unwrap();
+/// assert_eq!(&buf, b"01ab");
+/// # }
+/// ```
+#[doc(alias("hexadecimal", "base16"))]
+pub struct Hex<Encodable, Error> {
+    encodable: Encodable,
+    uppercase: bool,
+    phantom: PhantomData<Error>,
+}
+
+impl<Encodable, Error> Hex<Encodable, Error> {
+    /// Creates a new [`Hex`] combinator that writes lowercase digits.
+    #[inline]
+    #[must_use]
+    pub const fn new(encodable: Encodable) -> Self {
+        Self {
+            encodable,
+            uppercase: false,
+            phantom: PhantomData,
+        }
+    }
+    /// Makes the combinator write uppercase digits.
+    #[inline]
+    #[must_use]
+    pub const fn uppercase(mut self) -> Self {
+        self.uppercase = true;
+        self
+    }
+    /// Consumes the combinator and returns the inner encodable.
+    #[inline]
+    #[must_use]
+    pub fn into_inner(self) -> Encodable {
+        self.encodable
+    }
+}
+
+impl<Encodable, Error> From<Encodable> for Hex<Encodable, Error> {
+    #[inline]
+    fn from(value: Encodable) -> Self {
+        Self::new(value)
+    }
+}
+
+impl<Encodable, Error> AsRef<Encodable> for Hex<Encodable, Error> {
+    #[inline]
+    fn as_ref(&self) -> &Encodable {
+        &self.encodable
+    }
+}
+
+impl<Encodable, Error> Deref for Hex<Encodable, Error> {
+    type Target = Encodable;
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        self.as_ref()
+    }
+}
+
+impl<Encodable, Error> Borrow<Encodable> for Hex<Encodable, Error> {
+    #[inline]
+    fn borrow(&self) -> &Encodable {
+        &self.encodable
+    }
+}
+
+impl<Encodable, Encoder, Error> crate::Encodable<Encoder> for Hex<Encodable, Error>
+where
+    Encoder: StrEncoder,
+    for<'a> Encodable: crate::Encodable<HexEncoder<'a, Encoder>>,
+    for<'a> Error: From<<Encodable as crate::Encodable<HexEncoder<'a, Encoder>>>::Error>,
+    Error: From<Encoder::Error>,
+{
+    type Error = Error;
+
+    #[inline]
+    fn encode(&self, encoder: &mut Encoder) -> Result<(), Self::Error> {
+        let mut hex = HexEncoder::new(encoder);
+        if self.uppercase {
+            hex = hex.uppercase();
+        }
+        self.encodable.encode(&mut hex)?;
+        Ok(())
+    }
+}
+
+// Manual trait implementations because the derive macro does not support
+// phantom data fields.
+impl<Encodable, Error> Debug for Hex<Encodable, Error>
+where
+    Encodable: Debug,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Hex")
+            .field("encodable", &self.encodable)
+            .field("uppercase", &self.uppercase)
+            .finish()
+    }
+}
+impl<Encodable, Error> Clone for Hex<Encodable, Error>
+where
+    Encodable: Clone,
+{
+    #[inline]
+    fn clone(&self) -> Self {
+        Self {
+            encodable: self.encodable.clone(),
+            uppercase: self.uppercase,
+            phantom: PhantomData,
+        }
+    }
+}
+impl<Encodable, Error> Copy for Hex<Encodable, Error> where Encodable: Copy {}
+impl<Encodable, Error> Default for Hex<Encodable, Error>
+where
+    Encodable: Default,
+{
+    #[inline]
+    fn default() -> Self {
+        Self::new(Default::default())
+    }
+}
+impl<Encodable, Error> PartialEq for Hex<Encodable, Error>
+where
+    Encodable: PartialEq,
+{
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.encodable == other.encodable && self.uppercase == other.uppercase
+    }
+}
+impl<Encodable, Error> Eq for Hex<Encodable, Error> where Encodable: Eq {}
+
+#[cfg(test)]
+mod tests {
+    use core::borrow::Borrow;
+    use core::convert::Infallible;
+
+    use super::*;
+    use crate::combinators::LE;
+    use crate::encoders::InsufficientSpace;
+    use crate::Encodable;
+    use crate::EncodableSize;
+
+    const BUF_SIZE: usize = 64;
+
+    #[test]
+    fn assert_that_byte_encodables_are_written_as_hex() {
+        let mut buf = [0u8; BUF_SIZE];
+        let mut encoder = &mut buf as &mut [u8];
+        (
+            Hex::<_, InsufficientSpace>::new((LE::new(0x0102u16), "A")),
+            Hex::<_, InsufficientSpace>::new(b"\xBE\xEF").uppercase(),
+        )
+            .encode(&mut encoder)
+            .unwrap();
+        let written = BUF_SIZE - encoder.len();
+
+        assert_eq!(&buf[..written], b"020141BEEF");
+    }
+
+    #[test]
+    fn assert_that_hex_can_be_written_into_a_formatter() {
+        struct Bytes<'a>(&'a [u8]);
+
+        impl core::fmt::Display for Bytes<'_> {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                Hex::<_, core::fmt::Error>::new(self.0).encode(f)
+            }
+        }
+
+        let mut buf = [0u8; BUF_SIZE];
+        let mut encoder = &mut buf as &mut [u8];
+        format_args!("{}", Bytes(b"\x00\x7F\xFF"))
+            .encode(&mut encoder)
+            .unwrap();
+        let written = BUF_SIZE - encoder.len();
+
+        assert_eq!(&buf[..written], b"007fff");
+    }
+
+    #[test]
+    fn assert_that_the_encoded_size_is_doubled() {
+        assert_eq!(Hex::<_, Infallible>::new("abc").encoded_size(), Ok(6));
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn assert_that_inner_errors_are_converted() {
+        use alloc::string::String;
+
+        #[derive(Debug, PartialEq)]
+        struct Error;
+
+        impl From<Infallible> for Error {
+            fn from(err: Infallible) -> Self {
+                match err {}
+            }
+        }
+
+        let mut text = String::new();
+        assert_eq!(
+            Hex::<_, Error>::new(&b"\xBE\xEF"[..]).encode(&mut text),
+            Ok(())
+        );
+        assert_eq!(text, "beef");
+    }
+
+    #[test]
+    fn assert_that_hex_accessors_work() {
+        let hex = Hex::<u8, Infallible>::from(42u8);
+        let borrowed: &u8 = hex.borrow();
+        assert_eq!(*borrowed, 42u8);
+        assert_eq!(hex.as_ref(), &42u8);
+        assert_eq!(*hex, 42u8);
+        assert_eq!(hex, Clone::clone(&hex));
+        assert_ne!(hex, hex.uppercase());
+        assert_eq!(hex.into_inner(), 42u8);
+        assert_eq!(Hex::<u8, Infallible>::default().into_inner(), 0u8);
+    }
+}
@@ -2,11 +2,19 @@ use core::borrow::Borrow;
 use core::num::NonZero;
 use core::ops::Deref;
 
+use super::PrefixWidth;
+use crate::decoders::NonZeroError;
+use crate::ByteDecoder;
 use crate::ByteEncoder;
+use crate::Decodable;
 use crate::Encodable;
 
 /// Encodes a number in little-endian order.
 ///
+/// Numbers can also be decoded from little-endian order. Decoding a
+/// [`NonZero`] number fails with [`NonZeroError::Zero`] if the decoded value is
+/// zero.
+///
 /// # Examples
 ///
 /// ```rust
@@ -19,6 +27,15 @@ use crate::Encodable;
 /// assert_eq!(&buf, &[1, 0], "Encoding a u16 in little-endian order means the least significant byte comes first");
 /// # }
 /// ```
+///
+/// ```rust
+/// use encode::Decodable;
+/// use encode::combinators::LE;
+///
+/// let mut input: &[u8] = &[1, 0];
+/// let num = LE::<u16>::decode(&mut input).unwrap();
+/// assert_eq!(*num, 1);
+/// ```
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
 #[repr(transparent)]
 pub struct LE<E> {
@@ -85,6 +102,17 @@ macro_rules! impl_encodeable_le_for_num {
                     encoder.put_slice(&self.num.to_le_bytes())
                 }
             }
+            impl<'de, D: ByteDecoder<'de>> Decodable<D> for LE<$T>
+            {
+                type Error = D::Error;
+
+                #[inline]
+                fn decode(decoder:&mut D) -> Result<Self, Self::Error> {
+                    <[u8; core::mem::size_of::<$T>()]>::decode(decoder)
+                        .map(<$T>::from_le_bytes)
+                        .map(Self::new)
+                }
+            }
         )*
 
     }
@@ -114,6 +142,16 @@ macro_rules! impl_encodeable_le_for_nonzero_num {
                     encoder.put_slice(&self.num.get().to_le_bytes())
                 }
             }
+            impl<'de, D: ByteDecoder<'de>> Decodable<D> for LE<NonZero<$T>>
+            {
+                type Error = NonZeroError<D::Error>;
+
+                #[inline]
+                fn decode(decoder:&mut D) -> Result<Self, Self::Error> {
+                    let num = LE::<$T>::decode(decoder)?.into_inner();
+                    NonZero::new(num).map(Self::new).ok_or(NonZeroError::Zero)
+                }
+            }
         )*
 
     }
@@ -122,6 +160,9 @@ macro_rules! impl_encodeable_le_for_nonzero_num {
 macro_rules! impl_try_from_le_for_num {
     ($($T:ty)*) => {
         $(
+            impl PrefixWidth for LE<$T> {
+                const FIXED_WIDTH: Option<usize> = Some(core::mem::size_of::<$T>());
+            }
             impl TryFrom<usize> for LE<$T> {
                 type Error = core::num::TryFromIntError;
 
@@ -130,6 +171,14 @@ macro_rules! impl_try_from_le_for_num {
                     <$T>::try_from(value).map(Self::new)
                 }
             }
+            impl TryFrom<LE<$T>> for usize {
+                type Error = core::num::TryFromIntError;
+
+                #[inline]
+                fn try_from(value: LE<$T>) -> Result<Self, Self::Error> {
+                    usize::try_from(value.num).map_err(Into::into)
+                }
+            }
         )*
     };
 }
@@ -146,6 +195,7 @@ mod tests {
     use rstest::rstest;
 
     use super::*;
+    use crate::decoders::UnexpectedEnd;
     use crate::Encodable;
 
     const BUF_SIZE: usize = 32;
@@ -223,7 +273,7 @@ mod tests {
     #[case::succeeds(5usize, Some(5u8))]
     #[case::overflows(256usize, None)]
     fn assert_that_le_u8_try_from_usize(#[case] val: usize, #[case] expected: Option<u8>) {
-        let result: Result<u8, TryFromIntError> = LE::<u8>::try_from(val).map(|le| le.into_inner());
+        let result: Result<u8, TryFromIntError> = LE::<u8>::try_from(val).map(LE::into_inner);
 
         match expected {
             Some(expected) => assert_eq!(result.unwrap(), expected),
@@ -257,4 +307,44 @@ mod tests {
         let val: NonZero<u16> = le.into();
         assert_eq!(val.get(), 42u16);
     }
+
+    #[test]
+    fn assert_that_u16_can_be_decoded_in_little_endian() {
+        let mut decoder = &[0x01u8, 0x00u8] as &[u8];
+        assert_eq!(LE::<u16>::decode(&mut decoder), Ok(LE::new(1u16)));
+        assert!(decoder.is_empty());
+    }
+
+    #[test]
+    fn assert_that_f64_can_be_decoded_in_little_endian() {
+        let bytes = 1.5f64.to_le_bytes();
+        let mut decoder = &bytes as &[u8];
+        assert_eq!(LE::<f64>::decode(&mut decoder), Ok(LE::new(1.5f64)));
+    }
+
+    #[test]
+    fn assert_that_decoding_a_truncated_number_fails() {
+        let mut decoder = &[0x01u8] as &[u8];
+        assert_eq!(LE::<u32>::decode(&mut decoder), Err(UnexpectedEnd));
+    }
+
+    #[rstest]
+    #[case::non_zero(&[0x01u8, 0x00u8], Ok(1))]
+    #[case::zero(&[0x00u8, 0x00u8], Err(NonZeroError::Zero))]
+    #[case::truncated(&[0x01u8], Err(NonZeroError::Decoder(UnexpectedEnd)))]
+    fn assert_that_nonzero_u16_can_be_decoded_in_little_endian(
+        #[case] input: &[u8],
+        #[case] expected: Result<u16, NonZeroError<UnexpectedEnd>>,
+    ) {
+        let mut decoder = input;
+        let result = LE::<NonZero<u16>>::decode(&mut decoder).map(|le| le.get());
+        assert_eq!(result, expected);
+    }
+
+    #[rstest]
+    #[case::fits(LE::new(5i64), Some(5usize))]
+    #[case::negative(LE::new(-1i64), None)]
+    fn assert_that_usize_try_from_le_works(#[case] le: LE<i64>, #[case] expected: Option<usize>) {
+        assert_eq!(usize::try_from(le).ok(), expected);
+    }
 }
@@ -0,0 +1,481 @@
+use core::borrow::Borrow;
+use core::num::NonZero;
+use core::ops::Deref;
+
+use super::PrefixWidth;
+use crate::ByteEncoder;
+use crate::Encodable;
+
+/// Encodes an unsigned number using the [LEB128](https://en.wikipedia.org/wiki/LEB128)
+/// variable-length encoding.
+///
+/// Each byte stores 7 bits of the number, starting from the least significant
+/// group. The most significant bit of every byte but the last is set to
+/// indicate that more bytes follow. Small numbers take fewer bytes, which is
+/// why this encoding is used by Protocol Buffers, WebAssembly and DWARF.
+///
+/// The encoded size of a number can be computed without encoding it using
+/// [`Uleb128::encoded_len`]. Because it implements [`TryFrom<usize>`], it can
+/// also be used as the length of a [`LengthPrefix`](super::LengthPrefix).
+///
+/// # Examples
+///
+/// ```rust
+/// # #[cfg(feature = "alloc")] {
+/// use encode::Encodable;
+/// use encode::combinators::Uleb128;
+///
+/// let mut buf = Vec::new();
+/// Uleb128::new(624_485u32).encode(&mut buf).unwrap();
+/// assert_eq!(&buf, &[0xE5, 0x8E, 0x26]);
+/// assert_eq!(Uleb128::new(624_485u32).encoded_len(), 3);
+/// # }
+/// ```
+///
+/// ```rust
+/// # #[cfg(feature = "alloc")] {
+/// use core::num::TryFromIntError;
+/// use encode::Encodable;
+/// use encode::combinators::{LengthPrefix, Uleb128};
+///
+/// let mut buf = Vec::new();
+/// LengthPrefix::<_, Uleb128<u32>, TryFromIntError>::new("hello").encode(&mut buf).unwrap();
+/// assert_eq!(&buf, b"\x05hello");
+/// # }
+/// ```
+#[doc(alias("varint", "leb128", "uleb128"))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+#[repr(transparent)]
+pub struct Uleb128<T> {
+    num: T,
+}
+
+/// Encodes a signed number using the signed [LEB128](https://en.wikipedia.org/wiki/LEB128)
+/// variable-length encoding.
+///
+/// Each byte stores 7 bits of the two's complement representation of the
+/// number, starting from the least significant group. Encoding stops once the
+/// remaining bits are all equal to the sign bit of the last group, so numbers
+/// close to zero take fewer bytes regardless of their sign.
+///
+/// The encoded size of a number can be computed without encoding it using
+/// [`Sleb128::encoded_len`].
+///
+/// # Examples
+///
+/// ```rust
+/// # #[cfg(feature = "alloc")] {
+/// use encode::Encodable;
+/// use encode::combinators::Sleb128;
+///
+/// let mut buf = Vec::new();
+/// Sleb128::new(-123_456i32).encode(&mut buf).unwrap();
+/// assert_eq!(&buf, &[0xC0, 0xBB, 0x78]);
+/// # }
+/// ```
+#[doc(alias("varint", "leb128", "sleb128"))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+#[repr(transparent)]
+pub struct Sleb128<T> {
+    num: T,
+}
+
+macro_rules! impl_common {
+    ($name:ident) => {
+        impl<T> $name<T> {
+            #[doc = concat!("Creates a new [`", stringify!($name), "`] combinator.")]
+            #[inline]
+            #[must_use]
+            pub const fn new(num: T) -> Self {
+                Self { num }
+            }
+            #[doc = concat!("Consumes the [`", stringify!($name), "`] combinator and returns the inner value.")]
+            #[inline]
+            #[must_use]
+            pub fn into_inner(self) -> T {
+                self.num
+            }
+        }
+
+        impl<T> AsRef<T> for $name<T> {
+            #[inline]
+            fn as_ref(&self) -> &T {
+                &self.num
+            }
+        }
+        impl<T> Borrow<T> for $name<T> {
+            #[inline]
+            fn borrow(&self) -> &T {
+                &self.num
+            }
+        }
+        impl<T> Deref for $name<T> {
+            type Target = T;
+
+            #[inline]
+            fn deref(&self) -> &Self::Target {
+                &self.num
+            }
+        }
+    };
+}
+
+impl_common!(Uleb128);
+impl_common!(Sleb128);
+
+/// Returns the number of LEB128 bytes needed to store `bits` significant bits.
+#[inline]
+const fn groups(bits: u32) -> usize {
+    if bits == 0 {
+        1
+    } else {
+        bits.div_ceil(7) as usize
+    }
+}
+
+macro_rules! impl_uleb128_for_num {
+    ($($T:ty)*) => {
+        $(
+            impl Uleb128<$T> {
+                /// The maximum number of bytes this type can be encoded into.
+                pub const MAX_LEN: usize = groups(<$T>::BITS);
+
+                /// Returns the number of bytes the number is encoded into.
+                #[inline]
+                #[must_use]
+                pub const fn encoded_len(&self) -> usize {
+                    groups(<$T>::BITS - self.num.leading_zeros())
+                }
+            }
+            impl From<$T> for Uleb128<$T> {
+                #[inline]
+                fn from(num: $T) -> Self {
+                    Self { num }
+                }
+            }
+            impl From<Uleb128<$T>> for $T {
+                #[inline]
+                fn from(uleb: Uleb128<$T>) -> Self {
+                    uleb.num
+                }
+            }
+            impl PrefixWidth for Uleb128<$T> {
+                const FIXED_WIDTH: Option<usize> = None;
+            }
+            impl TryFrom<usize> for Uleb128<$T> {
+                type Error = core::num::TryFromIntError;
+
+                #[inline]
+                fn try_from(value: usize) -> Result<Self, Self::Error> {
+                    <$T>::try_from(value).map(Self::new)
+                }
+            }
+            impl TryFrom<Uleb128<$T>> for usize {
+                type Error = core::num::TryFromIntError;
+
+                #[inline]
+                fn try_from(value: Uleb128<$T>) -> Result<Self, Self::Error> {
+                    usize::try_from(value.num).map_err(Into::into)
+                }
+            }
+            impl<E: ByteEncoder> Encodable<E> for Uleb128<$T> {
+                type Error = E::Error;
+
+                #[inline]
+                #[allow(clippy::cast_possible_truncation)]
+                fn encode(&self, encoder: &mut E) -> Result<(), Self::Error> {
+                    let mut buf = [0u8; Self::MAX_LEN];
+                    let len = self.encoded_len();
+                    for (i, byte) in buf[..len].iter_mut().enumerate() {
+                        *byte = (self.num >> (7 * i)) as u8 & 0x7F;
+                    }
+                    for byte in &mut buf[..len - 1] {
+                        *byte |= 0x80;
+                    }
+                    encoder.put_slice(&buf[..len])
+                }
+            }
+            impl Uleb128<NonZero<$T>> {
+                /// The maximum number of bytes this type can be encoded into.
+                pub const MAX_LEN: usize = Uleb128::<$T>::MAX_LEN;
+
+                /// Returns the number of bytes the number is encoded into.
+                #[inline]
+                #[must_use]
+                pub const fn encoded_len(&self) -> usize {
+                    Uleb128::new(self.num.get()).encoded_len()
+                }
+            }
+            impl From<NonZero<$T>> for Uleb128<NonZero<$T>> {
+                #[inline]
+                fn from(num: NonZero<$T>) -> Self {
+                    Self { num }
+                }
+            }
+            impl From<Uleb128<NonZero<$T>>> for NonZero<$T> {
+                #[inline]
+                fn from(uleb: Uleb128<NonZero<$T>>) -> Self {
+                    uleb.num
+                }
+            }
+            impl<E: ByteEncoder> Encodable<E> for Uleb128<NonZero<$T>> {
+                type Error = E::Error;
+
+                #[inline]
+                fn encode(&self, encoder: &mut E) -> Result<(), Self::Error> {
+                    Uleb128::new(self.num.get()).encode(encoder)
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! impl_sleb128_for_num {
+    ($($T:ty)*) => {
+        $(
+            impl Sleb128<$T> {
+                /// The maximum number of bytes this type can be encoded into.
+                pub const MAX_LEN: usize = groups(<$T>::BITS);
+
+                /// Returns the number of bytes the number is encoded into.
+                #[inline]
+                #[must_use]
+                pub const fn encoded_len(&self) -> usize {
+                    // Flipping negative numbers turns leading ones into leading
+                    // zeros, and one extra bit is needed for the sign.
+                    let magnitude = self.num ^ (self.num >> (<$T>::BITS - 1));
+                    groups(<$T>::BITS - magnitude.leading_zeros() + 1)
+                }
+            }
+            impl From<$T> for Sleb128<$T> {
+                #[inline]
+                fn from(num: $T) -> Self {
+                    Self { num }
+                }
+            }
+            impl From<Sleb128<$T>> for $T {
+                #[inline]
+                fn from(sleb: Sleb128<$T>) -> Self {
+                    sleb.num
+                }
+            }
+            impl PrefixWidth for Sleb128<$T> {
+                const FIXED_WIDTH: Option<usize> = None;
+            }
+            impl TryFrom<usize> for Sleb128<$T> {
+                type Error = core::num::TryFromIntError;
+
+                #[inline]
+                fn try_from(value: usize) -> Result<Self, Self::Error> {
+                    <$T>::try_from(value).map(Self::new)
+                }
+            }
+            impl TryFrom<Sleb128<$T>> for usize {
+                type Error = core::num::TryFromIntError;
+
+                #[inline]
+                fn try_from(value: Sleb128<$T>) -> Result<Self, Self::Error> {
+                    usize::try_from(value.num).map_err(Into::into)
+                }
+            }
+            impl<E: ByteEncoder> Encodable<E> for Sleb128<$T> {
+                type Error = E::Error;
+
+                #[inline]
+                #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+                fn encode(&self, encoder: &mut E) -> Result<(), Self::Error> {
+                    let mut buf = [0u8; Self::MAX_LEN];
+                    let len = self.encoded_len();
+                    for (i, byte) in buf[..len].iter_mut().enumerate() {
+                        *byte = (self.num >> (7 * i)) as u8 & 0x7F;
+                    }
+                    for byte in &mut buf[..len - 1] {
+                        *byte |= 0x80;
+                    }
+                    encoder.put_slice(&buf[..len])
+                }
+            }
+            impl Sleb128<NonZero<$T>> {
+                /// The maximum number of bytes this type can be encoded into.
+                pub const MAX_LEN: usize = Sleb128::<$T>::MAX_LEN;
+
+                /// Returns the number of bytes the number is encoded into.
+                #[inline]
+                #[must_use]
+                pub const fn encoded_len(&self) -> usize {
+                    Sleb128::new(self.num.get()).encoded_len()
+                }
+            }
+            impl From<NonZero<$T>> for Sleb128<NonZero<$T>> {
+                #[inline]
+                fn from(num: NonZero<$T>) -> Self {
+                    Self { num }
+                }
+            }
+            impl From<Sleb128<NonZero<$T>>> for NonZero<$T> {
+                #[inline]
+                fn from(sleb: Sleb128<NonZero<$T>>) -> Self {
+                    sleb.num
+                }
+            }
+            impl<E: ByteEncoder> Encodable<E> for Sleb128<NonZero<$T>> {
+                type Error = E::Error;
+
+                #[inline]
+                fn encode(&self, encoder: &mut E) -> Result<(), Self::Error> {
+                    Sleb128::new(self.num.get()).encode(encoder)
+                }
+            }
+        )*
+    };
+}
+
+impl_uleb128_for_num!(u8 u16 u32 u64 u128);
+impl_sleb128_for_num!(i8 i16 i32 i64 i128);
+
+#[cfg(test)]
+mod tests {
+    use core::borrow::Borrow;
+    use core::num::NonZero;
+
+    use rstest::rstest;
+
+    use super::*;
+    use crate::EncodableSize;
+
+    const BUF_SIZE: usize = 32;
+
+    #[rstest]
+    #[case::zero(0, &[0x00])]
+    #[case::one(1, &[0x01])]
+    #[case::largest_single_byte(127, &[0x7F])]
+    #[case::smallest_two_bytes(128, &[0x80, 0x01])]
+    #[case::wikipedia(624_485, &[0xE5, 0x8E, 0x26])]
+    #[case::max(u64::MAX, &[0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0x01])]
+    fn assert_that_unsigned_numbers_can_be_encoded(#[case] num: u64, #[case] expected: &[u8]) {
+        let encodable = Uleb128::new(num);
+
+        let mut buf = [0u8; BUF_SIZE];
+        let mut encoder = &mut buf as &mut [u8];
+        encodable.encode(&mut encoder).unwrap();
+        let written = BUF_SIZE - encoder.len();
+
+        assert_eq!(&buf[..written], expected);
+        assert_eq!(encodable.encoded_len(), expected.len());
+        assert_eq!(encodable.encoded_size(), Ok(expected.len()));
+    }
+
+    #[rstest]
+    #[case::zero(0, &[0x00])]
+    #[case::minus_one(-1, &[0x7F])]
+    #[case::largest_single_byte(63, &[0x3F])]
+    #[case::smallest_two_bytes(64, &[0xC0, 0x00])]
+    #[case::smallest_single_byte(-64, &[0x40])]
+    #[case::largest_negative_two_bytes(-65, &[0xBF, 0x7F])]
+    #[case::wikipedia(-123_456, &[0xC0, 0xBB, 0x78])]
+    #[case::min(i64::MIN, &[0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x7F])]
+    #[case::max(i64::MAX, &[0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0x00])]
+    fn assert_that_signed_numbers_can_be_encoded(#[case] num: i64, #[case] expected: &[u8]) {
+        let encodable = Sleb128::new(num);
+
+        let mut buf = [0u8; BUF_SIZE];
+        let mut encoder = &mut buf as &mut [u8];
+        encodable.encode(&mut encoder).unwrap();
+        let written = BUF_SIZE - encoder.len();
+
+        assert_eq!(&buf[..written], expected);
+        assert_eq!(encodable.encoded_len(), expected.len());
+    }
+
+    #[rstest]
+    #[case::u8(Uleb128::new(u8::MAX).encoded_len(), Uleb128::<u8>::MAX_LEN, 2)]
+    #[case::u16(Uleb128::new(u16::MAX).encoded_len(), Uleb128::<u16>::MAX_LEN, 3)]
+    #[case::u32(Uleb128::new(u32::MAX).encoded_len(), Uleb128::<u32>::MAX_LEN, 5)]
+    #[case::u128(Uleb128::new(u128::MAX).encoded_len(), Uleb128::<u128>::MAX_LEN, 19)]
+    #[case::i8(Sleb128::new(i8::MIN).encoded_len(), Sleb128::<i8>::MAX_LEN, 2)]
+    #[case::i16(Sleb128::new(i16::MIN).encoded_len(), Sleb128::<i16>::MAX_LEN, 3)]
+    #[case::i32(Sleb128::new(i32::MAX).encoded_len(), Sleb128::<i32>::MAX_LEN, 5)]
+    #[case::i128(Sleb128::new(i128::MIN).encoded_len(), Sleb128::<i128>::MAX_LEN, 19)]
+    fn assert_that_the_largest_numbers_use_the_maximum_length(
+        #[case] len: usize,
+        #[case] max_len: usize,
+        #[case] expected: usize,
+    ) {
+        assert_eq!(len, expected);
+        assert_eq!(max_len, expected);
+    }
+
+    #[test]
+    fn assert_that_u128_can_be_encoded() {
+        let mut expected = [0xFFu8; 19];
+        expected[18] = 0x03;
+
+        let mut buf = [0u8; BUF_SIZE];
+        let mut encoder = &mut buf as &mut [u8];
+        Uleb128::new(u128::MAX).encode(&mut encoder).unwrap();
+        let written = BUF_SIZE - encoder.len();
+
+        assert_eq!(&buf[..written], &expected);
+    }
+
+    #[test]
+    fn assert_that_nonzero_numbers_can_be_encoded() {
+        let mut buf = [0u8; BUF_SIZE];
+        let mut encoder = &mut buf as &mut [u8];
+        Uleb128::new(NonZero::new(300u16).unwrap())
+            .encode(&mut encoder)
+            .unwrap();
+        Sleb128::new(NonZero::new(-2i8).unwrap())
+            .encode(&mut encoder)
+            .unwrap();
+        let written = BUF_SIZE - encoder.len();
+
+        assert_eq!(&buf[..written], &[0xAC, 0x02, 0x7E]);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn assert_that_uleb128_can_be_used_as_a_length_prefix() {
+        use crate::combinators::LengthPrefix;
+
+        let encodable =
+            LengthPrefix::<_, Uleb128<u32>, core::num::TryFromIntError>::new([0xAAu8; 128]);
+
+        let mut buf = alloc::vec::Vec::new();
+        encodable.encode(&mut buf).unwrap();
+
+        assert_eq!(buf.len(), 130);
+        assert_eq!(&buf[..2], &[0x80, 0x01]);
+        assert_eq!(&buf[2..], &[0xAA; 128]);
+    }
+
+    #[rstest]
+    #[case::succeeds(200usize, Some(200u8))]
+    #[case::overflows(256usize, None)]
+    fn assert_that_uleb128_u8_try_from_usize(#[case] val: usize, #[case] expected: Option<u8>) {
+        assert_eq!(
+            Uleb128::<u8>::try_from(val).ok().map(Uleb128::into_inner),
+            expected
+        );
+    }
+
+    #[test]
+    fn assert_that_uleb128_accessors_work() {
+        let uleb = Uleb128::new(42u32);
+        let borrowed: &u32 = uleb.borrow();
+        assert_eq!(*borrowed, 42);
+        assert_eq!(uleb.as_ref(), &42);
+        assert_eq!(*uleb, 42);
+        assert_eq!(uleb.into_inner(), 42);
+    }
+
+    #[test]
+    fn assert_that_sleb128_accessors_work() {
+        let sleb: Sleb128<i32> = (-42).into();
+        let borrowed: &i32 = sleb.borrow();
+        assert_eq!(*borrowed, -42);
+        assert_eq!(sleb.as_ref(), &-42);
+        assert_eq!(*sleb, -42);
+        assert_eq!(i32::from(sleb), -42);
+    }
+}
@@ -3,8 +3,16 @@ use core::fmt::Debug;
 use core::marker::PhantomData;
 use core::ops::Deref;
 
+use crate::decoders::TrailingBytes;
+
 /// Encodes a length prefixed value ([TLV](https://en.wikipedia.org/wiki/Type–length–value)).
 ///
+/// Length prefixed values can also be decoded. The length is decoded first,
+/// and then the value is decoded from a sub-slice bounded by that length. If
+/// the input is shorter than the decoded length, the decoder error is
+/// returned. If the value does not consume all the bytes of the sub-slice,
+/// [`TrailingBytes`] is returned.
+///
 /// # Examples
 ///
 /// ```rust
@@ -19,6 +27,27 @@ use core::ops::Deref;
 /// assert_eq!(&buf, b"\x05hello", "Using a single byte to indicate the length of the string");
 /// # }
 /// ```
+///
+/// ```rust
+/// use encode::Decodable;
+/// use encode::combinators::{LengthPrefix, BE};
+/// use encode::decoders::{TrailingBytes, UnexpectedEnd};
+/// use core::num::TryFromIntError;
+///
+/// #[derive(Debug)]
+/// enum Error {
+///     Truncated,
+///     TrailingBytes,
+///     TooLarge,
+/// }
+/// # impl From<UnexpectedEnd> for Error { fn from(_: UnexpectedEnd) -> Self { Error::Truncated } }
+/// # impl From<TrailingBytes> for Error { fn from(_: TrailingBytes) -> Self { Error::TrailingBytes } }
+/// # impl From<TryFromIntError> for Error { fn from(_: TryFromIntError) -> Self { Error::TooLarge } }
+///
+/// let mut input: &[u8] = b"\x00\x05hello";
+/// let value = LengthPrefix::<&[u8], BE<u16>, Error>::decode(&mut input).unwrap();
+/// assert_eq!(value.into_inner(), b"hello");
+/// ```
 #[doc(alias("length", "prefix", "TLV"))]
 #[repr(transparent)]
 pub struct LengthPrefix<Encodable, Length, Error> {
@@ -98,6 +127,35 @@ where
     }
 }
 
+impl<'de, Decodable, Length, Decoder, Error> crate::Decodable<Decoder>
+    for LengthPrefix<Decodable, Length, Error>
+where
+    Decoder: crate::ByteDecoder<'de>,
+    Decodable: crate::Decodable<&'de [u8]>,
+    Length: crate::Decodable<Decoder>,
+    usize: TryFrom<Length>,
+    Error: From<<Length as crate::Decodable<Decoder>>::Error>
+        + From<<usize as TryFrom<Length>>::Error>
+        + From<<Decodable as crate::Decodable<&'de [u8]>>::Error>
+        + From<Decoder::Error>
+        + From<TrailingBytes>,
+{
+    type Error = Error;
+
+    #[inline]
+    fn decode(decoder: &mut Decoder) -> Result<Self, Self::Error> {
+        let len = usize::try_from(Length::decode(decoder)?)?;
+        let mut body = decoder.take_slice(len)?;
+        let decodable = Decodable::decode(&mut body)?;
+
+        if body.is_empty() {
+            Ok(Self::new(decodable))
+        } else {
+            Err(TrailingBytes.into())
+        }
+    }
+}
+
 // Manual trait implementations because the derive macro does not support
 // phantom data fields.
 impl<Encodable, Length, Error> Debug for LengthPrefix<Encodable, Length, Error>
@@ -172,8 +230,41 @@ mod tests {
     use rstest::rstest;
 
     use super::*;
+    use crate::decoders::UnexpectedEnd;
+    use crate::Decodable;
     use crate::Encodable;
 
+    #[derive(Debug, PartialEq)]
+    enum DecodeError {
+        Truncated,
+        TrailingBytes,
+        TooLarge,
+    }
+
+    impl From<UnexpectedEnd> for DecodeError {
+        fn from(_: UnexpectedEnd) -> Self {
+            DecodeError::Truncated
+        }
+    }
+
+    impl From<TrailingBytes> for DecodeError {
+        fn from(_: TrailingBytes) -> Self {
+            DecodeError::TrailingBytes
+        }
+    }
+
+    impl From<TryFromIntError> for DecodeError {
+        fn from(_: TryFromIntError) -> Self {
+            DecodeError::TooLarge
+        }
+    }
+
+    impl From<core::convert::Infallible> for DecodeError {
+        fn from(err: core::convert::Infallible) -> Self {
+            match err {}
+        }
+    }
+
     #[cfg(feature = "alloc")]
     #[test]
     fn assert_that_length_prefix_encodes_length_and_value() {
@@ -218,7 +309,7 @@ mod tests {
     #[test]
     fn assert_that_length_prefix_clone_works() {
         let lp = LengthPrefix::<u8, u8, TryFromIntError>::new(42u8);
-        let clone = lp.clone();
+        let clone = Clone::clone(&lp);
         assert_eq!(*lp, *clone);
     }
 
@@ -262,4 +353,41 @@ mod tests {
             "Expected 6 bytes: 1 for length prefix + 5 for \"hello\""
         );
     }
+
+    #[test]
+    fn assert_that_length_prefix_decodes_length_and_value() {
+        let mut decoder = b"\x05hello, world" as &[u8];
+        let lp = LengthPrefix::<&[u8], u8, DecodeError>::decode(&mut decoder).unwrap();
+        assert_eq!(lp.into_inner(), b"hello");
+        assert_eq!(decoder, b", world", "Only the prefixed value is consumed");
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn assert_that_length_prefix_roundtrips() {
+        use crate::combinators::BE;
+
+        let mut buf = alloc::vec::Vec::new();
+        LengthPrefix::<_, BE<u16>, TryFromIntError>::new((1u8, 2u8))
+            .encode(&mut buf)
+            .unwrap();
+
+        let mut decoder = buf.as_slice();
+        let lp = LengthPrefix::<(u8, u8), BE<u16>, DecodeError>::decode(&mut decoder).unwrap();
+        assert_eq!(lp.into_inner(), (1u8, 2u8));
+    }
+
+    #[rstest]
+    #[case::truncated_length(b"", DecodeError::Truncated)]
+    #[case::truncated_value(b"\x05hell", DecodeError::Truncated)]
+    #[case::value_too_short(b"\x01\x01\x02", DecodeError::Truncated)]
+    #[case::trailing_bytes(b"\x03\x01\x02\x03", DecodeError::TrailingBytes)]
+    fn assert_that_length_prefix_reports_malformed_input(
+        #[case] input: &[u8],
+        #[case] expected: DecodeError,
+    ) {
+        let mut decoder = input;
+        let result = LengthPrefix::<(u8, u8), u8, DecodeError>::decode(&mut decoder);
+        assert_eq!(result.unwrap_err(), expected);
+    }
 }
@@ -19,14 +19,14 @@
 //!
 //! | Type | Description |
 //! |------|-------------|
-//! | [`(...)`](tuple) | Encodes a tuple by encoding each element in order |
+//! | [`(...)`](prim@tuple) | Encodes a tuple by encoding each element in order |
 //! | [`i8`] and [`u8`] | Encodes a single byte |
 //! | [`char`] | Encodes a character as its UTF-8 byte representation |
 //! | [`str`] | Encodes a UTF-8 string slice |
 //! | [`CStr`](core::ffi::CStr) | Encodes a C string slice, including the null terminator (`\0`) |
 //! | [`[u8; N]`](array) | Encodes a byte array |
 //! | [`[u8]`](slice) | Encodes a byte slice |
-//! | [`[bool; 8]`](array) | Encodes a set of flags as a single byte |
+//! | [`[bool; N]`](array) | Encodes a set of 8, 16, 32 or 64 flags as a big-endian integer, first flag in the most significant bit |
 //! | [`Arguments`](core::fmt::Arguments) | Encodes formatted data from [`format_args`] with zero allocations |
 //!
 //! ## Composition and Flow Combinators
@@ -41,10 +41,35 @@
 //! | [`Cond`] | Encodes a value only if a condition is met |
 //! | [`LE`] | Encodes a number in little-endian order |
 //! | [`BE`] | Encodes a number in big-endian order |
+//! | [`Uleb128`] | Encodes an unsigned number as a variable-length [LEB128](https://en.wikipedia.org/wiki/LEB128) integer |
+//! | [`Sleb128`] | Encodes a signed number as a variable-length [LEB128](https://en.wikipedia.org/wiki/LEB128) integer |
+//! | [`CompactSize`] | Encodes a number as a Bitcoin `CompactSize` variable-length integer |
+//! | [`SqliteVarint`] | Encodes a number as an `SQLite` variable-length integer |
+//! | [`QuicVarInt`] | Encodes a number as a QUIC variable-length integer |
+//! | [`Bitfield`] | Packs a list of `(value, width)` fields into an integer, to be encoded with [`LE`] or [`BE`] |
+//! | [`Bits`] | Encodes the given number of least significant bits of a number into a [`BitLevelEncoder`](crate::BitLevelEncoder) |
+//! | [`ZigZag`] | Maps a signed number to an unsigned one, keeping small negative numbers small |
 //! | [`LengthPrefix`] | Encodes a length prefixed value ([TLV](https://en.wikipedia.org/wiki/Type–length–value)) |
+//! | [`PatchedLengthPrefix`] | Encodes a length prefixed value, patching fixed-width lengths in after the value in a single pass |
+//! | [`AdjustedLengthPrefix`] | Encodes a length prefixed value, where the length can include the prefix itself or a constant adjustment |
+//! | [`Checksummed`] | Encodes a value followed by a [checksum](crate::checksums) of its encoded bytes |
+//! | [`Hex`] | Encodes the bytes of a value as hexadecimal text into a [`StrEncoder`](crate::StrEncoder) |
+//! | [`Base32`] | Encodes the bytes of a value as Base32 text (RFC 4648 or Crockford) into a [`StrEncoder`](crate::StrEncoder) |
+//! | [`Base58`] | Encodes the bytes of a value as Base58 text into a [`StrEncoder`](crate::StrEncoder). `Base58Check` requires the `sha2` feature |
+//! | [`Base64`] | Encodes the bytes of a value as Base64 text into a [`StrEncoder`](crate::StrEncoder) |
+//! | [`PercentEncoded`] | Percent-encodes the bytes of a value into a [`StrEncoder`](crate::StrEncoder), for URLs and form bodies |
+//! | [`Cobs`] | Encodes a value as a [COBS](https://en.wikipedia.org/wiki/Consistent_Overhead_Byte_Stuffing) frame, followed by a `0x00` delimiter |
+//! | [`Slip`] | Encodes a value as a [SLIP](https://www.rfc-editor.org/rfc/rfc1055) frame, delimited by `0xC0` |
+//! | [`Hdlc`] | Encodes a value as an HDLC-like frame, delimited by `0x7E` |
+//! | [`Pad`] | Pads a value to a given size with a fill byte |
+//! | [`PadTo`] | Pads a value to a size known at compile time with a fill byte |
+//! | [`FixedWidth`] | Encodes a string or byte slice into a field of exactly `N` bytes, padding or truncating it |
+//! | [`Align`] | Pads the output until its position is a multiple of a given size |
 //! | [`Separated`] | Encodes a sequence of encodables separated by a given delimiter |
 //! | [`Iter`] | Encodes a sequence of encodables |
+//! | [`CountPrefix`] | Encodes a sequence of encodables, prefixed by the number of elements |
 //! | [`FromError`] | Transforms the error type of an encodable. |
+//! | [`Tuple`] | Encodes a tuple whose elements have different error types |
 //!
 #![cfg_attr(
     feature = "alloc",
@@ -63,7 +88,7 @@ These types are supported when the `alloc` or `std` feature is enabled.
 )]
 #[cfg_attr(
     feature = "arrayvec",
-    doc = r"## ArrayVec Encodables (requires the `arrayvec` feature)
+    doc = r"## `ArrayVec` Encodables (requires the `arrayvec` feature)
 These types are supported when the `arrayvec` feature is enabled.
 | Type | Description |
 |------|-------------|
@@ -83,18 +108,72 @@ These types are supported when the `bytes` feature is enabled.
 
 "
 )]
+mod adjusted_length_prefix;
+mod align;
+mod base32;
+mod base58;
+mod base64;
 mod be;
+mod bitfield;
+mod bits;
+mod checksummed;
+mod compact_size;
 mod cond;
+mod count_prefix;
+mod errors;
+mod fixed_width;
+mod framing;
 mod from_error;
+mod hex;
 mod iter;
 mod le;
+mod leb128;
 mod length_prefix;
+mod pad;
+mod patched_length_prefix;
+mod percent_encoded;
+mod quic;
 mod separated;
+mod sqlite_varint;
+mod tuple;
+mod zigzag;
 
+pub use adjusted_length_prefix::AdjustedLengthPrefix;
+pub use align::Align;
+pub use base32::Base32;
+pub use base58::Base58;
+pub use base64::Base64;
 pub use be::BE;
+pub use bitfield::Bitfield;
+pub use bits::Bits;
+pub use checksummed::Checksummed;
+pub use compact_size::CompactSize;
 pub use cond::Cond;
+pub use count_prefix::CountPrefix;
+pub use errors::FieldOverflow;
+pub use errors::LengthOutOfRange;
+pub use errors::QuicVarIntOutOfRange;
+pub use errors::WidthOverflow;
+pub use fixed_width::FixedWidth;
+pub use fixed_width::OverflowPolicy;
+pub use framing::Cobs;
+pub use framing::Hdlc;
+pub use framing::Slip;
 pub use from_error::FromError;
+pub use hex::Hex;
 pub use iter::Iter;
 pub use le::LE;
+pub use leb128::Sleb128;
+pub use leb128::Uleb128;
 pub use length_prefix::LengthPrefix;
+pub use pad::Pad;
+pub use pad::PadTo;
+pub use patched_length_prefix::PatchedLengthPrefix;
+pub use patched_length_prefix::PrefixWidth;
+pub use percent_encoded::PercentEncoded;
+pub use quic::QuicVarInt;
+pub use quic::QuicVarIntWidth;
 pub use separated::Separated;
+pub use sqlite_varint::SqliteVarint;
+pub use tuple::Tuple;
+pub use zigzag::ZigZag;
@@ -0,0 +1,461 @@
+use core::borrow::Borrow;
+use core::fmt::Debug;
+use core::marker::PhantomData;
+use core::ops::Deref;
+
+use super::FieldOverflow;
+use crate::encoders::SizeEncoder;
+use crate::ByteEncoder;
+use crate::EncodableSize;
+
+/// Writes `count` copies of `fill` into the encoder.
+#[inline]
+pub(super) fn put_fill<E: ByteEncoder>(
+    encoder: &mut E,
+    fill: u8,
+    mut count: usize,
+) -> Result<(), E::Error> {
+    let chunk = [fill; 64];
+    while count > 0 {
+        let len = count.min(chunk.len());
+        encoder.put_slice(&chunk[..len])?;
+        count -= len;
+    }
+    Ok(())
+}
+
+/// Encodes `encodable` followed by `fill` bytes until `len` bytes are written.
+#[inline]
+fn encode_padded<Encodable, Encoder, Error>(
+    encodable: &Encodable,
+    encoder: &mut Encoder,
+    len: usize,
+    fill: u8,
+) -> Result<(), Error>
+where
+    Encoder: ByteEncoder,
+    Encodable: crate::Encodable<Encoder> + EncodableSize,
+    Error: From<<Encodable as crate::Encodable<Encoder>>::Error>
+        + From<<Encodable as crate::Encodable<SizeEncoder>>::Error>
+        + From<Encoder::Error>
+        + From<FieldOverflow>,
+{
+    let padding = len
+        .checked_sub(encodable.encoded_size()?)
+        .ok_or(FieldOverflow)?;
+    encodable.encode(encoder)?;
+    put_fill(encoder, fill, padding)?;
+    Ok(())
+}
+
+/// Pads an encodable to a given size with a fill byte.
+///
+/// The encodable is written first, followed by as many fill bytes as needed
+/// to reach the size. If the encodable is larger than the size,
+/// [`FieldOverflow`] is returned before anything is written. The fill byte
+/// defaults to `0`.
+///
+/// See [`PadTo`] for sizes known at compile time.
+///
+/// # Examples
+///
+/// ```rust
+/// use encode::Encodable;
+/// use encode::combinators::{FieldOverflow, Pad};
+/// use encode::encoders::InsufficientSpace;
+///
+/// #[derive(Debug, PartialEq)]
+/// enum Error {
+///     InsufficientSpace,
+///     FieldOverflow,
+/// }
+/// # impl From<InsufficientSpace> for Error { fn from(_: InsufficientSpace) -> Self { Error::InsufficientSpace } }
+/// # impl From<FieldOverflow> for Error { fn from(_: FieldOverflow) -> Self { Error::FieldOverflow } }
+/// # impl From<core::convert::Infallible> for Error { fn from(err: core::convert::Infallible) -> Self { match err {} } }
+///
+/// let mut buf = [0u8; 6];
+/// let mut encoder = &mut buf as &mut [u8];
+/// Pad::<_, Error>::new("abc", 6).with_fill(b' ').encode(&mut encoder).unwrap();
+/// assert_eq!(&buf, b"abc   ");
+///
+/// let mut encoder = &mut buf as &mut [u8];
+/// let overflowing = Pad::<_, Error>::new("abcdefg", 6);
+/// assert_eq!(overflowing.encode(&mut encoder), Err(Error::FieldOverflow));
+/// ```
+#[doc(alias("padding", "fill"))]
+pub struct Pad<Encodable, Error> {
+    encodable: Encodable,
+    len: usize,
+    fill: u8,
+    phantom: PhantomData<Error>,
+}
+
+impl<Encodable, Error> Pad<Encodable, Error> {
+    /// Creates a new [`Pad`] combinator that pads to `len` bytes.
+    #[inline]
+    #[must_use]
+    pub const fn new(encodable: Encodable, len: usize) -> Self {
+        Self {
+            encodable,
+            len,
+            fill: 0,
+            phantom: PhantomData,
+        }
+    }
+    /// Sets the byte used for padding.
+    #[inline]
+    #[must_use]
+    pub const fn with_fill(mut self, fill: u8) -> Self {
+        self.fill = fill;
+        self
+    }
+    /// Consumes the combinator and returns the inner encodable.
+    #[inline]
+    #[must_use]
+    pub fn into_inner(self) -> Encodable {
+        self.encodable
+    }
+}
+
+impl<Encodable, Error> AsRef<Encodable> for Pad<Encodable, Error> {
+    #[inline]
+    fn as_ref(&self) -> &Encodable {
+        &self.encodable
+    }
+}
+
+impl<Encodable, Error> Deref for Pad<Encodable, Error> {
+    type Target = Encodable;
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        self.as_ref()
+    }
+}
+
+impl<Encodable, Error> Borrow<Encodable> for Pad<Encodable, Error> {
+    #[inline]
+    fn borrow(&self) -> &Encodable {
+        &self.encodable
+    }
+}
+
+impl<Encodable, Encoder, Error> crate::Encodable<Encoder> for Pad<Encodable, Error>
+where
+    Encoder: ByteEncoder,
+    Encodable: crate::Encodable<Encoder> + EncodableSize,
+    Error: From<<Encodable as crate::Encodable<Encoder>>::Error>
+        + From<<Encodable as crate::Encodable<SizeEncoder>>::Error>
+        + From<Encoder::Error>
+        + From<FieldOverflow>,
+{
+    type Error = Error;
+
+    #[inline]
+    fn encode(&self, encoder: &mut Encoder) -> Result<(), Self::Error> {
+        encode_padded(&self.encodable, encoder, self.len, self.fill)
+    }
+}
+
+/// Pads an encodable to `N` bytes with a fill byte.
+///
+/// Works like [`Pad`], but the size is known at compile time, which makes it
+/// a good fit for fixed-width fields such as the ones found in C structs.
+///
+/// # Examples
+///
+/// ```rust
+/// use encode::Encodable;
+/// use encode::combinators::{FieldOverflow, PadTo};
+/// use encode::encoders::InsufficientSpace;
+///
+/// #[derive(Debug, PartialEq)]
+/// enum Error {
+///     InsufficientSpace,
+///     FieldOverflow,
+/// }
+/// # impl From<InsufficientSpace> for Error { fn from(_: InsufficientSpace) -> Self { Error::InsufficientSpace } }
+/// # impl From<FieldOverflow> for Error { fn from(_: FieldOverflow) -> Self { Error::FieldOverflow } }
+/// # impl From<core::convert::Infallible> for Error { fn from(err: core::convert::Infallible) -> Self { match err {} } }
+///
+/// let mut buf = [0xFFu8; 8];
+/// let mut encoder = &mut buf as &mut [u8];
+/// PadTo::<_, 8, Error>::new("name").encode(&mut encoder).unwrap();
+/// assert_eq!(&buf, b"name\0\0\0\0");
+/// ```
+#[doc(alias("padding", "fill"))]
+pub struct PadTo<Encodable, const N: usize, Error> {
+    encodable: Encodable,
+    fill: u8,
+    phantom: PhantomData<Error>,
+}
+
+impl<Encodable, const N: usize, Error> PadTo<Encodable, N, Error> {
+    /// Creates a new [`PadTo`] combinator.
+    #[inline]
+    #[must_use]
+    pub const fn new(encodable: Encodable) -> Self {
+        Self {
+            encodable,
+            fill: 0,
+            phantom: PhantomData,
+        }
+    }
+    /// Sets the byte used for padding.
+    #[inline]
+    #[must_use]
+    pub const fn with_fill(mut self, fill: u8) -> Self {
+        self.fill = fill;
+        self
+    }
+    /// Consumes the combinator and returns the inner encodable.
+    #[inline]
+    #[must_use]
+    pub fn into_inner(self) -> Encodable {
+        self.encodable
+    }
+}
+
+impl<Encodable, const N: usize, Error> AsRef<Encodable> for PadTo<Encodable, N, Error> {
+    #[inline]
+    fn as_ref(&self) -> &Encodable {
+        &self.encodable
+    }
+}
+
+impl<Encodable, const N: usize, Error> Deref for PadTo<Encodable, N, Error> {
+    type Target = Encodable;
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        self.as_ref()
+    }
+}
+
+impl<Encodable, const N: usize, Error> Borrow<Encodable> for PadTo<Encodable, N, Error> {
+    #[inline]
+    fn borrow(&self) -> &Encodable {
+        &self.encodable
+    }
+}
+
+impl<Encodable, Encoder, const N: usize, Error> crate::Encodable<Encoder>
+    for PadTo<Encodable, N, Error>
+where
+    Encoder: ByteEncoder,
+    Encodable: crate::Encodable<Encoder> + EncodableSize,
+    Error: From<<Encodable as crate::Encodable<Encoder>>::Error>
+        + From<<Encodable as crate::Encodable<SizeEncoder>>::Error>
+        + From<Encoder::Error>
+        + From<FieldOverflow>,
+{
+    type Error = Error;
+
+    #[inline]
+    fn encode(&self, encoder: &mut Encoder) -> Result<(), Self::Error> {
+        encode_padded(&self.encodable, encoder, N, self.fill)
+    }
+}
+
+// Manual trait implementations because the derive macro does not support
+// phantom data fields.
+impl<Encodable, Error> Debug for Pad<Encodable, Error>
+where
+    Encodable: Debug,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Pad")
+            .field("encodable", &self.encodable)
+            .field("len", &self.len)
+            .field("fill", &self.fill)
+            .finish()
+    }
+}
+impl<Encodable, Error> Clone for Pad<Encodable, Error>
+where
+    Encodable: Clone,
+{
+    #[inline]
+    fn clone(&self) -> Self {
+        Self::new(self.encodable.clone(), self.len).with_fill(self.fill)
+    }
+}
+impl<Encodable, Error> Copy for Pad<Encodable, Error> where Encodable: Copy {}
+impl<Encodable, Error> PartialEq for Pad<Encodable, Error>
+where
+    Encodable: PartialEq,
+{
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.encodable == other.encodable && self.len == other.len && self.fill == other.fill
+    }
+}
+impl<Encodable, Error> Eq for Pad<Encodable, Error> where Encodable: Eq {}
+
+impl<Encodable, const N: usize, Error> Debug for PadTo<Encodable, N, Error>
+where
+    Encodable: Debug,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("PadTo")
+            .field("encodable", &self.encodable)
+            .field("fill", &self.fill)
+            .finish()
+    }
+}
+impl<Encodable, const N: usize, Error> Clone for PadTo<Encodable, N, Error>
+where
+    Encodable: Clone,
+{
+    #[inline]
+    fn clone(&self) -> Self {
+        Self::new(self.encodable.clone()).with_fill(self.fill)
+    }
+}
+impl<Encodable, const N: usize, Error> Copy for PadTo<Encodable, N, Error> where Encodable: Copy {}
+impl<Encodable, const N: usize, Error> Default for PadTo<Encodable, N, Error>
+where
+    Encodable: Default,
+{
+    #[inline]
+    fn default() -> Self {
+        Self::new(Default::default())
+    }
+}
+impl<Encodable, const N: usize, Error> PartialEq for PadTo<Encodable, N, Error>
+where
+    Encodable: PartialEq,
+{
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.encodable == other.encodable && self.fill == other.fill
+    }
+}
+impl<Encodable, const N: usize, Error> Eq for PadTo<Encodable, N, Error> where Encodable: Eq {}
+
+#[cfg(test)]
+mod tests {
+    use core::borrow::Borrow;
+
+    use rstest::rstest;
+
+    use super::*;
+    use crate::encoders::InsufficientSpace;
+    use crate::Encodable;
+
+    const BUF_SIZE: usize = 256;
+
+    #[derive(Debug, PartialEq)]
+    enum Error {
+        InsufficientSpace,
+        FieldOverflow,
+    }
+
+    impl From<InsufficientSpace> for Error {
+        fn from(_: InsufficientSpace) -> Self {
+            Error::InsufficientSpace
+        }
+    }
+
+    impl From<FieldOverflow> for Error {
+        fn from(_: FieldOverflow) -> Self {
+            Error::FieldOverflow
+        }
+    }
+
+    impl From<core::convert::Infallible> for Error {
+        fn from(err: core::convert::Infallible) -> Self {
+            match err {}
+        }
+    }
+
+    #[rstest]
+    #[case::padded(b"abc", 5, b"abc\0\0")]
+    #[case::exact(b"abc", 3, b"abc")]
+    #[case::empty(b"", 2, b"\0\0")]
+    fn assert_that_values_are_padded(
+        #[case] s: &[u8],
+        #[case] len: usize,
+        #[case] expected: &[u8],
+    ) {
+        let mut buf = [0xAAu8; BUF_SIZE];
+        let mut encoder = &mut buf as &mut [u8];
+        Pad::<_, Error>::new(s, len).encode(&mut encoder).unwrap();
+        let written = BUF_SIZE - encoder.len();
+
+        assert_eq!(&buf[..written], expected);
+    }
+
+    #[test]
+    fn assert_that_long_padding_uses_the_fill_byte() {
+        let mut buf = [0u8; BUF_SIZE];
+        let mut encoder = &mut buf as &mut [u8];
+        Pad::<_, Error>::new(b'x', 200)
+            .with_fill(b' ')
+            .encode(&mut encoder)
+            .unwrap();
+        let written = BUF_SIZE - encoder.len();
+
+        assert_eq!(written, 200);
+        assert_eq!(buf[0], b'x');
+        assert!(buf[1..written].iter().all(|&byte| byte == b' '));
+    }
+
+    #[test]
+    fn assert_that_overflowing_values_write_nothing() {
+        let mut buf = [0u8; BUF_SIZE];
+        let mut encoder = &mut buf as &mut [u8];
+
+        assert_eq!(
+            Pad::<_, Error>::new("abc", 2).encode(&mut encoder),
+            Err(Error::FieldOverflow)
+        );
+        assert_eq!(
+            PadTo::<_, 2, Error>::new("abc").encode(&mut encoder),
+            Err(Error::FieldOverflow)
+        );
+        assert_eq!(encoder.len(), BUF_SIZE);
+    }
+
+    #[test]
+    fn assert_that_encoder_errors_are_propagated() {
+        let mut buf = [0u8; 4];
+        let mut encoder = &mut buf as &mut [u8];
+
+        assert_eq!(
+            PadTo::<_, 8, Error>::new("abc").encode(&mut encoder),
+            Err(Error::InsufficientSpace)
+        );
+    }
+
+    #[test]
+    fn assert_that_fixed_width_fields_are_padded() {
+        let mut buf = [0u8; BUF_SIZE];
+        let mut encoder = &mut buf as &mut [u8];
+        (
+            PadTo::<_, 4, Error>::new("ab").with_fill(b' '),
+            PadTo::<_, 4, Error>::new("cd"),
+        )
+            .encode(&mut encoder)
+            .unwrap();
+        let written = BUF_SIZE - encoder.len();
+
+        assert_eq!(&buf[..written], b"ab  cd\0\0");
+        assert_eq!(PadTo::<_, 4, Error>::new("ab").encoded_size(), Ok(4));
+    }
+
+    #[test]
+    fn assert_that_pad_accessors_work() {
+        let pad = Pad::<u8, Error>::new(42u8, 2);
+        let borrowed: &u8 = pad.borrow();
+        assert_eq!(*borrowed, 42u8);
+        assert_eq!(pad.as_ref(), &42u8);
+        assert_eq!(*pad, 42u8);
+        assert_eq!(pad, Clone::clone(&pad));
+        assert_ne!(pad, pad.with_fill(1));
+        assert_eq!(pad.into_inner(), 42u8);
+
+        let pad_to = PadTo::<u8, 2, Error>::default();
+        assert_eq!(*pad_to, 0u8);
+        assert_eq!(pad_to, Clone::clone(&pad_to));
+        assert_eq!(pad_to.into_inner(), 0u8);
+    }
+}
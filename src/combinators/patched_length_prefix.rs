@@ -0,0 +1,494 @@
+use core::borrow::Borrow;
+use core::fmt::Debug;
+use core::marker::PhantomData;
+use core::ops::Deref;
+
+use crate::encoders::InsufficientSpace;
+use crate::encoders::PatchError;
+use crate::encoders::SizeEncoder;
+use crate::EncodableSize;
+use crate::PatchableEncoder;
+
+/// The maximum number of bytes a length prefix can be encoded into.
+const MAX_PREFIX_LEN: usize = 32;
+
+/// Encodes a length prefixed value in a single pass.
+///
+/// Works like [`LengthPrefix`](super::LengthPrefix), but instead of computing
+/// the size of the value before encoding it, space for the length is reserved
+/// and filled in once the value has been encoded. This avoids encoding the
+/// value twice, which matters for deeply nested length prefixed values, but
+/// requires a [`PatchableEncoder`].
+///
+/// Only fixed-width lengths, such as [`u8`] or [`BE<u32>`](super::BE), can be
+/// patched in, because the space for the length has to be reserved before the
+/// length is known. Length types report their width through [`PrefixWidth`].
+/// Variable-width lengths, such as [`Uleb128`](super::Uleb128) or
+/// [`QuicVarInt`](super::QuicVarInt), fall back to computing the size first,
+/// exactly like [`LengthPrefix`](super::LengthPrefix) does.
+///
+/// If the encoder cannot patch the length in, for example because a custom
+/// length type encodes into a different number of bytes than its
+/// [`PrefixWidth`], [`PatchError::SizeMismatch`] is returned.
+///
+/// # Examples
+///
+/// ```rust
+/// # #[cfg(feature = "alloc")] {
+/// use encode::Encodable;
+/// use encode::combinators::{PatchedLengthPrefix, BE};
+/// use encode::encoders::{InsufficientSpace, PatchError};
+/// use core::num::TryFromIntError;
+///
+/// #[derive(Debug)]
+/// enum Error {
+///     InsufficientSpace,
+///     TooLarge,
+/// }
+/// # impl From<InsufficientSpace> for Error { fn from(_: InsufficientSpace) -> Self { Error::InsufficientSpace } }
+/// # impl<E: Into<Error>> From<PatchError<E>> for Error { fn from(e: PatchError<E>) -> Self { match e { PatchError::Encoder(e) => e.into(), PatchError::SizeMismatch => Error::InsufficientSpace } } }
+/// # impl From<TryFromIntError> for Error { fn from(_: TryFromIntError) -> Self { Error::TooLarge } }
+/// # impl From<core::convert::Infallible> for Error { fn from(e: core::convert::Infallible) -> Self { match e {} } }
+///
+/// let mut buf = Vec::new();
+/// let inner = PatchedLengthPrefix::<_, u8, Error>::new("hello");
+/// PatchedLengthPrefix::<_, BE<u16>, Error>::new(inner).encode(&mut buf).unwrap();
+/// assert_eq!(&buf, b"\x00\x06\x05hello");
+/// # }
+/// ```
+#[doc(alias("length", "prefix", "TLV", "backpatch"))]
+#[repr(transparent)]
+pub struct PatchedLengthPrefix<Encodable, Length, Error> {
+    encodable: Encodable,
+    phantom: PhantomData<(Length, Error)>,
+}
+
+impl<Encodable, Length, Error> PatchedLengthPrefix<Encodable, Length, Error> {
+    /// Creates a new [`PatchedLengthPrefix`] combinator.
+    #[inline]
+    #[must_use]
+    pub const fn new(encodable: Encodable) -> Self {
+        Self {
+            encodable,
+            phantom: PhantomData,
+        }
+    }
+    /// Consumes the combinator and returns the inner encodable.
+    #[inline]
+    #[must_use]
+    pub fn into_inner(self) -> Encodable {
+        self.encodable
+    }
+}
+
+impl<Encodable, Length, Error> From<Encodable> for PatchedLengthPrefix<Encodable, Length, Error> {
+    #[inline]
+    fn from(value: Encodable) -> Self {
+        Self::new(value)
+    }
+}
+
+impl<Encodable, Length, Error> AsRef<Encodable> for PatchedLengthPrefix<Encodable, Length, Error> {
+    #[inline]
+    fn as_ref(&self) -> &Encodable {
+        &self.encodable
+    }
+}
+
+impl<Encodable, Length, Error> Deref for PatchedLengthPrefix<Encodable, Length, Error> {
+    type Target = Encodable;
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        self.as_ref()
+    }
+}
+
+impl<Encodable, Length, Error> Borrow<Encodable> for PatchedLengthPrefix<Encodable, Length, Error> {
+    #[inline]
+    fn borrow(&self) -> &Encodable {
+        &self.encodable
+    }
+}
+
+/// A length type that knows whether its encoded width depends on its value.
+///
+/// [`PatchedLengthPrefix`] reserves space for the length before the value is
+/// encoded, which is only possible when the width of the length is known up
+/// front. Lengths with a variable width, such as [`Uleb128`](super::Uleb128),
+/// are written after computing the size of the value instead.
+pub trait PrefixWidth {
+    /// The number of bytes every length is encoded into, or [`None`] if the
+    /// width depends on the length.
+    const FIXED_WIDTH: Option<usize>;
+}
+
+impl PrefixWidth for u8 {
+    const FIXED_WIDTH: Option<usize> = Some(1);
+}
+
+impl PrefixWidth for i8 {
+    const FIXED_WIDTH: Option<usize> = Some(1);
+}
+
+/// Encodes a length into `buf`, returning the number of bytes written.
+#[inline]
+fn encode_length<Length>(length: &Length, buf: &mut [u8]) -> Result<usize, InsufficientSpace>
+where
+    for<'a> Length: crate::Encodable<&'a mut [u8], Error = InsufficientSpace>,
+{
+    let size = buf.len();
+    let mut encoder = buf;
+    length.encode(&mut encoder)?;
+    Ok(size - encoder.len())
+}
+
+impl<Encodable, Length, Encoder, Error> crate::Encodable<Encoder>
+    for PatchedLengthPrefix<Encodable, Length, Error>
+where
+    Encoder: PatchableEncoder,
+    Encodable: crate::Encodable<Encoder> + EncodableSize,
+    Length: TryFrom<usize> + PrefixWidth,
+    for<'a> Length: crate::Encodable<&'a mut [u8], Error = InsufficientSpace>,
+    Error: From<<Length as TryFrom<usize>>::Error>
+        + From<<Encodable as crate::Encodable<Encoder>>::Error>
+        + From<<Encodable as crate::Encodable<SizeEncoder>>::Error>
+        + From<InsufficientSpace>
+        + From<PatchError<Encoder::Error>>
+        + From<Encoder::Error>,
+{
+    type Error = Error;
+
+    #[inline]
+    fn encode(&self, encoder: &mut Encoder) -> Result<(), Self::Error> {
+        let mut buf = [0u8; MAX_PREFIX_LEN];
+        let Some(width) = Length::FIXED_WIDTH else {
+            // The width of the length depends on its value, which is only
+            // known once the value has been sized.
+            let len = self.encodable.encoded_size()?;
+            let written = encode_length(&Length::try_from(len)?, &mut buf)?;
+            encoder.put_slice(&buf[..written])?;
+            self.encodable.encode(encoder)?;
+            return Ok(());
+        };
+
+        let placeholder = encoder.reserve_placeholder(width)?;
+        self.encodable.encode(encoder)?;
+        let len = encoder.written_since(&placeholder);
+
+        let written = encode_length(&Length::try_from(len)?, &mut buf)?;
+        encoder.patch(placeholder, &buf[..written])?;
+        Ok(())
+    }
+}
+
+// Manual trait implementations because the derive macro does not support
+// phantom data fields.
+impl<Encodable, Length, Error> Debug for PatchedLengthPrefix<Encodable, Length, Error>
+where
+    Encodable: Debug,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("PatchedLengthPrefix")
+            .field("encodable", &self.encodable)
+            .finish()
+    }
+}
+impl<Encodable, Length, Error> Clone for PatchedLengthPrefix<Encodable, Length, Error>
+where
+    Encodable: Clone,
+{
+    #[inline]
+    fn clone(&self) -> Self {
+        Self::new(self.encodable.clone())
+    }
+}
+impl<Encodable, Length, Error> Copy for PatchedLengthPrefix<Encodable, Length, Error> where
+    Encodable: Copy
+{
+}
+impl<Encodable, Length, Error> Default for PatchedLengthPrefix<Encodable, Length, Error>
+where
+    Encodable: Default,
+{
+    #[inline]
+    fn default() -> Self {
+        Self::new(Default::default())
+    }
+}
+impl<Encodable, Length, Error> PartialEq for PatchedLengthPrefix<Encodable, Length, Error>
+where
+    Encodable: PartialEq,
+{
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.encodable == other.encodable
+    }
+}
+impl<Encodable, Length, Error> Eq for PatchedLengthPrefix<Encodable, Length, Error> where
+    Encodable: Eq
+{
+}
+impl<Encodable, Length, Error> PartialOrd for PatchedLengthPrefix<Encodable, Length, Error>
+where
+    Encodable: PartialOrd,
+{
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+        self.encodable.partial_cmp(&other.encodable)
+    }
+}
+impl<Encodable, Length, Error> Ord for PatchedLengthPrefix<Encodable, Length, Error>
+where
+    Encodable: Ord,
+{
+    #[inline]
+    fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+        self.encodable.cmp(&other.encodable)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::borrow::Borrow;
+    use core::num::TryFromIntError;
+
+    use rstest::rstest;
+
+    use super::*;
+    use crate::combinators::LengthPrefix;
+    use crate::combinators::QuicVarInt;
+    use crate::combinators::QuicVarIntOutOfRange;
+    use crate::combinators::Tuple;
+    use crate::combinators::Uleb128;
+    use crate::combinators::BE;
+    use crate::combinators::LE;
+    use crate::Encodable;
+
+    const BUF_SIZE: usize = 512;
+
+    #[derive(Debug, PartialEq)]
+    enum Error {
+        InsufficientSpace,
+        TooLarge,
+        SizeMismatch,
+    }
+
+    impl<E: Into<Error>> From<PatchError<E>> for Error {
+        fn from(err: PatchError<E>) -> Self {
+            match err {
+                PatchError::Encoder(err) => err.into(),
+                PatchError::SizeMismatch => Error::SizeMismatch,
+            }
+        }
+    }
+
+    impl From<QuicVarIntOutOfRange> for Error {
+        fn from(_: QuicVarIntOutOfRange) -> Self {
+            Error::TooLarge
+        }
+    }
+
+    impl From<InsufficientSpace> for Error {
+        fn from(_: InsufficientSpace) -> Self {
+            Error::InsufficientSpace
+        }
+    }
+
+    impl From<TryFromIntError> for Error {
+        fn from(_: TryFromIntError) -> Self {
+            Error::TooLarge
+        }
+    }
+
+    impl From<core::convert::Infallible> for Error {
+        fn from(err: core::convert::Infallible) -> Self {
+            match err {}
+        }
+    }
+
+    #[rstest]
+    #[case::empty(b"", b"\x00")]
+    #[case::hello(b"hello", b"\x05hello")]
+    fn assert_that_the_length_is_patched_in(#[case] value: &[u8], #[case] expected: &[u8]) {
+        let encodable = PatchedLengthPrefix::<_, u8, Error>::new(value);
+
+        let mut buf = [0u8; BUF_SIZE];
+        let mut encoder = &mut buf as &mut [u8];
+        encodable.encode(&mut encoder).unwrap();
+        let written = BUF_SIZE - encoder.len();
+
+        assert_eq!(&buf[..written], expected);
+    }
+
+    #[test]
+    fn assert_that_patched_length_prefixes_match_length_prefixes() {
+        let inner = Tuple::<_, Error>::new((
+            LE::new(1u32),
+            PatchedLengthPrefix::<_, BE<u16>, Error>::new("nested"),
+        ));
+        let patched = PatchedLengthPrefix::<_, LE<u32>, Error>::new(inner);
+        let inner = Tuple::<_, Error>::new((
+            LE::new(1u32),
+            LengthPrefix::<_, BE<u16>, Error>::new("nested"),
+        ));
+        let unpatched = LengthPrefix::<_, LE<u32>, Error>::new(inner);
+
+        let mut buf = [0u8; BUF_SIZE];
+        let mut encoder = &mut buf as &mut [u8];
+        patched.encode(&mut encoder).unwrap();
+        let written = BUF_SIZE - encoder.len();
+
+        let mut expected = [0u8; BUF_SIZE];
+        let mut encoder = &mut expected as &mut [u8];
+        unpatched.encode(&mut encoder).unwrap();
+
+        assert_eq!(&buf[..written], &expected[..written]);
+        assert_eq!(
+            &buf[..written],
+            b"\x0C\x00\x00\x00\x01\x00\x00\x00\x00\x06nested"
+        );
+    }
+
+    #[test]
+    fn assert_that_the_size_of_patched_length_prefixes_can_be_computed() {
+        let encodable = PatchedLengthPrefix::<_, LE<u16>, Error>::new("hello");
+
+        let mut encoder = SizeEncoder::new();
+        encodable.encode(&mut encoder).unwrap();
+
+        assert_eq!(encoder.size(), 7);
+    }
+
+    #[test]
+    fn assert_that_variable_width_prefixes_fall_back_to_sizing_the_value() {
+        let body = [0xAAu8; 128];
+        let leb128 = PatchedLengthPrefix::<_, Uleb128<u32>, Error>::new(&body[..]);
+        let quic = PatchedLengthPrefix::<_, QuicVarInt, Error>::new(&body[..64]);
+
+        let mut buf = [0u8; BUF_SIZE];
+        let mut encoder = &mut buf as &mut [u8];
+        leb128.encode(&mut encoder).unwrap();
+        quic.encode(&mut encoder).unwrap();
+        let written = BUF_SIZE - encoder.len();
+
+        assert_eq!(written, 2 + 128 + 2 + 64);
+        assert_eq!(&buf[..2], b"\x80\x01");
+        assert_eq!(&buf[130..132], b"\x40\x40");
+    }
+
+    #[test]
+    fn assert_that_nested_variable_width_prefixes_match_length_prefixes() {
+        let body = [0xAAu8; 200];
+        let patched = PatchedLengthPrefix::<_, Uleb128<u32>, Error>::new(PatchedLengthPrefix::<
+            _,
+            LE<u16>,
+            Error,
+        >::new(&body[..]));
+        let unpatched =
+            LengthPrefix::<_, Uleb128<u32>, Error>::new(LengthPrefix::<_, LE<u16>, Error>::new(
+                &body[..],
+            ));
+
+        let mut buf = [0u8; BUF_SIZE];
+        let mut encoder = &mut buf as &mut [u8];
+        patched.encode(&mut encoder).unwrap();
+        let written = BUF_SIZE - encoder.len();
+
+        let mut expected = [0u8; BUF_SIZE];
+        let mut encoder = &mut expected as &mut [u8];
+        unpatched.encode(&mut encoder).unwrap();
+
+        assert_eq!(written, 2 + 2 + 200);
+        assert_eq!(&buf[..written], &expected[..written]);
+    }
+
+    #[test]
+    fn assert_that_length_overflows_are_reported() {
+        let encodable = PatchedLengthPrefix::<_, u8, Error>::new([0u8; 256]);
+
+        let mut buf = [0u8; BUF_SIZE];
+        let mut encoder = &mut buf as &mut [u8];
+
+        assert_eq!(encodable.encode(&mut encoder), Err(Error::TooLarge));
+    }
+
+    #[test]
+    fn assert_that_encoder_errors_are_propagated() {
+        let encodable = PatchedLengthPrefix::<_, LE<u16>, Error>::new("hello");
+
+        let mut buf = [0u8; 4];
+        let mut encoder = &mut buf as &mut [u8];
+
+        assert_eq!(
+            encodable.encode(&mut encoder),
+            Err(Error::InsufficientSpace)
+        );
+    }
+
+    #[test]
+    fn assert_that_misreported_widths_are_rejected() {
+        struct Misreported(u8);
+
+        impl TryFrom<usize> for Misreported {
+            type Error = TryFromIntError;
+
+            fn try_from(value: usize) -> Result<Self, Self::Error> {
+                u8::try_from(value).map(Self)
+            }
+        }
+
+        impl PrefixWidth for Misreported {
+            const FIXED_WIDTH: Option<usize> = Some(2);
+        }
+
+        impl<E: crate::ByteEncoder> Encodable<E> for Misreported {
+            type Error = E::Error;
+
+            fn encode(&self, encoder: &mut E) -> Result<(), Self::Error> {
+                encoder.put_byte(self.0)
+            }
+        }
+
+        let encodable = PatchedLengthPrefix::<_, Misreported, Error>::new("hello");
+
+        let mut buf = [0u8; BUF_SIZE];
+        let mut encoder = &mut buf as &mut [u8];
+
+        assert_eq!(encodable.encode(&mut encoder), Err(Error::SizeMismatch));
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn assert_that_patched_length_prefixes_can_be_encoded_into_a_vec() {
+        let encodable = PatchedLengthPrefix::<_, BE<u32>, Error>::new("hello");
+
+        let mut buf = alloc::vec::Vec::new();
+        encodable.encode(&mut buf).unwrap();
+
+        assert_eq!(buf, b"\x00\x00\x00\x05hello");
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn assert_that_variable_width_prefixes_can_be_encoded_into_a_vec() {
+        let body = [0xAAu8; 128];
+        let encodable = PatchedLengthPrefix::<_, Uleb128<u32>, Error>::new(&body[..]);
+
+        let mut buf = alloc::vec::Vec::new();
+        encodable.encode(&mut buf).unwrap();
+
+        assert_eq!(buf.len(), 130);
+        assert_eq!(&buf[..3], b"\x80\x01\xAA");
+    }
+
+    #[test]
+    fn assert_that_patched_length_prefix_accessors_work() {
+        let lp = PatchedLengthPrefix::<u8, u8, Error>::new(42u8);
+        let borrowed: &u8 = lp.borrow();
+        assert_eq!(*borrowed, 42u8);
+        assert_eq!(lp.as_ref(), &42u8);
+        assert_eq!(*lp, 42u8);
+        assert_eq!(lp, Clone::clone(&lp));
+        assert_eq!(lp.into_inner(), 42u8);
+    }
+}
@@ -0,0 +1,258 @@
+use core::borrow::Borrow;
+use core::fmt::Debug;
+use core::marker::PhantomData;
+use core::ops::Deref;
+
+use crate::encoders::AsciiSet;
+use crate::encoders::PercentEncoder;
+use crate::StrEncoder;
+
+/// Percent-encodes the bytes of an encodable, escaping the bytes of an
+/// [`AsciiSet`].
+///
+/// The encodable is encoded through a [`PercentEncoder`], so it is escaped
+/// on the fly without an intermediate allocation. The output only requires a
+/// [`StrEncoder`], so URLs can be built directly into a
+/// [`Formatter`](core::fmt::Formatter) or an `ArrayString`. Combine it with
+/// [`Separated`](super::Separated) to build query strings and form bodies
+/// from escaped keys and values.
+///
+/// # Examples
+///
+/// ```rust
+/// # #[cfg(feature = "alloc")] {
+/// use core::convert::Infallible;
+/// use encode::Encodable;
+/// use encode::combinators::{PercentEncoded, Separated};
+/// use encode::encoders::AsciiSet;
+///
+/// let params = [("q", "rust & c"), ("lang", "en-US")];
+/// let pairs = params.map(|(key, value)| {
+///     (
+///         PercentEncoded::<_, Infallible>::new(key, AsciiSet::QUERY),
+///         '=',
+///         PercentEncoded::<_, Infallible>::new(value, AsciiSet::QUERY),
+///     )
+/// });
+///
+/// let mut url = String::from("https://example.com/search?");
+/// Separated::new(&pairs, '&').encode(&mut url).unwrap();
+/// assert_eq!(url, "https://example.com/search?q=rust%20%26%20c&lang=en-US");
+///
+/// let mut body = String::new();
+/// PercentEncoded::<_, Infallible>::new("a b/c", AsciiSet::FORM).encode(&mut body).unwrap();
+/// assert_eq!(body, "a+b%2Fc");
+/// # }
+/// ```
+#[doc(alias("url", "urlencode", "uri"))]
+pub struct PercentEncoded<Encodable, Error> {
+    encodable: Encodable,
+    set: AsciiSet,
+    phantom: PhantomData<Error>,
+}
+
+impl<Encodable, Error> PercentEncoded<Encodable, Error> {
+    /// Creates a new [`PercentEncoded`] combinator that escapes the bytes of
+    /// `set`.
+    #[inline]
+    #[must_use]
+    pub const fn new(encodable: Encodable, set: AsciiSet) -> Self {
+        Self {
+            encodable,
+            set,
+            phantom: PhantomData,
+        }
+    }
+    /// Returns the set of escaped bytes.
+    #[inline]
+    #[must_use]
+    pub const fn set(&self) -> &AsciiSet {
+        &self.set
+    }
+    /// Consumes the combinator and returns the inner encodable.
+    #[inline]
+    #[must_use]
+    pub fn into_inner(self) -> Encodable {
+        self.encodable
+    }
+}
+
+impl<Encodable, Error> AsRef<Encodable> for PercentEncoded<Encodable, Error> {
+    #[inline]
+    fn as_ref(&self) -> &Encodable {
+        &self.encodable
+    }
+}
+
+impl<Encodable, Error> Deref for PercentEncoded<Encodable, Error> {
+    type Target = Encodable;
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        self.as_ref()
+    }
+}
+
+impl<Encodable, Error> Borrow<Encodable> for PercentEncoded<Encodable, Error> {
+    #[inline]
+    fn borrow(&self) -> &Encodable {
+        &self.encodable
+    }
+}
+
+impl<Encodable, Encoder, Error> crate::Encodable<Encoder> for PercentEncoded<Encodable, Error>
+where
+    Encoder: StrEncoder,
+    for<'a> Encodable: crate::Encodable<PercentEncoder<'a, Encoder>>,
+    for<'a> Error: From<<Encodable as crate::Encodable<PercentEncoder<'a, Encoder>>>::Error>,
+    Error: From<Encoder::Error>,
+{
+    type Error = Error;
+
+    #[inline]
+    fn encode(&self, encoder: &mut Encoder) -> Result<(), Self::Error> {
+        self.encodable
+            .encode(&mut PercentEncoder::new(encoder, self.set))?;
+        Ok(())
+    }
+}
+
+// Manual trait implementations because the derive macro does not support
+// phantom data fields.
+impl<Encodable, Error> Debug for PercentEncoded<Encodable, Error>
+where
+    Encodable: Debug,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("PercentEncoded")
+            .field("encodable", &self.encodable)
+            .field("set", &self.set)
+            .finish()
+    }
+}
+impl<Encodable, Error> Clone for PercentEncoded<Encodable, Error>
+where
+    Encodable: Clone,
+{
+    #[inline]
+    fn clone(&self) -> Self {
+        Self::new(self.encodable.clone(), self.set)
+    }
+}
+impl<Encodable, Error> Copy for PercentEncoded<Encodable, Error> where Encodable: Copy {}
+impl<Encodable, Error> PartialEq for PercentEncoded<Encodable, Error>
+where
+    Encodable: PartialEq,
+{
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.encodable == other.encodable && self.set == other.set
+    }
+}
+impl<Encodable, Error> Eq for PercentEncoded<Encodable, Error> where Encodable: Eq {}
+
+#[cfg(test)]
+mod tests {
+    use core::borrow::Borrow;
+    use core::convert::Infallible;
+
+    use super::*;
+    use crate::combinators::FromError;
+    use crate::combinators::Separated;
+    use crate::encoders::InsufficientSpace;
+    use crate::Encodable;
+    use crate::EncodableSize;
+
+    const BUF_SIZE: usize = 128;
+
+    #[test]
+    fn assert_that_urls_can_be_built_from_escaped_parts() {
+        let segments = [
+            PercentEncoded::<_, InsufficientSpace>::new("files", AsciiSet::PATH_SEGMENT),
+            PercentEncoded::<_, InsufficientSpace>::new("a/b c.txt", AsciiSet::PATH_SEGMENT),
+        ];
+        let url = (
+            "https://",
+            PercentEncoded::<_, InsufficientSpace>::new("user@host", AsciiSet::USERINFO),
+            "@example.com/",
+            Separated::new(&segments, '/'),
+            "?v=",
+            PercentEncoded::<_, InsufficientSpace>::new(("1", '+', b'2'), AsciiSet::QUERY),
+        );
+
+        let mut buf = [0u8; BUF_SIZE];
+        let mut encoder = &mut buf as &mut [u8];
+        url.encode(&mut encoder).unwrap();
+        let written = BUF_SIZE - encoder.len();
+
+        assert_eq!(
+            &buf[..written],
+            b"https://user%40host@example.com/files/a%2Fb%20c.txt?v=1%2B2"
+        );
+    }
+
+    #[test]
+    fn assert_that_inner_errors_are_converted() {
+        #[derive(Debug, PartialEq)]
+        struct Error;
+
+        impl From<InsufficientSpace> for Error {
+            fn from(_: InsufficientSpace) -> Self {
+                Error
+            }
+        }
+
+        let segments = [
+            PercentEncoded::<_, Error>::new("a b", AsciiSet::PATH_SEGMENT),
+            PercentEncoded::<_, Error>::new("c/d", AsciiSet::PATH_SEGMENT),
+        ];
+        let path = Separated::new(&segments, FromError::<_, Error>::new('/'));
+
+        let mut buf = [0u8; BUF_SIZE];
+        let mut encoder = &mut buf as &mut [u8];
+        assert_eq!(path.encode(&mut encoder), Ok(()));
+        let written = BUF_SIZE - encoder.len();
+        assert_eq!(&buf[..written], b"a%20b/c%2Fd");
+
+        let mut encoder = &mut buf[..4];
+        assert_eq!(path.encode(&mut encoder), Err(Error));
+    }
+
+    #[test]
+    fn assert_that_values_can_be_escaped_into_a_formatter() {
+        struct Query<'a>(&'a str);
+
+        impl core::fmt::Display for Query<'_> {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                PercentEncoded::<_, core::fmt::Error>::new(self.0, AsciiSet::FORM).encode(f)
+            }
+        }
+
+        let mut buf = [0u8; BUF_SIZE];
+        let mut encoder = &mut buf as &mut [u8];
+        format_args!("q={}", Query("tea & café"))
+            .encode(&mut encoder)
+            .unwrap();
+        let written = BUF_SIZE - encoder.len();
+
+        assert_eq!(&buf[..written], b"q=tea+%26+caf%C3%A9");
+    }
+
+    #[test]
+    fn assert_that_the_encoded_size_includes_escapes() {
+        let encodable = PercentEncoded::<_, Infallible>::new("a b", AsciiSet::QUERY);
+        assert_eq!(encodable.encoded_size(), Ok(5));
+    }
+
+    #[test]
+    fn assert_that_percent_encoded_accessors_work() {
+        let encoded = PercentEncoded::<_, Infallible>::new("a", AsciiSet::QUERY);
+        let borrowed: &&str = encoded.borrow();
+        assert_eq!(*borrowed, "a");
+        assert_eq!(encoded.as_ref(), &"a");
+        assert_eq!(*encoded, "a");
+        assert_eq!(encoded.set(), &AsciiSet::QUERY);
+        assert_eq!(encoded, Clone::clone(&encoded));
+        assert_ne!(encoded, PercentEncoded::new("a", AsciiSet::FORM));
+        assert_eq!(encoded.into_inner(), "a");
+    }
+}
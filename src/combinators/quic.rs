@@ -0,0 +1,330 @@
+use core::ops::Deref;
+
+use super::PrefixWidth;
+use super::QuicVarIntOutOfRange;
+use crate::ByteEncoder;
+use crate::Encodable;
+
+/// The width of an encoded [`QuicVarInt`].
+///
+/// The width is stored in the two most significant bits of the first byte.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub enum QuicVarIntWidth {
+    /// 1 byte, for numbers up to 2<sup>6</sup> - 1.
+    #[default]
+    One,
+    /// 2 bytes, for numbers up to 2<sup>14</sup> - 1.
+    Two,
+    /// 4 bytes, for numbers up to 2<sup>30</sup> - 1.
+    Four,
+    /// 8 bytes, for numbers up to 2<sup>62</sup> - 1.
+    Eight,
+}
+
+impl QuicVarIntWidth {
+    /// Returns the shortest width that can represent the given number.
+    ///
+    /// Numbers larger than [`QuicVarInt::MAX`] are reported as
+    /// [`QuicVarIntWidth::Eight`].
+    #[inline]
+    #[must_use]
+    pub const fn of(num: u64) -> Self {
+        match num {
+            0..0x40 => Self::One,
+            0x40..0x4000 => Self::Two,
+            0x4000..0x4000_0000 => Self::Four,
+            _ => Self::Eight,
+        }
+    }
+
+    /// Returns the number of bytes of this width.
+    #[inline]
+    #[must_use]
+    pub const fn bytes(self) -> usize {
+        match self {
+            Self::One => 1,
+            Self::Two => 2,
+            Self::Four => 4,
+            Self::Eight => 8,
+        }
+    }
+
+    /// Returns the value of the two bit prefix for this width.
+    #[inline]
+    const fn prefix(self) -> u8 {
+        match self {
+            Self::One => 0b00,
+            Self::Two => 0b01,
+            Self::Four => 0b10,
+            Self::Eight => 0b11,
+        }
+    }
+}
+
+/// Encodes a number as a QUIC variable-length integer
+/// ([RFC 9000, Section 16](https://www.rfc-editor.org/rfc/rfc9000.html#section-16)).
+///
+/// The two most significant bits of the first byte store the width of the
+/// integer, which is followed by the number in big-endian order. Numbers are
+/// encoded into the shortest width by default. Use
+/// [`QuicVarInt::with_min_width`] to force a larger width, for example to
+/// reserve space for a value that will be patched later.
+///
+/// Because it implements [`TryFrom<usize>`], it can also be used as the length
+/// of a [`LengthPrefix`](super::LengthPrefix), as long as its error can be
+/// created from a [`QuicVarIntOutOfRange`].
+///
+/// # Examples
+///
+/// ```rust
+/// # #[cfg(feature = "alloc")] {
+/// use encode::Encodable;
+/// use encode::combinators::{QuicVarInt, QuicVarIntWidth};
+///
+/// let mut buf = Vec::new();
+/// QuicVarInt::new(15_293).unwrap().encode(&mut buf).unwrap();
+/// assert_eq!(&buf, &[0x7B, 0xBD]);
+///
+/// buf.clear();
+/// QuicVarInt::new(37).unwrap().with_min_width(QuicVarIntWidth::Two).encode(&mut buf).unwrap();
+/// assert_eq!(&buf, &[0x40, 0x25]);
+///
+/// assert!(QuicVarInt::new(1 << 62).is_err());
+/// # }
+/// ```
+#[doc(alias("varint", "quic", "http3"))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct QuicVarInt {
+    num: u64,
+    min_width: QuicVarIntWidth,
+}
+
+impl QuicVarInt {
+    /// The largest number that can be encoded, 2<sup>62</sup> - 1.
+    pub const MAX: u64 = (1 << 62) - 1;
+
+    /// Creates a new [`QuicVarInt`] combinator.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`QuicVarIntOutOfRange`] if the number is larger than
+    /// [`QuicVarInt::MAX`].
+    #[inline]
+    pub const fn new(num: u64) -> Result<Self, QuicVarIntOutOfRange> {
+        if num > Self::MAX {
+            return Err(QuicVarIntOutOfRange);
+        }
+        Ok(Self {
+            num,
+            min_width: QuicVarIntWidth::One,
+        })
+    }
+
+    /// Forces the number to be encoded with at least the given width.
+    #[inline]
+    #[must_use]
+    pub const fn with_min_width(mut self, min_width: QuicVarIntWidth) -> Self {
+        self.min_width = min_width;
+        self
+    }
+
+    /// Returns the width the number is encoded with.
+    #[inline]
+    #[must_use]
+    pub const fn width(&self) -> QuicVarIntWidth {
+        let width = QuicVarIntWidth::of(self.num);
+        if width.bytes() < self.min_width.bytes() {
+            self.min_width
+        } else {
+            width
+        }
+    }
+
+    /// Returns the number of bytes the number is encoded into.
+    #[inline]
+    #[must_use]
+    pub const fn encoded_len(&self) -> usize {
+        self.width().bytes()
+    }
+
+    /// Consumes the [`QuicVarInt`] combinator and returns the inner value.
+    #[inline]
+    #[must_use]
+    pub const fn into_inner(self) -> u64 {
+        self.num
+    }
+}
+
+impl AsRef<u64> for QuicVarInt {
+    #[inline]
+    fn as_ref(&self) -> &u64 {
+        &self.num
+    }
+}
+impl Deref for QuicVarInt {
+    type Target = u64;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.num
+    }
+}
+
+impl TryFrom<u64> for QuicVarInt {
+    type Error = QuicVarIntOutOfRange;
+
+    #[inline]
+    fn try_from(value: u64) -> Result<Self, Self::Error> {
+        Self::new(value)
+    }
+}
+impl PrefixWidth for QuicVarInt {
+    const FIXED_WIDTH: Option<usize> = None;
+}
+impl TryFrom<usize> for QuicVarInt {
+    type Error = QuicVarIntOutOfRange;
+
+    #[inline]
+    fn try_from(value: usize) -> Result<Self, Self::Error> {
+        u64::try_from(value)
+            .map_err(|_| QuicVarIntOutOfRange)
+            .and_then(Self::new)
+    }
+}
+impl From<QuicVarInt> for u64 {
+    #[inline]
+    fn from(value: QuicVarInt) -> Self {
+        value.num
+    }
+}
+impl TryFrom<QuicVarInt> for usize {
+    type Error = core::num::TryFromIntError;
+
+    #[inline]
+    fn try_from(value: QuicVarInt) -> Result<Self, Self::Error> {
+        usize::try_from(value.num)
+    }
+}
+
+impl<E: ByteEncoder> Encodable<E> for QuicVarInt {
+    type Error = E::Error;
+
+    #[inline]
+    fn encode(&self, encoder: &mut E) -> Result<(), Self::Error> {
+        let width = self.width();
+        let mut bytes = self.num.to_be_bytes();
+        let start = bytes.len() - width.bytes();
+        bytes[start] |= width.prefix() << 6;
+        encoder.put_slice(&bytes[start..])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+    use crate::EncodableSize;
+
+    const BUF_SIZE: usize = 32;
+
+    // Examples from RFC 9000, Appendix A.1
+    #[rstest]
+    #[case::one_byte(37, &[0x25])]
+    #[case::two_bytes(15_293, &[0x7B, 0xBD])]
+    #[case::four_bytes(494_878_333, &[0x9D, 0x7F, 0x3E, 0x7D])]
+    #[case::eight_bytes(151_288_809_941_952_652, &[0xC2, 0x19, 0x7C, 0x5E, 0xFF, 0x14, 0xE8, 0x8C])]
+    #[case::max(QuicVarInt::MAX, &[0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF])]
+    fn assert_that_numbers_are_encoded_in_the_shortest_form(
+        #[case] num: u64,
+        #[case] expected: &[u8],
+    ) {
+        let encodable = QuicVarInt::new(num).unwrap();
+
+        let mut buf = [0u8; BUF_SIZE];
+        let mut encoder = &mut buf as &mut [u8];
+        encodable.encode(&mut encoder).unwrap();
+        let written = BUF_SIZE - encoder.len();
+
+        assert_eq!(&buf[..written], expected);
+        assert_eq!(encodable.encoded_len(), expected.len());
+        assert_eq!(encodable.encoded_size(), Ok(expected.len()));
+    }
+
+    #[rstest]
+    #[case::same(0x3F, QuicVarIntWidth::One, &[0x3F])]
+    #[case::two(37, QuicVarIntWidth::Two, &[0x40, 0x25])]
+    #[case::eight(37, QuicVarIntWidth::Eight, &[0xC0, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x25])]
+    #[case::smaller_than_needed(15_293, QuicVarIntWidth::One, &[0x7B, 0xBD])]
+    fn assert_that_a_minimum_width_can_be_forced(
+        #[case] num: u64,
+        #[case] min_width: QuicVarIntWidth,
+        #[case] expected: &[u8],
+    ) {
+        let encodable = QuicVarInt::new(num).unwrap().with_min_width(min_width);
+
+        let mut buf = [0u8; BUF_SIZE];
+        let mut encoder = &mut buf as &mut [u8];
+        encodable.encode(&mut encoder).unwrap();
+        let written = BUF_SIZE - encoder.len();
+
+        assert_eq!(&buf[..written], expected);
+        assert_eq!(encodable.encoded_len(), expected.len());
+    }
+
+    #[rstest]
+    #[case::first_invalid(QuicVarInt::MAX + 1)]
+    #[case::max(u64::MAX)]
+    fn assert_that_numbers_out_of_range_are_rejected(#[case] num: u64) {
+        assert_eq!(QuicVarInt::new(num), Err(QuicVarIntOutOfRange));
+        assert_eq!(QuicVarInt::try_from(num), Err(QuicVarIntOutOfRange));
+    }
+
+    #[rstest]
+    #[case::boundary_one(0x3F, QuicVarIntWidth::One)]
+    #[case::boundary_two(0x40, QuicVarIntWidth::Two)]
+    #[case::boundary_four(0x4000, QuicVarIntWidth::Four)]
+    #[case::boundary_eight(0x4000_0000, QuicVarIntWidth::Eight)]
+    fn assert_that_the_shortest_width_is_picked(
+        #[case] num: u64,
+        #[case] expected: QuicVarIntWidth,
+    ) {
+        assert_eq!(QuicVarIntWidth::of(num), expected);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn assert_that_quic_var_int_can_be_used_as_a_length_prefix() {
+        use crate::combinators::LengthPrefix;
+
+        #[derive(Debug)]
+        struct TooLarge;
+        impl From<QuicVarIntOutOfRange> for TooLarge {
+            fn from(_: QuicVarIntOutOfRange) -> Self {
+                Self
+            }
+        }
+        impl From<core::convert::Infallible> for TooLarge {
+            fn from(value: core::convert::Infallible) -> Self {
+                match value {}
+            }
+        }
+
+        let encodable = LengthPrefix::<_, QuicVarInt, TooLarge>::new([0xAAu8; 64]);
+        let mut buf = alloc::vec::Vec::new();
+        encodable.encode(&mut buf).unwrap();
+
+        assert_eq!(buf.len(), 66);
+        assert_eq!(&buf[..2], &[0x40, 0x40]);
+    }
+
+    #[test]
+    fn assert_that_quic_var_int_accessors_work() {
+        let varint = QuicVarInt::try_from(42usize).unwrap();
+        assert_eq!(varint.as_ref(), &42);
+        assert_eq!(*varint, 42);
+        assert_eq!(u64::from(varint), 42);
+        assert_eq!(usize::try_from(varint), Ok(42));
+        assert_eq!(varint.into_inner(), 42);
+    }
+}
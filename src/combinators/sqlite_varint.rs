@@ -0,0 +1,213 @@
+use core::borrow::Borrow;
+use core::ops::Deref;
+
+use super::PrefixWidth;
+use crate::ByteEncoder;
+use crate::Encodable;
+
+/// Encodes a number using the
+/// [variable-length integer](https://www.sqlite.org/fileformat2.html#varint)
+/// encoding of the `SQLite` file format.
+///
+/// Numbers are encoded in big-endian order, using between 1 and 9 bytes. The
+/// first 8 bytes store 7 bits of the number each, with the most significant
+/// bit set on every byte but the last. If the number needs more than 56 bits,
+/// the 9th byte stores the remaining 8 bits in full.
+///
+/// Because it implements [`TryFrom<usize>`], it can also be used as the length
+/// of a [`LengthPrefix`](super::LengthPrefix).
+///
+/// # Examples
+///
+/// ```rust
+/// # #[cfg(feature = "alloc")] {
+/// use encode::Encodable;
+/// use encode::combinators::SqliteVarint;
+///
+/// let mut buf = Vec::new();
+/// SqliteVarint::new(300).encode(&mut buf).unwrap();
+/// assert_eq!(&buf, &[0x82, 0x2C]);
+/// assert_eq!(SqliteVarint::new(300).encoded_len(), 2);
+/// # }
+/// ```
+#[doc(alias("varint", "sqlite"))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+#[repr(transparent)]
+pub struct SqliteVarint {
+    num: u64,
+}
+
+impl SqliteVarint {
+    /// The maximum number of bytes a number can be encoded into.
+    pub const MAX_LEN: usize = 9;
+
+    /// Creates a new [`SqliteVarint`] combinator.
+    #[inline]
+    #[must_use]
+    pub const fn new(num: u64) -> Self {
+        Self { num }
+    }
+    /// Consumes the [`SqliteVarint`] combinator and returns the inner value.
+    #[inline]
+    #[must_use]
+    pub const fn into_inner(self) -> u64 {
+        self.num
+    }
+    /// Returns the number of bytes the number is encoded into.
+    #[inline]
+    #[must_use]
+    pub const fn encoded_len(&self) -> usize {
+        let bits = u64::BITS - self.num.leading_zeros();
+        if bits > 56 {
+            Self::MAX_LEN
+        } else if bits == 0 {
+            1
+        } else {
+            bits.div_ceil(7) as usize
+        }
+    }
+}
+
+impl AsRef<u64> for SqliteVarint {
+    #[inline]
+    fn as_ref(&self) -> &u64 {
+        &self.num
+    }
+}
+impl Borrow<u64> for SqliteVarint {
+    #[inline]
+    fn borrow(&self) -> &u64 {
+        &self.num
+    }
+}
+impl Deref for SqliteVarint {
+    type Target = u64;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.num
+    }
+}
+
+impl From<u64> for SqliteVarint {
+    #[inline]
+    fn from(num: u64) -> Self {
+        Self { num }
+    }
+}
+impl From<SqliteVarint> for u64 {
+    #[inline]
+    fn from(value: SqliteVarint) -> Self {
+        value.num
+    }
+}
+impl PrefixWidth for SqliteVarint {
+    const FIXED_WIDTH: Option<usize> = None;
+}
+impl TryFrom<usize> for SqliteVarint {
+    type Error = core::num::TryFromIntError;
+
+    #[inline]
+    fn try_from(value: usize) -> Result<Self, Self::Error> {
+        u64::try_from(value).map(Self::new)
+    }
+}
+impl TryFrom<SqliteVarint> for usize {
+    type Error = core::num::TryFromIntError;
+
+    #[inline]
+    fn try_from(value: SqliteVarint) -> Result<Self, Self::Error> {
+        usize::try_from(value.num)
+    }
+}
+
+impl<E: ByteEncoder> Encodable<E> for SqliteVarint {
+    type Error = E::Error;
+
+    #[inline]
+    #[allow(clippy::cast_possible_truncation)]
+    fn encode(&self, encoder: &mut E) -> Result<(), Self::Error> {
+        let mut buf = [0u8; Self::MAX_LEN];
+        let len = self.encoded_len();
+        let mut num = self.num;
+        let mut groups = &mut buf[..len];
+        if len == Self::MAX_LEN {
+            // The last byte stores 8 bits instead of 7
+            buf[Self::MAX_LEN - 1] = num as u8;
+            num >>= 8;
+            groups = &mut buf[..Self::MAX_LEN - 1];
+        }
+        let last = groups.len() - 1;
+        for (i, byte) in groups.iter_mut().enumerate().rev() {
+            *byte = num as u8 & 0x7F;
+            if i != last {
+                *byte |= 0x80;
+            }
+            num >>= 7;
+        }
+        if len == Self::MAX_LEN {
+            buf[last] |= 0x80;
+        }
+        encoder.put_slice(&buf[..len])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::borrow::Borrow;
+
+    use rstest::rstest;
+
+    use super::*;
+    use crate::EncodableSize;
+
+    const BUF_SIZE: usize = 32;
+
+    #[rstest]
+    #[case::zero(0, &[0x00])]
+    #[case::largest_single_byte(0x7F, &[0x7F])]
+    #[case::smallest_two_bytes(0x80, &[0x81, 0x00])]
+    #[case::three_hundred(300, &[0x82, 0x2C])]
+    #[case::largest_eight_bytes(0x00FF_FFFF_FFFF_FFFF, &[0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0x7F])]
+    #[case::smallest_nine_bytes(0x0100_0000_0000_0000, &[0x80, 0xC0, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x00])]
+    #[case::max(u64::MAX, &[0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF])]
+    #[case::high_bit(0x8000_0000_0000_0001, &[0xC0, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x01])]
+    fn assert_that_sqlite_varints_can_be_encoded(#[case] num: u64, #[case] expected: &[u8]) {
+        let encodable = SqliteVarint::new(num);
+
+        let mut buf = [0u8; BUF_SIZE];
+        let mut encoder = &mut buf as &mut [u8];
+        encodable.encode(&mut encoder).unwrap();
+        let written = BUF_SIZE - encoder.len();
+
+        assert_eq!(&buf[..written], expected);
+        assert_eq!(encodable.encoded_len(), expected.len());
+        assert_eq!(encodable.encoded_size(), Ok(expected.len()));
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn assert_that_sqlite_varint_can_be_used_as_a_length_prefix() {
+        use crate::combinators::LengthPrefix;
+
+        let encodable =
+            LengthPrefix::<_, SqliteVarint, core::num::TryFromIntError>::new([0xAAu8; 300]);
+        let mut buf = alloc::vec::Vec::new();
+        encodable.encode(&mut buf).unwrap();
+
+        assert_eq!(buf.len(), 302);
+        assert_eq!(&buf[..2], &[0x82, 0x2C]);
+    }
+
+    #[test]
+    fn assert_that_sqlite_varint_accessors_work() {
+        let varint = SqliteVarint::try_from(42usize).unwrap();
+        let borrowed: &u64 = varint.borrow();
+        assert_eq!(*borrowed, 42);
+        assert_eq!(varint.as_ref(), &42);
+        assert_eq!(*varint, 42);
+        assert_eq!(u64::from(varint), 42);
+        assert_eq!(usize::try_from(varint), Ok(42));
+        assert_eq!(varint.into_inner(), 42);
+    }
+}
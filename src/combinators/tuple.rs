@@ -0,0 +1,267 @@
+#![allow(unused_variables)]
+use core::borrow::Borrow;
+use core::fmt::Debug;
+use core::marker::PhantomData;
+use core::ops::Deref;
+
+use crate::BaseEncoder;
+use crate::Encodable;
+
+/// Encodes a tuple whose elements have different error types.
+///
+/// Plain tuples require every element to share the same error type. This
+/// combinator lifts that restriction by converting the error of each element
+/// into a common `Error` type, as long as it implements [`From`] for all of
+/// them.
+///
+/// # Example
+///
+/// ```
+/// # #[cfg(feature = "alloc")] {
+/// use core::num::TryFromIntError;
+/// use encode::Encodable;
+/// use encode::combinators::{LengthPrefix, Tuple, LE};
+///
+/// let encodable = Tuple::<_, TryFromIntError>::new((
+///     1u8,
+///     LE::new(2.0f64),
+///     LengthPrefix::<_, u8, TryFromIntError>::new("hello"),
+/// ));
+///
+/// let mut buf = Vec::new();
+/// encodable.encode(&mut buf).unwrap();
+/// assert_eq!(&buf, b"\x01\x00\x00\x00\x00\x00\x00\x00\x40\x05hello");
+/// # }
+/// ```
+#[repr(transparent)]
+pub struct Tuple<T, Error> {
+    tuple: T,
+    error: PhantomData<Error>,
+}
+
+impl<T, Error> Tuple<T, Error> {
+    /// Creates a new [`Tuple`] combinator.
+    #[inline]
+    #[must_use]
+    pub const fn new(tuple: T) -> Self {
+        Self {
+            tuple,
+            error: PhantomData,
+        }
+    }
+    /// Consumes the [`Tuple`] combinator and returns the inner tuple.
+    #[inline]
+    #[must_use]
+    pub fn into_inner(self) -> T {
+        self.tuple
+    }
+}
+
+impl<T, Error> Deref for Tuple<T, Error> {
+    type Target = T;
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        self.as_ref()
+    }
+}
+impl<T, Error> AsRef<T> for Tuple<T, Error> {
+    #[inline]
+    fn as_ref(&self) -> &T {
+        &self.tuple
+    }
+}
+impl<T, Error> Borrow<T> for Tuple<T, Error> {
+    #[inline]
+    fn borrow(&self) -> &T {
+        &self.tuple
+    }
+}
+
+// https://users.rust-lang.org/t/macro-to-impl-trait-for-tuple/79165/3
+macro_rules! impl_encodable_for_tuple {
+    ($($T:tt)*) => {
+        paste::paste! {
+            impl<ENC, Error, $($T,)*> Encodable<ENC> for Tuple<($($T,)*), Error>
+            where
+                ENC: BaseEncoder,
+                Error: From<ENC::Error> $(+ From<$T::Error>)*,
+                $($T: Encodable<ENC>,)*
+            {
+                type Error = Error;
+                #[inline]
+                fn encode(&self, encoder: &mut ENC) -> Result<(), Self::Error> {
+                    let ($([<$T:lower>],)*) = &self.tuple;
+                    $([<$T:lower>].encode(encoder)?;)*
+                    Ok(())
+                }
+            }
+        }
+    };
+}
+
+impl_encodable_for_tuple!(A B C D E F G H I J K L M N O P Q R S T U V W X Y Z);
+impl_encodable_for_tuple!(A B C D E F G H I J K L M N O P Q R S T U V W X Y);
+impl_encodable_for_tuple!(A B C D E F G H I J K L M N O P Q R S T U V W X);
+impl_encodable_for_tuple!(A B C D E F G H I J K L M N O P Q R S T U V W);
+impl_encodable_for_tuple!(A B C D E F G H I J K L M N O P Q R S T U V);
+impl_encodable_for_tuple!(A B C D E F G H I J K L M N O P Q R S T U);
+impl_encodable_for_tuple!(A B C D E F G H I J K L M N O P Q R S T);
+impl_encodable_for_tuple!(A B C D E F G H I J K L M N O P Q R S);
+impl_encodable_for_tuple!(A B C D E F G H I J K L M N O P Q R);
+impl_encodable_for_tuple!(A B C D E F G H I J K L M N O P Q);
+impl_encodable_for_tuple!(A B C D E F G H I J K L M N O P);
+impl_encodable_for_tuple!(A B C D E F G H I J K L M N O);
+impl_encodable_for_tuple!(A B C D E F G H I J K L M N);
+impl_encodable_for_tuple!(A B C D E F G H I J K L M);
+impl_encodable_for_tuple!(A B C D E F G H I J K L);
+impl_encodable_for_tuple!(A B C D E F G H I J K);
+impl_encodable_for_tuple!(A B C D E F G H I J);
+impl_encodable_for_tuple!(A B C D E F G H I);
+impl_encodable_for_tuple!(A B C D E F G H);
+impl_encodable_for_tuple!(A B C D E F G);
+impl_encodable_for_tuple!(A B C D E F);
+impl_encodable_for_tuple!(A B C D E);
+impl_encodable_for_tuple!(A B C D);
+impl_encodable_for_tuple!(A B C);
+impl_encodable_for_tuple!(A B);
+impl_encodable_for_tuple!(A);
+impl_encodable_for_tuple!();
+
+// Manual trait implementations because the derive macro does not support
+// phantom data fields.
+impl<T: Debug, Error> Debug for Tuple<T, Error> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Tuple").field("tuple", &self.tuple).finish()
+    }
+}
+impl<T: Clone, Error> Clone for Tuple<T, Error> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self {
+            tuple: self.tuple.clone(),
+            error: PhantomData,
+        }
+    }
+}
+impl<T: Copy, Error> Copy for Tuple<T, Error> {}
+impl<T: Default, Error> Default for Tuple<T, Error> {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            tuple: Default::default(),
+            error: PhantomData,
+        }
+    }
+}
+impl<T: PartialEq, Error> PartialEq for Tuple<T, Error> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.tuple == other.tuple
+    }
+}
+impl<T: Eq, Error> Eq for Tuple<T, Error> {}
+impl<T: PartialOrd, Error> PartialOrd for Tuple<T, Error> {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+        self.tuple.partial_cmp(&other.tuple)
+    }
+}
+impl<T: Ord, Error> Ord for Tuple<T, Error> {
+    #[inline]
+    fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+        self.tuple.cmp(&other.tuple)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::convert::Infallible;
+    use core::num::TryFromIntError;
+
+    use rstest::rstest;
+
+    use super::*;
+    use crate::combinators::LengthPrefix;
+    use crate::combinators::LE;
+    use crate::encoders::InsufficientSpace;
+
+    const BUF_SIZE: usize = 32;
+
+    #[derive(Debug, PartialEq)]
+    enum MixedError {
+        InsufficientSpace,
+        TooLarge,
+    }
+
+    impl From<InsufficientSpace> for MixedError {
+        fn from(_: InsufficientSpace) -> Self {
+            Self::InsufficientSpace
+        }
+    }
+
+    impl From<TryFromIntError> for MixedError {
+        fn from(_: TryFromIntError) -> Self {
+            Self::TooLarge
+        }
+    }
+
+    impl From<Infallible> for MixedError {
+        fn from(value: Infallible) -> Self {
+            match value {}
+        }
+    }
+
+    #[test]
+    fn assert_that_elements_with_different_errors_can_be_encoded() {
+        let expected = b"\x01\x02\x00\x03abc";
+        let encodable = Tuple::<_, MixedError>::new((
+            1u8,
+            LE::new(2u16),
+            LengthPrefix::<_, u8, MixedError>::new("abc"),
+        ));
+
+        let mut buf = [0u8; BUF_SIZE];
+        let mut encoder = &mut buf as &mut [u8];
+        encodable.encode(&mut encoder).unwrap();
+        let written = BUF_SIZE - encoder.len();
+        let result = &buf[..written];
+
+        assert_eq!(expected, result);
+    }
+
+    #[rstest]
+    #[case::encoder(2, &[0u8; 4], MixedError::InsufficientSpace)]
+    #[case::element(BUF_SIZE, &[0u8; 256], MixedError::TooLarge)]
+    fn assert_that_element_errors_are_converted(
+        #[case] size: usize,
+        #[case] bytes: &[u8],
+        #[case] expected: MixedError,
+    ) {
+        let encodable =
+            Tuple::<_, MixedError>::new((1u8, LengthPrefix::<_, u8, MixedError>::new(bytes)));
+
+        let mut buf = [0u8; BUF_SIZE];
+        let mut encoder = &mut buf[..size];
+        let result = encodable.encode(&mut encoder);
+
+        assert_eq!(result, Err(expected));
+    }
+
+    #[test]
+    fn assert_that_empty_tuples_can_be_encoded() {
+        let encodable = Tuple::<_, Infallible>::new(());
+        encodable.encode(&mut ()).unwrap();
+    }
+
+    #[test]
+    fn assert_that_tuple_into_inner_returns_value() {
+        let tuple = Tuple::<_, Infallible>::new((1u8, 2u8));
+        assert_eq!(tuple.into_inner(), (1u8, 2u8));
+    }
+
+    #[test]
+    fn assert_that_tuple_deref_works() {
+        let tuple = Tuple::<_, Infallible>::new((1u8, 2u8));
+        assert_eq!(tuple.0, 1u8);
+    }
+}
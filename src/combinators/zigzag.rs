@@ -0,0 +1,267 @@
+use core::borrow::Borrow;
+use core::ops::Deref;
+
+use super::Uleb128;
+use super::BE;
+use super::LE;
+use crate::ByteEncoder;
+use crate::Encodable;
+
+/// Maps a signed number to an unsigned one using
+/// [ZigZag](https://protobuf.dev/programming-guides/encoding/#signed-ints)
+/// encoding.
+///
+/// This encoding interleaves positive and negative numbers (`0, -1, 1, -2,
+/// 2, ...` become `0, 1, 2, 3, 4, ...`), so numbers with a small absolute
+/// value map to small unsigned numbers. This is the encoding used by the
+/// `sint32` and `sint64` types of Protocol Buffers.
+///
+/// [`ZigZag`] does not define a byte order on its own. Wrap it in [`LE`],
+/// [`BE`] or [`Uleb128`] to encode it.
+///
+/// # Examples
+///
+/// ```rust
+/// # #[cfg(feature = "alloc")] {
+/// use encode::Encodable;
+/// use encode::combinators::{Uleb128, ZigZag, LE};
+///
+/// let mut buf = Vec::new();
+/// Uleb128::new(ZigZag::new(-1i32)).encode(&mut buf).unwrap();
+/// assert_eq!(&buf, &[0x01]);
+///
+/// buf.clear();
+/// LE::new(ZigZag::new(1i16)).encode(&mut buf).unwrap();
+/// assert_eq!(&buf, &[0x02, 0x00]);
+/// # }
+/// ```
+#[doc(alias("sint32", "sint64"))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+#[repr(transparent)]
+pub struct ZigZag<T> {
+    num: T,
+}
+
+impl<T> ZigZag<T> {
+    /// Creates a new [`ZigZag`] combinator.
+    #[inline]
+    #[must_use]
+    pub const fn new(num: T) -> Self {
+        Self { num }
+    }
+    /// Consumes the [`ZigZag`] combinator and returns the inner value.
+    #[inline]
+    #[must_use]
+    pub fn into_inner(self) -> T {
+        self.num
+    }
+}
+
+impl<T> AsRef<T> for ZigZag<T> {
+    #[inline]
+    fn as_ref(&self) -> &T {
+        &self.num
+    }
+}
+impl<T> Borrow<T> for ZigZag<T> {
+    #[inline]
+    fn borrow(&self) -> &T {
+        &self.num
+    }
+}
+impl<T> Deref for ZigZag<T> {
+    type Target = T;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.num
+    }
+}
+
+macro_rules! impl_zigzag_for_num {
+    ($($T:ty => $U:ty)*) => {
+        $(
+            impl ZigZag<$T> {
+                /// Returns the encoded representation of the number.
+                #[inline]
+                #[must_use]
+                #[allow(clippy::cast_sign_loss)]
+                pub const fn to_unsigned(&self) -> $U {
+                    ((self.num << 1) ^ (self.num >> (<$T>::BITS - 1))) as $U
+                }
+                /// Creates a [`ZigZag`] combinator from its encoded
+                /// representation.
+                #[inline]
+                #[must_use]
+                #[allow(clippy::cast_possible_wrap)]
+                pub const fn from_unsigned(num: $U) -> Self {
+                    Self::new((num >> 1) as $T ^ -((num & 1) as $T))
+                }
+            }
+            impl From<$T> for ZigZag<$T> {
+                #[inline]
+                fn from(num: $T) -> Self {
+                    Self { num }
+                }
+            }
+            impl From<ZigZag<$T>> for $T {
+                #[inline]
+                fn from(zigzag: ZigZag<$T>) -> Self {
+                    zigzag.num
+                }
+            }
+            impl<E: ByteEncoder> Encodable<E> for LE<ZigZag<$T>> {
+                type Error = E::Error;
+
+                #[inline]
+                fn encode(&self, encoder: &mut E) -> Result<(), Self::Error> {
+                    LE::new(self.to_unsigned()).encode(encoder)
+                }
+            }
+            impl<E: ByteEncoder> Encodable<E> for BE<ZigZag<$T>> {
+                type Error = E::Error;
+
+                #[inline]
+                fn encode(&self, encoder: &mut E) -> Result<(), Self::Error> {
+                    BE::new(self.to_unsigned()).encode(encoder)
+                }
+            }
+            impl Uleb128<ZigZag<$T>> {
+                /// The maximum number of bytes this type can be encoded into.
+                pub const MAX_LEN: usize = Uleb128::<$U>::MAX_LEN;
+
+                /// Returns the number of bytes the number is encoded into.
+                #[inline]
+                #[must_use]
+                pub fn encoded_len(&self) -> usize {
+                    Uleb128::new(self.to_unsigned()).encoded_len()
+                }
+            }
+            impl<E: ByteEncoder> Encodable<E> for Uleb128<ZigZag<$T>> {
+                type Error = E::Error;
+
+                #[inline]
+                fn encode(&self, encoder: &mut E) -> Result<(), Self::Error> {
+                    Uleb128::new(self.to_unsigned()).encode(encoder)
+                }
+            }
+        )*
+    };
+}
+
+impl_zigzag_for_num!(i8 => u8 i16 => u16 i32 => u32 i64 => u64 i128 => u128);
+
+#[cfg(test)]
+mod tests {
+    use core::borrow::Borrow;
+
+    use rstest::rstest;
+
+    use super::*;
+
+    const BUF_SIZE: usize = 32;
+
+    #[rstest]
+    #[case::zero(0, 0)]
+    #[case::minus_one(-1, 1)]
+    #[case::one(1, 2)]
+    #[case::minus_two(-2, 3)]
+    #[case::max(i32::MAX, u32::MAX - 1)]
+    #[case::min(i32::MIN, u32::MAX)]
+    fn assert_that_signed_numbers_are_interleaved(#[case] num: i32, #[case] expected: u32) {
+        assert_eq!(ZigZag::new(num).to_unsigned(), expected);
+        assert_eq!(ZigZag::<i32>::from_unsigned(expected), ZigZag::new(num));
+    }
+
+    #[test]
+    fn assert_that_every_i8_round_trips() {
+        for num in i8::MIN..=i8::MAX {
+            let zigzag = ZigZag::new(num);
+            assert_eq!(ZigZag::<i8>::from_unsigned(zigzag.to_unsigned()), zigzag);
+        }
+    }
+
+    #[test]
+    fn assert_that_every_i16_round_trips() {
+        for num in i16::MIN..=i16::MAX {
+            let zigzag = ZigZag::new(num);
+            assert_eq!(ZigZag::<i16>::from_unsigned(zigzag.to_unsigned()), zigzag);
+        }
+    }
+
+    #[test]
+    fn assert_that_every_u16_maps_back_to_itself() {
+        for num in u16::MIN..=u16::MAX {
+            assert_eq!(ZigZag::<i16>::from_unsigned(num).to_unsigned(), num);
+        }
+    }
+
+    macro_rules! boundary_round_trip_tests {
+        ($($name:ident: $T:ty => $U:ty,)*) => {
+            $(
+                #[test]
+                fn $name() {
+                    let boundaries = [<$T>::MIN, <$T>::MIN + 1, -1, 0, 1, <$T>::MAX - 1, <$T>::MAX];
+                    for num in boundaries {
+                        let zigzag = ZigZag::new(num);
+                        assert_eq!(ZigZag::<$T>::from_unsigned(zigzag.to_unsigned()), zigzag);
+                    }
+                    assert_eq!(ZigZag::new(<$T>::MAX).to_unsigned(), <$U>::MAX - 1);
+                    assert_eq!(ZigZag::new(<$T>::MIN).to_unsigned(), <$U>::MAX);
+                }
+            )*
+        };
+    }
+
+    boundary_round_trip_tests! {
+        assert_that_i32_boundaries_round_trip: i32 => u32,
+        assert_that_i64_boundaries_round_trip: i64 => u64,
+        assert_that_i128_boundaries_round_trip: i128 => u128,
+    }
+
+    #[test]
+    fn assert_that_zigzag_can_be_encoded_in_little_endian() {
+        let mut buf = [0u8; BUF_SIZE];
+        let mut encoder = &mut buf as &mut [u8];
+        LE::new(ZigZag::new(-2i16)).encode(&mut encoder).unwrap();
+        let written = BUF_SIZE - encoder.len();
+        assert_eq!(&buf[..written], &[0x03, 0x00]);
+    }
+
+    #[test]
+    fn assert_that_zigzag_can_be_encoded_in_big_endian() {
+        let mut buf = [0u8; BUF_SIZE];
+        let mut encoder = &mut buf as &mut [u8];
+        BE::new(ZigZag::new(-2i16)).encode(&mut encoder).unwrap();
+        let written = BUF_SIZE - encoder.len();
+        assert_eq!(&buf[..written], &[0x00, 0x03]);
+    }
+
+    #[rstest]
+    #[case::zero(0, &[0x00])]
+    #[case::minus_one(-1, &[0x01])]
+    #[case::minus_sixty_five(-65, &[0x81, 0x01])]
+    #[case::min(i64::MIN, &[0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0x01])]
+    fn assert_that_zigzag_can_be_encoded_as_a_varint(#[case] num: i64, #[case] expected: &[u8]) {
+        let encodable = Uleb128::new(ZigZag::new(num));
+
+        let mut buf = [0u8; BUF_SIZE];
+        let mut encoder = &mut buf as &mut [u8];
+        encodable.encode(&mut encoder).unwrap();
+        let written = BUF_SIZE - encoder.len();
+
+        assert_eq!(&buf[..written], expected);
+        assert_eq!(encodable.encoded_len(), expected.len());
+    }
+
+    #[test]
+    fn assert_that_zigzag_accessors_work() {
+        let zigzag: ZigZag<i32> = (-42).into();
+        let borrowed: &i32 = zigzag.borrow();
+        assert_eq!(*borrowed, -42);
+        assert_eq!(zigzag.as_ref(), &-42);
+        assert_eq!(*zigzag, -42);
+        assert_eq!(i32::from(zigzag), -42);
+        assert_eq!(zigzag.into_inner(), -42);
+    }
+}
@@ -0,0 +1,3 @@
+mod primitives;
+mod slices;
+mod tuples;
@@ -0,0 +1,68 @@
+use crate::ByteDecoder;
+use crate::Decodable;
+
+impl<'de, D: ByteDecoder<'de>> Decodable<D> for u8 {
+    type Error = D::Error;
+
+    #[inline]
+    fn decode(decoder: &mut D) -> Result<Self, Self::Error> {
+        decoder.take_byte()
+    }
+}
+
+impl<'de, D: ByteDecoder<'de>> Decodable<D> for i8 {
+    type Error = D::Error;
+
+    #[inline]
+    fn decode(decoder: &mut D) -> Result<Self, Self::Error> {
+        #[allow(clippy::cast_possible_wrap)]
+        u8::decode(decoder).map(|byte| byte as i8)
+    }
+}
+
+/// Any non-zero byte is decoded as `true`.
+impl<'de, D: ByteDecoder<'de>> Decodable<D> for bool {
+    type Error = D::Error;
+
+    #[inline]
+    fn decode(decoder: &mut D) -> Result<Self, Self::Error> {
+        u8::decode(decoder).map(|byte| byte != 0)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use rstest::rstest;
+
+    use super::*;
+    use crate::decoders::UnexpectedEnd;
+
+    #[test]
+    fn assert_that_unsigned_bytes_can_be_decoded() {
+        let mut decoder = b"\xFF" as &[u8];
+        assert_eq!(u8::decode(&mut decoder), Ok(u8::MAX));
+        assert!(decoder.is_empty());
+    }
+
+    #[test]
+    fn assert_that_signed_bytes_can_be_decoded() {
+        let mut decoder = b"\x80" as &[u8];
+        assert_eq!(i8::decode(&mut decoder), Ok(i8::MIN));
+        assert!(decoder.is_empty());
+    }
+
+    #[rstest]
+    #[case::enabled(b"\x01", true)]
+    #[case::disabled(b"\x00", false)]
+    #[case::non_zero(b"\x2A", true)]
+    fn assert_that_booleans_can_be_decoded(#[case] input: &[u8], #[case] expected: bool) {
+        let mut decoder = input;
+        assert_eq!(bool::decode(&mut decoder), Ok(expected));
+    }
+
+    #[test]
+    fn assert_that_decoding_a_byte_from_empty_input_fails() {
+        let mut decoder = b"" as &[u8];
+        assert_eq!(bool::decode(&mut decoder), Err(UnexpectedEnd));
+    }
+}
@@ -0,0 +1,84 @@
+use core::ffi::CStr;
+
+use crate::ByteDecoder;
+use crate::Decodable;
+
+/// Consumes all the remaining bytes of the decoder.
+impl<'de, D: ByteDecoder<'de>> Decodable<D> for &'de [u8] {
+    type Error = D::Error;
+
+    #[inline]
+    fn decode(decoder: &mut D) -> Result<Self, Self::Error> {
+        let len = decoder.peek().len();
+        decoder.take_slice(len)
+    }
+}
+
+impl<'de, const SIZE: usize, D: ByteDecoder<'de>> Decodable<D> for [u8; SIZE] {
+    type Error = D::Error;
+
+    #[inline]
+    fn decode(decoder: &mut D) -> Result<Self, Self::Error> {
+        let mut array = [0; SIZE];
+        array.copy_from_slice(decoder.take_slice(SIZE)?);
+        Ok(array)
+    }
+}
+
+/// Consumes the bytes up to and including the null terminator (`\0`).
+impl<'de, D: ByteDecoder<'de>> Decodable<D> for &'de CStr {
+    type Error = D::Error;
+
+    #[inline]
+    fn decode(decoder: &mut D) -> Result<Self, Self::Error> {
+        let remaining = decoder.peek();
+        // Without a terminator, asking for one byte past the end of the input
+        // makes the decoder report the truncated input.
+        let len = remaining
+            .iter()
+            .position(|&byte| byte == 0)
+            .unwrap_or(remaining.len())
+            + 1;
+        let bytes = decoder.take_slice(len)?;
+        Ok(CStr::from_bytes_with_nul(bytes).unwrap_or_default())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::decoders::UnexpectedEnd;
+
+    #[test]
+    fn assert_that_arrays_can_be_decoded() {
+        let mut decoder = b"\x01\x02\x03\x04" as &[u8];
+        assert_eq!(<[u8; 3]>::decode(&mut decoder), Ok([1, 2, 3]));
+        assert_eq!(decoder, b"\x04");
+    }
+
+    #[test]
+    fn assert_that_arrays_fail_on_truncated_input() {
+        let mut decoder = b"\x01\x02" as &[u8];
+        assert_eq!(<[u8; 3]>::decode(&mut decoder), Err(UnexpectedEnd));
+    }
+
+    #[test]
+    fn assert_that_slices_consume_the_remaining_input() {
+        let mut decoder = b"\x01\x02\x03" as &[u8];
+        assert_eq!(<&[u8]>::decode(&mut decoder), Ok(b"\x01\x02\x03" as &[u8]));
+        assert!(decoder.is_empty());
+    }
+
+    #[test]
+    fn assert_that_cstrs_can_be_decoded() {
+        let mut decoder = b"Hello world!\0rest" as &[u8];
+        assert_eq!(<&CStr>::decode(&mut decoder), Ok(c"Hello world!"));
+        assert_eq!(decoder, b"rest");
+    }
+
+    #[test]
+    fn assert_that_cstrs_without_terminator_fail() {
+        let mut decoder = b"Hello world!" as &[u8];
+        assert_eq!(<&CStr>::decode(&mut decoder), Err(UnexpectedEnd));
+    }
+}
@@ -0,0 +1,78 @@
+#![allow(unused_variables)]
+use crate::BaseDecoder;
+use crate::Decodable;
+
+macro_rules! impl_decodable_for_tuple {
+    ($($T:tt)*) => {
+        impl<DEC, A, $($T,)*> Decodable<DEC> for (A,$($T,)*)
+        where
+            DEC: BaseDecoder,
+            A: Decodable<DEC>,
+            $($T: Decodable<DEC, Error = A::Error>,)*
+        {
+            type Error = A::Error;
+            #[inline]
+            fn decode(decoder:&mut DEC) -> Result<Self, Self::Error> {
+                Ok((A::decode(decoder)?, $($T::decode(decoder)?,)*))
+            }
+        }
+    };
+}
+
+impl_decodable_for_tuple!(B C D E F G H I J K L M N O P Q R S T U V W X Y Z);
+impl_decodable_for_tuple!(B C D E F G H I J K L M N O P Q R S T U V W X Y);
+impl_decodable_for_tuple!(B C D E F G H I J K L M N O P Q R S T U V W X);
+impl_decodable_for_tuple!(B C D E F G H I J K L M N O P Q R S T U V W);
+impl_decodable_for_tuple!(B C D E F G H I J K L M N O P Q R S T U V);
+impl_decodable_for_tuple!(B C D E F G H I J K L M N O P Q R S T U);
+impl_decodable_for_tuple!(B C D E F G H I J K L M N O P Q R S T);
+impl_decodable_for_tuple!(B C D E F G H I J K L M N O P Q R S);
+impl_decodable_for_tuple!(B C D E F G H I J K L M N O P Q R);
+impl_decodable_for_tuple!(B C D E F G H I J K L M N O P Q);
+impl_decodable_for_tuple!(B C D E F G H I J K L M N O P);
+impl_decodable_for_tuple!(B C D E F G H I J K L M N O);
+impl_decodable_for_tuple!(B C D E F G H I J K L M N);
+impl_decodable_for_tuple!(B C D E F G H I J K L M);
+impl_decodable_for_tuple!(B C D E F G H I J K L);
+impl_decodable_for_tuple!(B C D E F G H I J K);
+impl_decodable_for_tuple!(B C D E F G H I J);
+impl_decodable_for_tuple!(B C D E F G H I);
+impl_decodable_for_tuple!(B C D E F G H);
+impl_decodable_for_tuple!(B C D E F G);
+impl_decodable_for_tuple!(B C D E F);
+impl_decodable_for_tuple!(B C D E);
+impl_decodable_for_tuple!(B C D);
+impl_decodable_for_tuple!(B C);
+impl_decodable_for_tuple!(B);
+impl_decodable_for_tuple!();
+
+impl<D: BaseDecoder> Decodable<D> for () {
+    type Error = D::Error;
+
+    #[inline]
+    fn decode(_decoder: &mut D) -> Result<Self, Self::Error> {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use core::ffi::CStr;
+
+    use super::*;
+
+    #[test]
+    fn assert_that_tuples_can_be_decoded() {
+        let mut decoder = b"hello\0\x01\x02" as &[u8];
+        let result = <(&CStr, u8, bool)>::decode(&mut decoder).unwrap();
+        assert_eq!(result, (c"hello", 1, true));
+        assert!(decoder.is_empty());
+    }
+
+    #[test]
+    fn assert_that_unit_can_be_decoded() {
+        let mut decoder = b"\x01" as &[u8];
+        <()>::decode(&mut decoder).unwrap();
+        assert_eq!(decoder, b"\x01", "Unit does not consume any input");
+    }
+}
@@ -0,0 +1,99 @@
+/// An error that occurs when the input ends before a value could be fully
+/// decoded.
+///
+/// This error is returned by decoders that read from a fixed-size input, such
+/// as [`&[u8]`](slice).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct UnexpectedEnd;
+
+impl core::error::Error for UnexpectedEnd {}
+impl core::fmt::Display for UnexpectedEnd {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(f, "The input ended before the value could be decoded")
+    }
+}
+
+/// An error that occurs when a length delimited value does not consume all the
+/// bytes it was given.
+///
+/// This error is returned by combinators that bound the input of a value, such
+/// as [`LengthPrefix`](crate::combinators::LengthPrefix), when the value ends
+/// before the bounded input does.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct TrailingBytes;
+
+impl core::error::Error for TrailingBytes {}
+impl core::fmt::Display for TrailingBytes {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(f, "The value did not consume all the bytes of its input")
+    }
+}
+
+/// An error that occurs when decoding a [`NonZero`](core::num::NonZero) number.
+///
+/// Either the decoder failed to provide the bytes of the number, or the
+/// decoded number was zero.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum NonZeroError<E> {
+    /// The decoder failed to provide the bytes of the number.
+    Decoder(E),
+    /// The decoded number was zero.
+    Zero,
+}
+
+impl<E> From<E> for NonZeroError<E> {
+    #[inline]
+    fn from(err: E) -> Self {
+        Self::Decoder(err)
+    }
+}
+
+impl<E: core::error::Error + 'static> core::error::Error for NonZeroError<E> {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match self {
+            Self::Decoder(err) => Some(err),
+            Self::Zero => None,
+        }
+    }
+}
+impl<E: core::fmt::Display> core::fmt::Display for NonZeroError<E> {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        match self {
+            Self::Decoder(err) => core::fmt::Display::fmt(err, f),
+            Self::Zero => write!(f, "Expected a non-zero number, but found zero"),
+        }
+    }
+}
+
+#[cfg(all(test, feature = "alloc"))]
+mod tests {
+    use alloc::string::ToString;
+
+    use super::*;
+
+    #[test]
+    fn assert_that_unexpected_end_displays_correctly() {
+        let err = UnexpectedEnd;
+        let msg = err.to_string();
+        assert_eq!(msg, "The input ended before the value could be decoded");
+    }
+
+    #[test]
+    fn assert_that_trailing_bytes_displays_correctly() {
+        let err = TrailingBytes;
+        let msg = err.to_string();
+        assert_eq!(msg, "The value did not consume all the bytes of its input");
+    }
+
+    #[test]
+    fn assert_that_non_zero_error_displays_correctly() {
+        assert_eq!(
+            NonZeroError::<UnexpectedEnd>::Zero.to_string(),
+            "Expected a non-zero number, but found zero"
+        );
+        assert_eq!(
+            NonZeroError::Decoder(UnexpectedEnd).to_string(),
+            UnexpectedEnd.to_string()
+        );
+    }
+}
@@ -0,0 +1,20 @@
+//! Decoders for reading encoded data back from various input types.
+//!
+//! [`BaseDecoder`]: crate::BaseDecoder
+//! [`ByteDecoder`]: crate::ByteDecoder
+//!
+//! This module provides implementations of [`BaseDecoder`] and
+//! [`ByteDecoder`] for the supported input types.
+//!
+//! # Supported Decoder Types
+//!
+//! | Type | Description | [`BaseDecoder`] | [`ByteDecoder`] | Requires feature |
+//! |------|-------------|-----------------|-----------------|------------------|
+//! | [`&[u8]`](slice) | Reads bytes from the front of a slice. Fails if the slice is exhausted. | ✅ | ✅ | - |
+
+mod errors;
+mod slices;
+
+pub use errors::NonZeroError;
+pub use errors::TrailingBytes;
+pub use errors::UnexpectedEnd;
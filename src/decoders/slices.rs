@@ -0,0 +1,61 @@
+use super::UnexpectedEnd;
+use crate::BaseDecoder;
+use crate::ByteDecoder;
+
+impl BaseDecoder for &[u8] {
+    type Error = UnexpectedEnd;
+}
+
+impl<'de> ByteDecoder<'de> for &'de [u8] {
+    #[inline]
+    fn take_slice(&mut self, len: usize) -> Result<&'de [u8], Self::Error> {
+        let (a, b) = self.split_at_checked(len).ok_or(UnexpectedEnd)?;
+        *self = b;
+        Ok(a)
+    }
+
+    #[inline]
+    fn take_byte(&mut self) -> Result<u8, Self::Error> {
+        let (byte, rest) = self.split_first().ok_or(UnexpectedEnd)?;
+        *self = rest;
+        Ok(*byte)
+    }
+
+    #[inline]
+    fn peek(&self) -> &'de [u8] {
+        self
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::Decodable;
+
+    #[test]
+    fn assert_that_decoding_something_from_an_empty_slice_always_fails() {
+        let mut decoder = &[] as &[u8];
+        assert_eq!(u8::decode(&mut decoder), Err(UnexpectedEnd));
+    }
+
+    #[test]
+    fn assert_that_slices_can_be_used_as_decoders() {
+        let mut decoder = b"hello" as &[u8];
+
+        let result = <[u8; 4]>::decode(&mut decoder).unwrap();
+
+        assert_eq!(&result, b"hell");
+        assert_eq!(
+            decoder, b"o",
+            "The decoder should only contain the unread bytes"
+        );
+    }
+
+    #[test]
+    fn assert_that_peek_does_not_consume_bytes() {
+        let mut decoder = b"hi" as &[u8];
+        assert_eq!(decoder.peek(), b"hi");
+        assert_eq!(decoder.take_byte(), Ok(b'h'));
+        assert_eq!(decoder.peek(), b"i");
+    }
+}
@@ -10,7 +10,7 @@ impl<E: ByteEncoder, const N: usize> Encodable<E> for ArrayVec<u8, N> {
 
     #[inline]
     fn encode(&self, encoder: &mut E) -> Result<(), Self::Error> {
-        let slice: &[u8] = &*self;
+        let slice: &[u8] = self;
         slice.encode(encoder)
     }
 }
@@ -20,7 +20,7 @@ impl<E: StrEncoder, const N: usize> Encodable<E> for ArrayString<N> {
 
     #[inline]
     fn encode(&self, encoder: &mut E) -> Result<(), Self::Error> {
-        let slice: &str = &*self;
+        let slice: &str = self;
         slice.encode(encoder)
     }
 }
@@ -9,7 +9,7 @@ impl<E: ByteEncoder> Encodable<E> for Bytes {
 
     #[inline]
     fn encode(&self, encoder: &mut E) -> Result<(), Self::Error> {
-        let slice: &[u8] = &*self;
+        let slice: &[u8] = self;
         slice.encode(encoder)
     }
 }
@@ -19,7 +19,7 @@ impl<E: ByteEncoder> Encodable<E> for BytesMut {
 
     #[inline]
     fn encode(&self, encoder: &mut E) -> Result<(), Self::Error> {
-        let slice: &[u8] = &*self;
+        let slice: &[u8] = self;
         slice.encode(encoder)
     }
 }
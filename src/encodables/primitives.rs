@@ -2,21 +2,26 @@ use crate::ByteEncoder;
 use crate::Encodable;
 use crate::StrEncoder;
 
-impl<E: ByteEncoder> Encodable<E> for [bool; 8] {
-    type Error = E::Error;
-
-    #[inline]
-    fn encode(&self, encoder: &mut E) -> Result<(), Self::Error> {
-        let byte = self
-            .into_iter()
-            .rev()
-            .enumerate()
-            .filter_map(|(i, v)| v.then_some(1 << i))
-            .fold(0u8, core::ops::BitOr::bitor);
-        byte.encode(encoder)
-    }
+macro_rules! impl_encodable_for_flags {
+    ($($N:literal => $T:ty)*) => {
+        $(
+            impl<E: ByteEncoder> Encodable<E> for [bool; $N] {
+                type Error = E::Error;
+
+                #[inline]
+                fn encode(&self, encoder: &mut E) -> Result<(), Self::Error> {
+                    let flags = self
+                        .iter()
+                        .fold(0, |flags: $T, &flag| (flags << 1) | <$T>::from(flag));
+                    flags.to_be_bytes().encode(encoder)
+                }
+            }
+        )*
+    };
 }
 
+impl_encodable_for_flags!(8 => u8 16 => u16 32 => u32 64 => u64);
+
 impl<E: StrEncoder> Encodable<E> for char {
     type Error = E::Error;
 
@@ -81,7 +86,7 @@ mod test {
 
     #[test]
     fn assert_that_a_bool_array_can_be_encoded_as_bit_flags() {
-        let expected = [0b11010001];
+        let expected = [0b1101_0001];
         let encodable = [true, true, false, true, false, false, false, true];
 
         let mut buf = [0u8; BUF_SIZE];
@@ -93,6 +98,24 @@ mod test {
         assert_eq!(expected, result);
     }
 
+    #[test]
+    fn assert_that_wide_bool_arrays_are_encoded_in_big_endian_order() {
+        let mut encodable = [false; 32];
+        encodable[0] = true;
+        encodable[9] = true;
+        encodable[31] = true;
+
+        let mut buf = [0u8; BUF_SIZE];
+        let mut encoder = &mut buf as &mut [u8];
+        encodable.encode(&mut encoder).unwrap();
+        [true; 16].encode(&mut encoder).unwrap();
+        [true; 64].encode(&mut encoder).unwrap();
+        let written = BUF_SIZE - encoder.len();
+
+        assert_eq!(&buf[..4], &[0b1000_0000, 0b0100_0000, 0, 0b0000_0001]);
+        assert_eq!(&buf[4..written], &[0xFF; 10]);
+    }
+
     #[test]
     fn assert_that_chars_can_be_encoded() {
         let expected = b"a";
@@ -1,5 +1,9 @@
+use core::ops::Range;
+
+use super::PatchError;
 use crate::BaseEncoder;
 use crate::ByteEncoder;
+use crate::PatchableEncoder;
 use crate::StrEncoder;
 use alloc::string::String;
 use alloc::vec::Vec;
@@ -22,6 +26,35 @@ impl ByteEncoder for Vec<u8> {
     }
 }
 
+impl PatchableEncoder for Vec<u8> {
+    type Placeholder = Range<usize>;
+
+    #[inline]
+    fn reserve_placeholder(&mut self, len: usize) -> Result<Self::Placeholder, Self::Error> {
+        let start = self.len();
+        self.resize(start + len, 0);
+        Ok(start..self.len())
+    }
+
+    #[inline]
+    fn written_since(&self, placeholder: &Self::Placeholder) -> usize {
+        self.len() - placeholder.end
+    }
+
+    #[inline]
+    fn patch(
+        &mut self,
+        placeholder: Self::Placeholder,
+        bytes: &[u8],
+    ) -> Result<(), PatchError<Self::Error>> {
+        self.get_mut(placeholder)
+            .filter(|reserved| reserved.len() == bytes.len())
+            .ok_or(PatchError::SizeMismatch)?
+            .copy_from_slice(bytes);
+        Ok(())
+    }
+}
+
 impl BaseEncoder for String {
     type Error = core::convert::Infallible;
 }
@@ -56,6 +89,33 @@ mod test {
         assert_eq!(buf, [0xABu8]);
     }
 
+    #[test]
+    fn assert_that_vec_patches_of_the_wrong_size_are_rejected() {
+        let mut buf = Vec::new();
+        let placeholder = buf.reserve_placeholder(2).unwrap();
+        assert_eq!(
+            buf.patch(placeholder.clone(), &[5]),
+            Err(PatchError::SizeMismatch)
+        );
+        assert_eq!(
+            buf.patch(placeholder, &[0, 0, 5]),
+            Err(PatchError::SizeMismatch)
+        );
+        assert_eq!(buf, [0, 0]);
+    }
+
+    #[test]
+    fn assert_that_vec_reserved_bytes_can_be_patched() {
+        let mut buf = Vec::new();
+        buf.put_byte(0xAB).unwrap();
+        let placeholder = buf.reserve_placeholder(2).unwrap();
+        b"hello".encode(&mut buf).unwrap();
+
+        assert_eq!(buf.written_since(&placeholder), 5);
+        buf.patch(placeholder, b"\x00\x05").unwrap();
+        assert_eq!(buf, b"\xAB\x00\x05hello");
+    }
+
     #[test]
     fn assert_that_string_grows() {
         let mut buf = String::with_capacity(1);
@@ -0,0 +1,286 @@
+use crate::BaseEncoder;
+use crate::ByteEncoder;
+use crate::StrEncoder;
+
+/// The alphabet used by a [`Base32Encoder`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub enum Base32Alphabet {
+    /// The alphabet of [RFC 4648](https://www.rfc-editor.org/rfc/rfc4648#section-6),
+    /// using `A` to `Z` and `2` to `7`. This is the alphabet of TOTP secrets.
+    #[default]
+    Rfc4648,
+    /// The alphabet of RFC 4648 in lowercase, as used by Tor onion addresses.
+    Rfc4648Lowercase,
+    /// The alphabet of [Crockford's Base32](https://www.crockford.com/base32.html),
+    /// which leaves out `I`, `L`, `O` and `U`. It has no padding.
+    Crockford,
+}
+
+impl Base32Alphabet {
+    /// Returns the 32 characters of the alphabet.
+    #[inline]
+    const fn chars(self) -> &'static [u8; 32] {
+        match self {
+            Self::Rfc4648 => b"ABCDEFGHIJKLMNOPQRSTUVWXYZ234567",
+            Self::Rfc4648Lowercase => b"abcdefghijklmnopqrstuvwxyz234567",
+            Self::Crockford => b"0123456789ABCDEFGHJKMNPQRSTVWXYZ",
+        }
+    }
+}
+
+/// An encoder that writes the bytes written through it as Base32 text into a
+/// [`StrEncoder`].
+///
+/// Every group of five bytes becomes eight characters of the
+/// [`Base32Alphabet`]. Up to four bytes of an incomplete group are kept
+/// inside the encoder, so [`finish`](Base32Encoder::finish) must be called to
+/// write them, followed by `=` padding unless it is disabled or the alphabet
+/// is [`Crockford`](Base32Alphabet::Crockford). Nothing is allocated.
+///
+/// # Example
+///
+/// ```
+/// # #[cfg(feature = "alloc")] {
+/// use encode::Encodable;
+/// use encode::encoders::Base32Encoder;
+///
+/// let mut text = String::new();
+/// let mut encoder = Base32Encoder::new(&mut text);
+/// "foobar".encode(&mut encoder).unwrap();
+/// encoder.finish().unwrap();
+/// assert_eq!(text, "MZXW6YTBOI======");
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct Base32Encoder<'a, E> {
+    encoder: &'a mut E,
+    alphabet: Base32Alphabet,
+    padding: bool,
+    pending: [u8; 4],
+    len: usize,
+}
+
+impl<'a, E> Base32Encoder<'a, E> {
+    /// Creates a new [`Base32Encoder`] that uses the RFC 4648 alphabet with
+    /// padding.
+    #[inline]
+    #[must_use]
+    pub fn new(encoder: &'a mut E) -> Self {
+        Self {
+            encoder,
+            alphabet: Base32Alphabet::Rfc4648,
+            padding: true,
+            pending: [0; 4],
+            len: 0,
+        }
+    }
+    /// Sets the alphabet used by the encoder.
+    #[inline]
+    #[must_use]
+    pub const fn with_alphabet(mut self, alphabet: Base32Alphabet) -> Self {
+        self.alphabet = alphabet;
+        self
+    }
+    /// Sets whether an incomplete group is padded with `=`.
+    ///
+    /// The [`Crockford`](Base32Alphabet::Crockford) alphabet is never padded.
+    #[inline]
+    #[must_use]
+    pub const fn with_padding(mut self, padding: bool) -> Self {
+        self.padding = padding;
+        self
+    }
+    /// Returns a reference to the inner encoder.
+    ///
+    /// The bytes of an incomplete group have not been written into it yet.
+    #[inline]
+    #[must_use]
+    pub fn get_ref(&self) -> &E {
+        self.encoder
+    }
+    /// Encodes a group of up to five bytes into `out`, returning the number
+    /// of characters without padding.
+    #[inline]
+    fn encode_group(&self, group: &[u8], out: &mut [u8]) -> usize {
+        let chars = self.alphabet.chars();
+        let mut bytes = [0u8; 8];
+        bytes[3..3 + group.len()].copy_from_slice(group);
+        let n = u64::from_be_bytes(bytes);
+        let used = (group.len() * 8).div_ceil(5);
+        for (i, c) in out.iter_mut().take(used).enumerate() {
+            // The index is masked to 5 bits, so nothing is truncated.
+            #[allow(clippy::cast_possible_truncation)]
+            let index = (n >> (35 - 5 * i)) as usize & 0x1F;
+            *c = chars[index];
+        }
+        used
+    }
+}
+
+impl<'a, E: StrEncoder> Base32Encoder<'a, E> {
+    /// Writes the characters in `buf` into the inner encoder.
+    #[inline]
+    fn put_chars(&mut self, buf: &[u8]) -> Result<(), E::Error> {
+        // Base32 characters are ASCII, so this never fails.
+        self.encoder
+            .put_str(core::str::from_utf8(buf).unwrap_or_default())
+    }
+    /// Writes the incomplete group and its padding, and returns the inner
+    /// encoder.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the inner encoder cannot write the last
+    /// characters.
+    #[inline]
+    pub fn finish(mut self) -> Result<&'a mut E, E::Error> {
+        if self.len > 0 {
+            let mut out = [b'='; 8];
+            let pending = self.pending;
+            let used = self.encode_group(&pending[..self.len], &mut out);
+            let padding = self.padding && self.alphabet != Base32Alphabet::Crockford;
+            let end = if padding { 8 } else { used };
+            self.put_chars(&out[..end])?;
+        }
+        Ok(self.encoder)
+    }
+}
+
+impl<E: BaseEncoder> BaseEncoder for Base32Encoder<'_, E> {
+    type Error = E::Error;
+}
+
+impl<E: StrEncoder> ByteEncoder for Base32Encoder<'_, E> {
+    #[inline]
+    fn put_slice(&mut self, mut slice: &[u8]) -> Result<(), Self::Error> {
+        if self.len > 0 {
+            let missing = (5 - self.len).min(slice.len());
+            let mut group = [0u8; 5];
+            group[..self.len].copy_from_slice(&self.pending[..self.len]);
+            group[self.len..self.len + missing].copy_from_slice(&slice[..missing]);
+            slice = &slice[missing..];
+            if self.len + missing < 5 {
+                self.pending.copy_from_slice(&group[..4]);
+                self.len += missing;
+                return Ok(());
+            }
+            let mut out = [0u8; 8];
+            self.encode_group(&group, &mut out);
+            self.put_chars(&out)?;
+            self.len = 0;
+        }
+        let mut buf = [0u8; 128];
+        let full = slice.len() - slice.len() % 5;
+        for chunk in slice[..full].chunks(buf.len() / 8 * 5) {
+            for (out, group) in buf.chunks_exact_mut(8).zip(chunk.chunks_exact(5)) {
+                self.encode_group(group, out);
+            }
+            let end = chunk.len() / 5 * 8;
+            self.put_chars(&buf[..end])?;
+        }
+        let rest = &slice[full..];
+        self.pending[..rest.len()].copy_from_slice(rest);
+        self.len = rest.len();
+        Ok(())
+    }
+
+    #[inline]
+    fn put_byte(&mut self, byte: u8) -> Result<(), Self::Error> {
+        self.put_slice(&[byte])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+    use crate::encoders::InsufficientSpace;
+    use crate::Encodable;
+
+    const BUF_SIZE: usize = 512;
+
+    fn base32<T: AsRef<[u8]>>(
+        data: &[T],
+        alphabet: Base32Alphabet,
+        padding: bool,
+        buf: &mut [u8],
+    ) -> usize {
+        let size = buf.len();
+        let mut encoder = buf;
+        let mut base32 = Base32Encoder::new(&mut encoder)
+            .with_alphabet(alphabet)
+            .with_padding(padding);
+        for part in data {
+            part.as_ref().encode(&mut base32).unwrap();
+        }
+        base32.finish().unwrap();
+        size - encoder.len()
+    }
+
+    // The test vectors from https://www.rfc-editor.org/rfc/rfc4648#section-10
+    #[rstest]
+    #[case::empty(b"", b"")]
+    #[case::one(b"f", b"MY======")]
+    #[case::two(b"fo", b"MZXQ====")]
+    #[case::three(b"foo", b"MZXW6===")]
+    #[case::four(b"foob", b"MZXW6YQ=")]
+    #[case::five(b"fooba", b"MZXW6YTB")]
+    #[case::six(b"foobar", b"MZXW6YTBOI======")]
+    fn assert_that_rfc_vectors_are_encoded(#[case] data: &[u8], #[case] expected: &[u8]) {
+        let mut buf = [0u8; BUF_SIZE];
+        let written = base32(&[data], Base32Alphabet::Rfc4648, true, &mut buf);
+        assert_eq!(&buf[..written], expected);
+
+        let padding = expected.iter().rev().take_while(|&&c| c == b'=').count();
+        let written = base32(&[data], Base32Alphabet::Rfc4648, false, &mut buf);
+        assert_eq!(&buf[..written], &expected[..expected.len() - padding]);
+
+        let written = base32(&[data], Base32Alphabet::Rfc4648Lowercase, true, &mut buf);
+        assert!(buf[..written]
+            .iter()
+            .copied()
+            .eq(expected.iter().map(u8::to_ascii_lowercase)));
+    }
+
+    #[rstest]
+    #[case::foobar(b"foobar", b"CSQPYRK1E8")]
+    #[case::all_ones(b"\x7F\x7F\x7F\x7F\x7F", b"FXZQYZVZ")]
+    fn assert_that_crockford_is_never_padded(#[case] data: &[u8], #[case] expected: &[u8]) {
+        let mut buf = [0u8; BUF_SIZE];
+        let written = base32(&[data], Base32Alphabet::Crockford, true, &mut buf);
+        assert_eq!(&buf[..written], expected);
+    }
+
+    #[rstest]
+    #[case::single_bytes(&["f", "o", "o", "b", "a", "r"])]
+    #[case::across_groups(&["foo", "bar"])]
+    #[case::partial_then_long(&["f", "oobar"])]
+    #[case::empty_parts(&["", "fooba", "", "r", ""])]
+    fn assert_that_split_writes_are_grouped(#[case] parts: &[&str]) {
+        let mut buf = [0u8; BUF_SIZE];
+        let written = base32(parts, Base32Alphabet::Rfc4648, true, &mut buf);
+        assert_eq!(&buf[..written], b"MZXW6YTBOI======");
+    }
+
+    #[test]
+    fn assert_that_long_slices_are_written_in_chunks() {
+        let data = [0u8; 201];
+        let mut buf = [0u8; BUF_SIZE];
+        let written = base32(&[data], Base32Alphabet::Rfc4648, true, &mut buf);
+
+        assert_eq!(written, 328);
+        assert!(buf[..written - 6].iter().all(|&c| c == b'A'));
+        assert_eq!(&buf[written - 6..written], b"======");
+    }
+
+    #[test]
+    fn assert_that_encoder_errors_are_propagated() {
+        let mut buf = [0u8; 10];
+        let mut encoder = &mut buf as &mut [u8];
+        let mut base32 = Base32Encoder::new(&mut encoder);
+        "fooba".encode(&mut base32).unwrap();
+
+        assert_eq!(base32.put_slice(b"fooba"), Err(InsufficientSpace));
+    }
+}
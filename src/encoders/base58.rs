@@ -0,0 +1,284 @@
+#[cfg(feature = "sha2")]
+use sha2::Digest;
+#[cfg(feature = "sha2")]
+use sha2::Sha256;
+
+use super::Base58Error;
+use crate::BaseEncoder;
+use crate::ByteEncoder;
+use crate::StrEncoder;
+
+/// The Bitcoin Base58 alphabet.
+const ALPHABET: &[u8; 58] = b"123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
+
+/// An encoder that writes the bytes written through it as
+/// [Base58](https://en.wikipedia.org/wiki/Binary-to-text_encoding#Base58)
+/// text into a [`StrEncoder`], using the Bitcoin alphabet.
+///
+/// Base58 treats the whole input as a single big number, so no character is
+/// known until the last byte is seen. The encoder keeps the digits of the
+/// number in a buffer of `N` digits, updated as bytes arrive, and writes them
+/// when [`finish`](Base58Encoder::finish) is called. Each leading zero byte
+/// is written as a `1` and takes no space in the buffer. Writing more bytes
+/// than the buffer can represent fails with
+/// [`Base58Error::CapacityExceeded`].
+/// The default of 128 digits fits at least 93 bytes of input, and
+/// [`with_capacity`](Base58Encoder::with_capacity) sets a different size.
+///
+/// With [`with_check`](Base58Encoder::with_check), the first four bytes of
+/// the double SHA-256 hash of the input are appended before encoding, as in
+/// `Base58Check`. This requires the `sha2` feature.
+///
+/// # Example
+///
+/// ```
+/// # #[cfg(feature = "alloc")] {
+/// use encode::Encodable;
+/// use encode::encoders::Base58Encoder;
+///
+/// let mut text = String::new();
+/// let mut encoder = Base58Encoder::new(&mut text);
+/// "Hello World!".encode(&mut encoder).unwrap();
+/// encoder.finish().unwrap();
+/// assert_eq!(text, "2NEpo7TZRRrLZSi2U");
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct Base58Encoder<'a, E, const N: usize = 128> {
+    encoder: &'a mut E,
+    digits: [u8; N],
+    len: usize,
+    zeros: usize,
+    #[cfg(feature = "sha2")]
+    check: Option<Sha256>,
+}
+
+impl<'a, E> Base58Encoder<'a, E> {
+    /// Creates a new [`Base58Encoder`] with a buffer of 128 digits.
+    #[inline]
+    #[must_use]
+    pub fn new(encoder: &'a mut E) -> Self {
+        Self::with_capacity(encoder)
+    }
+}
+
+impl<'a, E, const N: usize> Base58Encoder<'a, E, N> {
+    /// Creates a new [`Base58Encoder`] with a buffer of `N` digits.
+    #[inline]
+    #[must_use]
+    pub fn with_capacity(encoder: &'a mut E) -> Self {
+        Self {
+            encoder,
+            digits: [0; N],
+            len: 0,
+            zeros: 0,
+            #[cfg(feature = "sha2")]
+            check: None,
+        }
+    }
+    /// Makes the encoder append the `Base58Check` checksum to the input.
+    #[cfg(feature = "sha2")]
+    #[inline]
+    #[must_use]
+    pub fn with_check(mut self) -> Self {
+        self.check = Some(Sha256::new());
+        self
+    }
+    /// Returns a reference to the inner encoder.
+    ///
+    /// Nothing is written into it until the encoder is finished.
+    #[inline]
+    #[must_use]
+    pub fn get_ref(&self) -> &E {
+        self.encoder
+    }
+    /// Multiplies the number by 256 and adds `byte` to it.
+    #[inline]
+    fn push(&mut self, byte: u8) -> Result<(), Base58Error<()>> {
+        if self.len == 0 && byte == 0 {
+            self.zeros += 1;
+            return Ok(());
+        }
+        let mut carry = u32::from(byte);
+        for digit in &mut self.digits[..self.len] {
+            carry += u32::from(*digit) << 8;
+            // The remainder of a division by 58 always fits into a byte.
+            #[allow(clippy::cast_possible_truncation)]
+            let rem = (carry % 58) as u8;
+            *digit = rem;
+            carry /= 58;
+        }
+        while carry > 0 {
+            let digit = self
+                .digits
+                .get_mut(self.len)
+                .ok_or(Base58Error::CapacityExceeded)?;
+            #[allow(clippy::cast_possible_truncation)]
+            let rem = (carry % 58) as u8;
+            *digit = rem;
+            self.len += 1;
+            carry /= 58;
+        }
+        Ok(())
+    }
+    /// Adds the bytes to the number, updating the checksum.
+    #[inline]
+    fn push_slice(&mut self, slice: &[u8]) -> Result<(), Base58Error<()>> {
+        for &byte in slice {
+            self.push(byte)?;
+        }
+        #[cfg(feature = "sha2")]
+        if let Some(check) = &mut self.check {
+            check.update(slice);
+        }
+        Ok(())
+    }
+}
+
+impl<'a, E: StrEncoder, const N: usize> Base58Encoder<'a, E, N> {
+    /// Writes the encoded number and returns the inner encoder.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Base58Error::CapacityExceeded`] if the checksum does not fit
+    /// into the buffer, or an error if the inner encoder cannot write the
+    /// characters.
+    #[inline]
+    // Only appending the `Base58Check` checksum needs to mutate the encoder.
+    #[cfg_attr(not(feature = "sha2"), allow(unused_mut))]
+    pub fn finish(mut self) -> Result<&'a mut E, Base58Error<E::Error>> {
+        #[cfg(feature = "sha2")]
+        if let Some(check) = self.check.take() {
+            let hash = Sha256::digest(check.finalize());
+            self.push_slice(&hash[..4])
+                .map_err(|_| Base58Error::CapacityExceeded)?;
+        }
+        let mut buf = [b'1'; 64];
+        let mut zeros = self.zeros;
+        while zeros > 0 {
+            let n = zeros.min(buf.len());
+            put_ascii(self.encoder, &buf[..n])?;
+            zeros -= n;
+        }
+        // The digits are stored least significant first.
+        for chunk in self.digits[..self.len].rchunks(buf.len()) {
+            for (c, &digit) in buf.iter_mut().zip(chunk.iter().rev()) {
+                *c = ALPHABET[usize::from(digit)];
+            }
+            put_ascii(self.encoder, &buf[..chunk.len()])?;
+        }
+        Ok(self.encoder)
+    }
+}
+
+/// Writes ASCII characters into a [`StrEncoder`].
+#[inline]
+fn put_ascii<E: StrEncoder>(encoder: &mut E, buf: &[u8]) -> Result<(), E::Error> {
+    // Base58 characters are ASCII, so this never fails.
+    encoder.put_str(core::str::from_utf8(buf).unwrap_or_default())
+}
+
+impl<E: BaseEncoder, const N: usize> BaseEncoder for Base58Encoder<'_, E, N> {
+    type Error = Base58Error<E::Error>;
+}
+
+impl<E: StrEncoder, const N: usize> ByteEncoder for Base58Encoder<'_, E, N> {
+    #[inline]
+    fn put_slice(&mut self, slice: &[u8]) -> Result<(), Self::Error> {
+        self.push_slice(slice)
+            .map_err(|_| Base58Error::CapacityExceeded)
+    }
+
+    #[inline]
+    fn put_byte(&mut self, byte: u8) -> Result<(), Self::Error> {
+        self.put_slice(&[byte])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+    use crate::encoders::InsufficientSpace;
+    use crate::Encodable;
+
+    const BUF_SIZE: usize = 256;
+
+    fn base58(data: &[u8], buf: &mut [u8]) -> usize {
+        let size = buf.len();
+        let mut encoder = buf;
+        let mut base58 = Base58Encoder::new(&mut encoder);
+        for &byte in data {
+            byte.encode(&mut base58).unwrap();
+        }
+        base58.finish().unwrap();
+        size - encoder.len()
+    }
+
+    #[rstest]
+    #[case::empty(b"", b"")]
+    #[case::zero(b"\x00", b"1")]
+    #[case::zeros(b"\x00\x00\x00", b"111")]
+    #[case::one(b"\x01", b"2")]
+    #[case::fifty_eight(b"\x3A", b"21")]
+    #[case::hello_world(b"Hello World!", b"2NEpo7TZRRrLZSi2U")]
+    #[case::leading_zeros(b"\x00\x00\x28\x7f\xb4\xcd", b"11233QC4")]
+    #[case::inner_zeros(b"\x01\x00\x00", b"LUw")]
+    #[case::max(b"\xFF\xFF\xFF\xFF", b"7YXq9G")]
+    fn assert_that_bytes_are_written_as_base58(#[case] data: &[u8], #[case] expected: &[u8]) {
+        let mut buf = [0u8; BUF_SIZE];
+        let written = base58(data, &mut buf);
+        assert_eq!(&buf[..written], expected);
+    }
+
+    #[cfg(feature = "sha2")]
+    #[rstest]
+    #[case::empty(b"", b"3QJmnh")]
+    #[case::burn_address(&[0u8; 21], b"1111111111111111111114oLvT2")]
+    #[case::p2pkh(
+        b"\x00\x01\x09\x66\x77\x60\x06\x95\x3D\x55\x67\x43\x9E\x5E\x39\xF8\x6A\x0D\x27\x3B\xEE",
+        b"16UwLL9Risc3QfPqBUvKofHmBQ7wMtjvM"
+    )]
+    fn assert_that_base58check_appends_the_checksum(#[case] data: &[u8], #[case] expected: &[u8]) {
+        let mut buf = [0u8; BUF_SIZE];
+        let mut encoder = &mut buf as &mut [u8];
+        let mut base58 = Base58Encoder::new(&mut encoder).with_check();
+        data.encode(&mut base58).unwrap();
+        base58.finish().unwrap();
+        let written = BUF_SIZE - encoder.len();
+        assert_eq!(&buf[..written], expected);
+    }
+
+    #[test]
+    fn assert_that_the_buffer_limit_is_enforced() {
+        let mut buf = [0u8; BUF_SIZE];
+        let mut encoder = &mut buf as &mut [u8];
+        let mut base58 = Base58Encoder::<_, 4>::with_capacity(&mut encoder);
+
+        assert_eq!(b"\x00\x00\xFF\xFF".encode(&mut base58), Ok(()));
+        assert_eq!(base58.put_byte(0xFF), Err(Base58Error::CapacityExceeded));
+    }
+
+    #[test]
+    fn assert_that_the_default_buffer_fits_93_bytes() {
+        let mut buf = [0u8; BUF_SIZE];
+        let mut encoder = &mut buf as &mut [u8];
+        let mut base58 = Base58Encoder::new(&mut encoder);
+
+        assert_eq!([0xFFu8; 93].encode(&mut base58), Ok(()));
+    }
+
+    #[test]
+    fn assert_that_encoder_errors_are_propagated() {
+        let mut buf = [0u8; 2];
+        let mut encoder = &mut buf as &mut [u8];
+        let mut base58 = Base58Encoder::new(&mut encoder);
+        "abc".encode(&mut base58).unwrap();
+
+        assert_eq!(
+            base58.finish().map(|_| ()),
+            Err(Base58Error::Encoder(InsufficientSpace))
+        );
+    }
+}
@@ -0,0 +1,273 @@
+use crate::BaseEncoder;
+use crate::ByteEncoder;
+use crate::StrEncoder;
+
+/// The alphabet used by a [`Base64Encoder`].
+///
+/// Both alphabets are defined in
+/// [RFC 4648](https://www.rfc-editor.org/rfc/rfc4648).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub enum Base64Alphabet {
+    /// The standard alphabet, using `+` and `/`.
+    #[default]
+    Standard,
+    /// The URL and filename safe alphabet, using `-` and `_`.
+    UrlSafe,
+}
+
+impl Base64Alphabet {
+    /// Returns the 64 characters of the alphabet.
+    #[inline]
+    const fn chars(self) -> &'static [u8; 64] {
+        match self {
+            Self::Standard => b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/",
+            Self::UrlSafe => b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-_",
+        }
+    }
+}
+
+/// An encoder that writes the bytes written through it as Base64 text into a
+/// [`StrEncoder`].
+///
+/// Every group of three bytes becomes four characters of the
+/// [`Base64Alphabet`]. Up to two bytes of an incomplete group are kept inside
+/// the encoder, so [`finish`](Base64Encoder::finish) must be called to write
+/// them, followed by `=` padding unless it is disabled. Nothing is allocated,
+/// so the inner encoder can be a [`String`](alloc::string::String), a
+/// [`Formatter`](core::fmt::Formatter) or any [`ByteEncoder`].
+///
+/// # Example
+///
+/// ```
+/// # #[cfg(feature = "alloc")] {
+/// use encode::Encodable;
+/// use encode::encoders::Base64Encoder;
+///
+/// let mut text = String::new();
+/// let mut encoder = Base64Encoder::new(&mut text);
+/// "hello".encode(&mut encoder).unwrap();
+/// encoder.finish().unwrap();
+/// assert_eq!(text, "aGVsbG8=");
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct Base64Encoder<'a, E> {
+    encoder: &'a mut E,
+    alphabet: Base64Alphabet,
+    padding: bool,
+    pending: [u8; 2],
+    len: usize,
+}
+
+impl<'a, E> Base64Encoder<'a, E> {
+    /// Creates a new [`Base64Encoder`] that uses the standard alphabet with
+    /// padding.
+    #[inline]
+    #[must_use]
+    pub fn new(encoder: &'a mut E) -> Self {
+        Self {
+            encoder,
+            alphabet: Base64Alphabet::Standard,
+            padding: true,
+            pending: [0; 2],
+            len: 0,
+        }
+    }
+    /// Sets the alphabet used by the encoder.
+    #[inline]
+    #[must_use]
+    pub const fn with_alphabet(mut self, alphabet: Base64Alphabet) -> Self {
+        self.alphabet = alphabet;
+        self
+    }
+    /// Sets whether an incomplete group is padded with `=`.
+    #[inline]
+    #[must_use]
+    pub const fn with_padding(mut self, padding: bool) -> Self {
+        self.padding = padding;
+        self
+    }
+    /// Returns a reference to the inner encoder.
+    ///
+    /// The bytes of an incomplete group have not been written into it yet.
+    #[inline]
+    #[must_use]
+    pub fn get_ref(&self) -> &E {
+        self.encoder
+    }
+    /// Encodes a group of up to three bytes into `out`, returning the number
+    /// of characters without padding.
+    #[inline]
+    fn encode_group(&self, group: &[u8], out: &mut [u8]) -> usize {
+        let chars = self.alphabet.chars();
+        let mut bytes = [0u8; 3];
+        bytes[..group.len()].copy_from_slice(group);
+        let n = u32::from_be_bytes([0, bytes[0], bytes[1], bytes[2]]);
+        let used = group.len() + 1;
+        for (i, c) in out.iter_mut().take(used).enumerate() {
+            *c = chars[(n >> (18 - 6 * i)) as usize & 0x3F];
+        }
+        used
+    }
+}
+
+impl<'a, E: StrEncoder> Base64Encoder<'a, E> {
+    /// Writes the characters in `buf` into the inner encoder.
+    #[inline]
+    fn put_chars(&mut self, buf: &[u8]) -> Result<(), E::Error> {
+        // Base64 characters are ASCII, so this never fails.
+        self.encoder
+            .put_str(core::str::from_utf8(buf).unwrap_or_default())
+    }
+    /// Writes the incomplete group and its padding, and returns the inner
+    /// encoder.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the inner encoder cannot write the last
+    /// characters.
+    #[inline]
+    pub fn finish(mut self) -> Result<&'a mut E, E::Error> {
+        if self.len > 0 {
+            let mut out = [b'='; 4];
+            let pending = self.pending;
+            let used = self.encode_group(&pending[..self.len], &mut out);
+            let end = if self.padding { 4 } else { used };
+            self.put_chars(&out[..end])?;
+        }
+        Ok(self.encoder)
+    }
+}
+
+impl<E: BaseEncoder> BaseEncoder for Base64Encoder<'_, E> {
+    type Error = E::Error;
+}
+
+impl<E: StrEncoder> ByteEncoder for Base64Encoder<'_, E> {
+    #[inline]
+    fn put_slice(&mut self, mut slice: &[u8]) -> Result<(), Self::Error> {
+        if self.len > 0 {
+            let missing = (3 - self.len).min(slice.len());
+            let mut group = [0u8; 3];
+            group[..self.len].copy_from_slice(&self.pending[..self.len]);
+            group[self.len..self.len + missing].copy_from_slice(&slice[..missing]);
+            slice = &slice[missing..];
+            if self.len + missing < 3 {
+                self.pending = [group[0], group[1]];
+                self.len += missing;
+                return Ok(());
+            }
+            let mut out = [0u8; 4];
+            self.encode_group(&group, &mut out);
+            self.put_chars(&out)?;
+            self.len = 0;
+        }
+        let mut buf = [0u8; 128];
+        let full = slice.len() - slice.len() % 3;
+        for chunk in slice[..full].chunks(buf.len() / 4 * 3) {
+            for (out, group) in buf.chunks_exact_mut(4).zip(chunk.chunks_exact(3)) {
+                self.encode_group(group, out);
+            }
+            let end = chunk.len() / 3 * 4;
+            self.put_chars(&buf[..end])?;
+        }
+        let rest = &slice[full..];
+        self.pending[..rest.len()].copy_from_slice(rest);
+        self.len = rest.len();
+        Ok(())
+    }
+
+    #[inline]
+    fn put_byte(&mut self, byte: u8) -> Result<(), Self::Error> {
+        self.put_slice(&[byte])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+    use crate::encoders::InsufficientSpace;
+    use crate::Encodable;
+
+    const BUF_SIZE: usize = 512;
+
+    fn base64<T: AsRef<[u8]>>(
+        data: &[T],
+        alphabet: Base64Alphabet,
+        padding: bool,
+        buf: &mut [u8],
+    ) -> usize {
+        let size = buf.len();
+        let mut encoder = buf;
+        let mut base64 = Base64Encoder::new(&mut encoder)
+            .with_alphabet(alphabet)
+            .with_padding(padding);
+        for part in data {
+            part.as_ref().encode(&mut base64).unwrap();
+        }
+        base64.finish().unwrap();
+        size - encoder.len()
+    }
+
+    // The test vectors from https://www.rfc-editor.org/rfc/rfc4648#section-10
+    #[rstest]
+    #[case::empty(b"", b"")]
+    #[case::one(b"f", b"Zg==")]
+    #[case::two(b"fo", b"Zm8=")]
+    #[case::three(b"foo", b"Zm9v")]
+    #[case::four(b"foob", b"Zm9vYg==")]
+    #[case::five(b"fooba", b"Zm9vYmE=")]
+    #[case::six(b"foobar", b"Zm9vYmFy")]
+    fn assert_that_rfc_vectors_are_encoded(#[case] data: &[u8], #[case] expected: &[u8]) {
+        let mut buf = [0u8; BUF_SIZE];
+        let written = base64(&[data], Base64Alphabet::Standard, true, &mut buf);
+        assert_eq!(&buf[..written], expected);
+
+        let padding = expected.iter().rev().take_while(|&&c| c == b'=').count();
+        let written = base64(&[data], Base64Alphabet::Standard, false, &mut buf);
+        assert_eq!(&buf[..written], &expected[..expected.len() - padding]);
+    }
+
+    #[rstest]
+    #[case::standard(Base64Alphabet::Standard, b"+/+/")]
+    #[case::url_safe(Base64Alphabet::UrlSafe, b"-_-_")]
+    fn assert_that_the_alphabet_is_used(#[case] alphabet: Base64Alphabet, #[case] expected: &[u8]) {
+        let mut buf = [0u8; BUF_SIZE];
+        let written = base64(&[b"\xFB\xFF\xBF"], alphabet, true, &mut buf);
+        assert_eq!(&buf[..written], expected);
+    }
+
+    #[rstest]
+    #[case::single_bytes(&["f", "o", "o", "b", "a"])]
+    #[case::across_groups(&["fo", "ob", "a"])]
+    #[case::partial_then_long(&["f", "ooba"])]
+    #[case::empty_parts(&["", "foo", "", "ba", ""])]
+    fn assert_that_split_writes_are_grouped(#[case] parts: &[&str]) {
+        let mut buf = [0u8; BUF_SIZE];
+        let written = base64(parts, Base64Alphabet::Standard, true, &mut buf);
+        assert_eq!(&buf[..written], b"Zm9vYmE=");
+    }
+
+    #[test]
+    fn assert_that_long_slices_are_written_in_chunks() {
+        let data = [0xFFu8; 200];
+        let mut buf = [0u8; BUF_SIZE];
+        let written = base64(&[&data], Base64Alphabet::Standard, true, &mut buf);
+
+        assert_eq!(written, 268);
+        assert!(buf[..264].iter().all(|&c| c == b'/'));
+        assert_eq!(&buf[264..written], b"//8=");
+    }
+
+    #[test]
+    fn assert_that_encoder_errors_are_propagated() {
+        let mut buf = [0u8; 10];
+        let mut encoder = &mut buf as &mut [u8];
+        let mut base64 = Base64Encoder::new(&mut encoder);
+        "foobar".encode(&mut base64).unwrap();
+
+        assert_eq!(base64.put_slice(b"foo"), Err(InsufficientSpace));
+    }
+}
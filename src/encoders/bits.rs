@@ -0,0 +1,391 @@
+use core::marker::PhantomData;
+
+use crate::BaseEncoder;
+use crate::BitLevelEncoder;
+use crate::ByteEncoder;
+
+/// Defines the order in which bits are packed into bytes by a [`BitEncoder`].
+///
+/// See [`Msb0`] and [`Lsb0`].
+pub trait BitOrder {
+    /// Returns the next `take` bits of an `n` bit wide `value`, after
+    /// `written` of its bits have already been written.
+    ///
+    /// Bits past the 64th are zero.
+    fn next_bits(value: u64, n: u32, written: u32, take: u32) -> u8;
+    /// Returns how far `take` bits must be shifted left to be placed in a byte
+    /// that already holds `filled` bits.
+    fn shift(filled: u32, take: u32) -> u32;
+}
+
+/// Packs bits starting from the most significant bit of each byte, writing
+/// the most significant bits of each value first.
+///
+/// This is the order used by most network protocols and media formats, such
+/// as MPEG-TS or H.264.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Msb0;
+
+/// Packs bits starting from the least significant bit of each byte, writing
+/// the least significant bits of each value first.
+///
+/// This is the order used by formats such as DEFLATE.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Lsb0;
+
+/// Returns the `take` least significant bits of `value`.
+#[inline]
+#[allow(clippy::cast_possible_truncation)]
+const fn low_bits(value: u64, take: u32) -> u8 {
+    value as u8 & (u8::MAX >> (8 - take))
+}
+
+impl BitOrder for Msb0 {
+    #[inline]
+    fn next_bits(value: u64, n: u32, written: u32, take: u32) -> u8 {
+        low_bits(value.checked_shr(n - written - take).unwrap_or(0), take)
+    }
+
+    #[inline]
+    fn shift(filled: u32, take: u32) -> u32 {
+        8 - filled - take
+    }
+}
+
+impl BitOrder for Lsb0 {
+    #[inline]
+    fn next_bits(value: u64, _n: u32, written: u32, take: u32) -> u8 {
+        low_bits(value.checked_shr(written).unwrap_or(0), take)
+    }
+
+    #[inline]
+    fn shift(filled: u32, _take: u32) -> u32 {
+        filled
+    }
+}
+
+/// An encoder that packs individual bits into bytes before writing them into
+/// another [`ByteEncoder`].
+///
+/// Bits are buffered until a whole byte has been filled. The last, partially
+/// filled byte is only written by [`align`](Self::align) or
+/// [`finish`](Self::finish), which pad it with zero bits. Dropping a
+/// [`BitEncoder`] with [`pending_bits`](Self::pending_bits) discards them.
+///
+/// [`BitEncoder`] is also a [`ByteEncoder`], so any byte encodable can be
+/// written at any bit offset. Slices written while the encoder is aligned are
+/// passed through to the inner encoder as-is.
+///
+/// # Example
+///
+/// ```
+/// use encode::{BitLevelEncoder, Encodable};
+/// use encode::combinators::BE;
+/// use encode::encoders::BitEncoder;
+///
+/// let mut buf = [0u8; 4];
+/// let mut encoder = BitEncoder::new(&mut buf as &mut [u8]);
+/// encoder.put_bits(0b101, 3).unwrap();
+/// BE::new(0xFFFFu16).encode(&mut encoder).unwrap();
+/// encoder.finish().unwrap();
+/// assert_eq!(buf, [0b1011_1111, 0xFF, 0b1110_0000, 0]);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct BitEncoder<E, O = Msb0> {
+    encoder: E,
+    byte: u8,
+    filled: u32,
+    order: PhantomData<O>,
+}
+
+impl<E> BitEncoder<E> {
+    /// Creates a new [`BitEncoder`] that packs bits in [`Msb0`] order.
+    #[inline]
+    #[must_use]
+    pub const fn new(encoder: E) -> Self {
+        Self::with_order(encoder)
+    }
+}
+
+impl<E, O> BitEncoder<E, O> {
+    /// Creates a new [`BitEncoder`] that packs bits in the given [`BitOrder`].
+    ///
+    /// ```
+    /// use encode::BitLevelEncoder;
+    /// use encode::encoders::{BitEncoder, Lsb0};
+    ///
+    /// let mut buf = [0u8; 1];
+    /// let mut encoder = BitEncoder::<_, Lsb0>::with_order(&mut buf as &mut [u8]);
+    /// encoder.put_bits(0b101, 3).unwrap();
+    /// encoder.finish().unwrap();
+    /// assert_eq!(buf, [0b0000_0101]);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn with_order(encoder: E) -> Self {
+        Self {
+            encoder,
+            byte: 0,
+            filled: 0,
+            order: PhantomData,
+        }
+    }
+    /// Returns a reference to the inner encoder.
+    #[inline]
+    #[must_use]
+    pub const fn get_ref(&self) -> &E {
+        &self.encoder
+    }
+    /// Returns the number of bits that have been written but not yet passed
+    /// on to the inner encoder.
+    #[inline]
+    #[must_use]
+    pub const fn pending_bits(&self) -> u32 {
+        self.filled
+    }
+    /// Returns `true` if the encoder is at a byte boundary.
+    #[inline]
+    #[must_use]
+    pub const fn is_aligned(&self) -> bool {
+        self.filled == 0
+    }
+}
+
+impl<E: ByteEncoder, O> BitEncoder<E, O> {
+    /// Pads the current byte with zero bits and writes it into the inner
+    /// encoder.
+    ///
+    /// Does nothing if the encoder is already at a byte boundary.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the inner encoder cannot write the byte.
+    #[inline]
+    pub fn align(&mut self) -> Result<(), E::Error> {
+        if self.is_aligned() {
+            return Ok(());
+        }
+        let byte = core::mem::take(&mut self.byte);
+        self.filled = 0;
+        self.encoder.put_byte(byte)
+    }
+    /// Aligns the encoder and returns the inner encoder.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the inner encoder cannot write the last byte.
+    #[inline]
+    pub fn finish(mut self) -> Result<E, E::Error> {
+        self.align()?;
+        Ok(self.encoder)
+    }
+}
+
+impl<E: BaseEncoder, O> BaseEncoder for BitEncoder<E, O> {
+    type Error = E::Error;
+}
+
+impl<E: ByteEncoder, O: BitOrder> BitLevelEncoder for BitEncoder<E, O> {
+    #[inline]
+    fn put_bits(&mut self, value: u64, n: u32) -> Result<(), Self::Error> {
+        let mut written = 0;
+        while written < n {
+            let take = (8 - self.filled).min(n - written);
+            self.byte |= O::next_bits(value, n, written, take) << O::shift(self.filled, take);
+            self.filled += take;
+            written += take;
+            if self.filled == 8 {
+                self.align()?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<E: ByteEncoder, O: BitOrder> ByteEncoder for BitEncoder<E, O> {
+    #[inline]
+    fn put_slice(&mut self, slice: &[u8]) -> Result<(), Self::Error> {
+        if self.is_aligned() {
+            return self.encoder.put_slice(slice);
+        }
+        slice.iter().try_for_each(|&byte| self.put_byte(byte))
+    }
+
+    #[inline]
+    fn put_byte(&mut self, byte: u8) -> Result<(), Self::Error> {
+        if self.is_aligned() {
+            return self.encoder.put_byte(byte);
+        }
+        self.put_bits(u64::from(byte), 8)
+    }
+}
+
+/// An encoder that counts the number of bits of the encoded data.
+///
+/// This is the [`SizeEncoder`](super::SizeEncoder) equivalent for
+/// [`BitLevelEncoder`]s. Bytes written through its [`ByteEncoder`]
+/// implementation count as 8 bits each.
+///
+/// # Example
+///
+/// ```
+/// use encode::Encodable;
+/// use encode::combinators::Bits;
+/// use encode::encoders::BitSizeEncoder;
+///
+/// let mut encoder = BitSizeEncoder::new();
+/// (Bits::<u8, 3>::new(5), Bits::<u16, 10>::new(7)).encode(&mut encoder).unwrap();
+/// assert_eq!(encoder.bits(), 13);
+/// assert_eq!(encoder.bytes(), 2);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Default)]
+#[repr(transparent)]
+pub struct BitSizeEncoder {
+    bits: u64,
+}
+
+impl BitSizeEncoder {
+    /// Creates a new [`BitSizeEncoder`].
+    #[inline]
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// Returns the number of bits of the encoded data.
+    #[inline]
+    #[must_use]
+    pub const fn bits(&self) -> u64 {
+        self.bits
+    }
+    /// Returns the number of bytes of the encoded data, counting a partially
+    /// filled last byte as a whole byte.
+    #[inline]
+    #[must_use]
+    pub const fn bytes(&self) -> u64 {
+        self.bits.div_ceil(8)
+    }
+}
+
+impl BaseEncoder for BitSizeEncoder {
+    type Error = core::convert::Infallible;
+}
+
+impl BitLevelEncoder for BitSizeEncoder {
+    #[inline]
+    fn put_bits(&mut self, _value: u64, n: u32) -> Result<(), Self::Error> {
+        self.bits += u64::from(n);
+        Ok(())
+    }
+}
+
+impl ByteEncoder for BitSizeEncoder {
+    #[inline]
+    fn put_slice(&mut self, slice: &[u8]) -> Result<(), Self::Error> {
+        self.bits += slice.len() as u64 * 8;
+        Ok(())
+    }
+
+    #[inline]
+    fn put_byte(&mut self, _byte: u8) -> Result<(), Self::Error> {
+        self.bits += 8;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+    use crate::combinators::LE;
+    use crate::encoders::InsufficientSpace;
+    use crate::Encodable;
+
+    const BUF_SIZE: usize = 16;
+
+    fn encode_bits<O: BitOrder>(fields: &[(u64, u32)]) -> ([u8; BUF_SIZE], usize) {
+        let mut buf = [0u8; BUF_SIZE];
+        let mut encoder = BitEncoder::<_, O>::with_order(&mut buf as &mut [u8]);
+        for &(value, n) in fields {
+            encoder.put_bits(value, n).unwrap();
+        }
+        let written = BUF_SIZE - encoder.finish().unwrap().len();
+        (buf, written)
+    }
+
+    #[rstest]
+    #[case::single_byte(&[(0b101, 3), (0b11111, 5)], &[0b1011_1111])]
+    #[case::padded(&[(0b1, 1)], &[0b1000_0000])]
+    #[case::across_bytes(&[(0b1, 1), (0xFF, 8)], &[0xFF, 0b1000_0000])]
+    #[case::only_low_bits(&[(0xFF, 4)], &[0b1111_0000])]
+    #[case::wide(&[(0x0102_0304_0506_0708, 64)], &[1, 2, 3, 4, 5, 6, 7, 8])]
+    #[case::zero_extended(&[(0xFF, 72)], &[0, 0, 0, 0, 0, 0, 0, 0, 0xFF])]
+    #[case::empty(&[(0xFF, 0)], &[])]
+    fn assert_that_msb0_packs_the_most_significant_bit_first(
+        #[case] fields: &[(u64, u32)],
+        #[case] expected: &[u8],
+    ) {
+        let (buf, written) = encode_bits::<Msb0>(fields);
+        assert_eq!(&buf[..written], expected);
+    }
+
+    #[rstest]
+    #[case::single_byte(&[(0b101, 3), (0b11111, 5)], &[0b1111_1101])]
+    #[case::padded(&[(0b1, 1)], &[0b0000_0001])]
+    #[case::across_bytes(&[(0b1, 1), (0xFF, 8)], &[0xFF, 0b0000_0001])]
+    #[case::wide(&[(0x0102_0304_0506_0708, 64)], &[8, 7, 6, 5, 4, 3, 2, 1])]
+    #[case::zero_extended(&[(0xFF, 72)], &[0xFF, 0, 0, 0, 0, 0, 0, 0, 0])]
+    fn assert_that_lsb0_packs_the_least_significant_bit_first(
+        #[case] fields: &[(u64, u32)],
+        #[case] expected: &[u8],
+    ) {
+        let (buf, written) = encode_bits::<Lsb0>(fields);
+        assert_eq!(&buf[..written], expected);
+    }
+
+    #[test]
+    fn assert_that_bytes_can_be_written_at_any_bit_offset() {
+        let mut buf = [0u8; BUF_SIZE];
+        let mut encoder = BitEncoder::new(&mut buf as &mut [u8]);
+        encoder.put_bits(0, 4).unwrap();
+        LE::new(0xABCDu16).encode(&mut encoder).unwrap();
+        assert_eq!(encoder.pending_bits(), 4);
+        encoder.align().unwrap();
+        assert!(encoder.is_aligned());
+        "hi".encode(&mut encoder).unwrap();
+        let written = BUF_SIZE - encoder.finish().unwrap().len();
+
+        assert_eq!(&buf[..written], &[0x0C, 0xDA, 0xB0, b'h', b'i']);
+    }
+
+    #[test]
+    fn assert_that_aligning_an_aligned_encoder_writes_nothing() {
+        let mut buf = [0u8; BUF_SIZE];
+        let mut encoder = BitEncoder::new(&mut buf as &mut [u8]);
+        encoder.align().unwrap();
+        let written = BUF_SIZE - encoder.finish().unwrap().len();
+
+        assert_eq!(written, 0);
+    }
+
+    #[test]
+    fn assert_that_inner_encoder_errors_are_propagated() {
+        let mut buf = [0u8; 1];
+        let mut encoder = BitEncoder::new(&mut buf as &mut [u8]);
+        encoder.put_bits(0, 7).unwrap();
+
+        assert_eq!(encoder.put_bits(0, 2), Ok(()));
+        assert_eq!(encoder.finish(), Err(InsufficientSpace));
+    }
+
+    #[test]
+    fn assert_that_bit_size_encoder_counts_bits_and_bytes() {
+        let mut encoder = BitSizeEncoder::new();
+        encoder.put_bits(0, 3).unwrap();
+        encoder.put_byte(0).unwrap();
+        encoder.put_slice(b"ab").unwrap();
+
+        assert_eq!(encoder.bits(), 27);
+        assert_eq!(encoder.bytes(), 4);
+    }
+}
@@ -1,8 +1,12 @@
+use core::ops::Range;
+
 use bytes::BufMut;
 use bytes::BytesMut;
 
+use super::PatchError;
 use crate::BaseEncoder;
 use crate::ByteEncoder;
+use crate::PatchableEncoder;
 
 impl BaseEncoder for BytesMut {
     type Error = core::convert::Infallible;
@@ -22,9 +26,39 @@ impl ByteEncoder for BytesMut {
     }
 }
 
+impl PatchableEncoder for BytesMut {
+    type Placeholder = Range<usize>;
+
+    #[inline]
+    fn reserve_placeholder(&mut self, len: usize) -> Result<Self::Placeholder, Self::Error> {
+        let start = self.len();
+        BufMut::put_bytes(self, 0, len);
+        Ok(start..self.len())
+    }
+
+    #[inline]
+    fn written_since(&self, placeholder: &Self::Placeholder) -> usize {
+        self.len() - placeholder.end
+    }
+
+    #[inline]
+    fn patch(
+        &mut self,
+        placeholder: Self::Placeholder,
+        bytes: &[u8],
+    ) -> Result<(), PatchError<Self::Error>> {
+        self.get_mut(placeholder)
+            .filter(|reserved| reserved.len() == bytes.len())
+            .ok_or(PatchError::SizeMismatch)?
+            .copy_from_slice(bytes);
+        Ok(())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::Encodable;
+    use crate::PatchableEncoder;
 
     use super::*;
 
@@ -39,4 +73,25 @@ mod tests {
         assert_eq!(bytes.len(), 6, "The buffer should contain 5 bytes");
         assert_eq!(bytes, b"hello\0"[..]);
     }
+
+    #[test]
+    fn assert_that_bytesmut_patches_of_the_wrong_size_are_rejected() {
+        let mut buf = BytesMut::new();
+        let placeholder = buf.reserve_placeholder(1).unwrap();
+        assert_eq!(
+            buf.patch(placeholder, &[0, 5]),
+            Err(PatchError::SizeMismatch)
+        );
+    }
+
+    #[test]
+    fn assert_that_bytesmut_reserved_bytes_can_be_patched() {
+        let mut buf = BytesMut::new();
+        let placeholder = buf.reserve_placeholder(1).unwrap();
+        "hello".encode(&mut buf).unwrap();
+
+        assert_eq!(buf.written_since(&placeholder), 5);
+        buf.patch(placeholder, &[5]).unwrap();
+        assert_eq!(buf.freeze(), b"\x05hello"[..]);
+    }
 }
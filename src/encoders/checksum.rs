@@ -0,0 +1,111 @@
+use crate::checksums::Checksum;
+use crate::BaseEncoder;
+use crate::ByteEncoder;
+
+/// An encoder that computes a [`Checksum`] of the bytes written through it.
+///
+/// Bytes are passed on to the inner encoder, and the checksum is updated with
+/// every byte the inner encoder accepts. Nothing is buffered, so it also works
+/// with encoders that cannot go back, such as
+/// [`IoEncoder`](super::IoEncoder).
+///
+/// # Example
+///
+/// ```
+/// use encode::Encodable;
+/// use encode::checksums::{Checksum, Crc16Ccitt};
+/// use encode::encoders::ChecksumEncoder;
+///
+/// let mut buf = [0u8; 16];
+/// let mut output = &mut buf as &mut [u8];
+/// let mut encoder = ChecksumEncoder::new(&mut output, Crc16Ccitt::new());
+/// "123456789".encode(&mut encoder).unwrap();
+/// assert_eq!(*encoder.checksum().finish(), 0x29B1);
+/// ```
+#[derive(Debug)]
+pub struct ChecksumEncoder<'a, E, C> {
+    encoder: &'a mut E,
+    checksum: C,
+}
+
+impl<'a, E, C> ChecksumEncoder<'a, E, C> {
+    /// Creates a new [`ChecksumEncoder`].
+    #[inline]
+    #[must_use]
+    pub fn new(encoder: &'a mut E, checksum: C) -> Self {
+        Self { encoder, checksum }
+    }
+    /// Returns a reference to the inner encoder.
+    #[inline]
+    #[must_use]
+    pub fn get_ref(&self) -> &E {
+        self.encoder
+    }
+    /// Returns a reference to the checksum of the bytes written so far.
+    #[inline]
+    #[must_use]
+    pub const fn checksum(&self) -> &C {
+        &self.checksum
+    }
+    /// Consumes the encoder and returns the inner encoder and the checksum.
+    #[inline]
+    #[must_use]
+    pub fn into_parts(self) -> (&'a mut E, C) {
+        (self.encoder, self.checksum)
+    }
+}
+
+impl<E: BaseEncoder, C> BaseEncoder for ChecksumEncoder<'_, E, C> {
+    type Error = E::Error;
+}
+
+impl<E: ByteEncoder, C: Checksum> ByteEncoder for ChecksumEncoder<'_, E, C> {
+    #[inline]
+    fn put_slice(&mut self, slice: &[u8]) -> Result<(), Self::Error> {
+        self.encoder.put_slice(slice)?;
+        self.checksum.update(slice);
+        Ok(())
+    }
+
+    #[inline]
+    fn put_byte(&mut self, byte: u8) -> Result<(), Self::Error> {
+        self.encoder.put_byte(byte)?;
+        self.checksum.update(&[byte]);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::checksums::Crc32;
+    use crate::encoders::InsufficientSpace;
+    use crate::Encodable;
+
+    const BUF_SIZE: usize = 16;
+
+    #[test]
+    fn assert_that_bytes_are_passed_through_and_checksummed() {
+        let mut buf = [0u8; BUF_SIZE];
+        let mut output = &mut buf as &mut [u8];
+        let mut encoder = ChecksumEncoder::new(&mut output, Crc32::new());
+        ("1234", b'5', b"6789").encode(&mut encoder).unwrap();
+        let (encoder, checksum) = encoder.into_parts();
+        let written = BUF_SIZE - encoder.len();
+
+        assert_eq!(&buf[..written], b"123456789");
+        assert_eq!(checksum.finish(), Crc32::compute(b"123456789"));
+    }
+
+    #[test]
+    fn assert_that_rejected_bytes_are_not_checksummed() {
+        let mut buf = [0u8; 4];
+        let mut output = &mut buf as &mut [u8];
+        let mut encoder = ChecksumEncoder::new(&mut output, Crc32::new());
+        "1234".encode(&mut encoder).unwrap();
+
+        assert_eq!(encoder.put_slice(b"5"), Err(InsufficientSpace));
+        assert_eq!(encoder.put_byte(b'5'), Err(InsufficientSpace));
+        assert_eq!(encoder.checksum().finish(), Crc32::compute(b"1234"));
+    }
+}
@@ -0,0 +1,209 @@
+use crate::BaseEncoder;
+use crate::ByteEncoder;
+
+/// The largest number of data bytes in a single COBS block.
+const MAX_BLOCK: usize = 254;
+
+/// An encoder that frames the bytes written through it with
+/// [Consistent Overhead Byte Stuffing](https://en.wikipedia.org/wiki/Consistent_Overhead_Byte_Stuffing).
+///
+/// COBS removes every `0x00` byte from the data, so `0x00` can be used as the
+/// frame delimiter. The data is split into blocks of up to 254 non-zero
+/// bytes, and every block is prefixed with its length plus one. A block is
+/// only written once its length is known, so up to 254 bytes are buffered
+/// inside the encoder. Call [`finish`](CobsEncoder::finish) to write the last
+/// block and the trailing `0x00` delimiter.
+///
+/// The overhead is at most one byte for every 254 bytes of data, plus the
+/// delimiter.
+///
+/// # Example
+///
+/// ```
+/// use encode::Encodable;
+/// use encode::encoders::CobsEncoder;
+///
+/// let mut buf = [0u8; 8];
+/// let mut output = &mut buf as &mut [u8];
+/// let mut encoder = CobsEncoder::new(&mut output);
+/// b"\x11\x00\x22\x33".encode(&mut encoder).unwrap();
+/// encoder.finish().unwrap();
+/// assert_eq!(&buf[..6], b"\x02\x11\x03\x22\x33\x00");
+/// ```
+#[derive(Debug)]
+pub struct CobsEncoder<'a, E> {
+    encoder: &'a mut E,
+    block: [u8; MAX_BLOCK],
+    len: usize,
+    after_full_block: bool,
+}
+
+impl<'a, E> CobsEncoder<'a, E> {
+    /// Creates a new [`CobsEncoder`].
+    #[inline]
+    #[must_use]
+    pub fn new(encoder: &'a mut E) -> Self {
+        Self {
+            encoder,
+            block: [0; MAX_BLOCK],
+            len: 0,
+            after_full_block: false,
+        }
+    }
+    /// Returns a reference to the inner encoder.
+    ///
+    /// The bytes of the current block have not been written into it yet.
+    #[inline]
+    #[must_use]
+    pub fn get_ref(&self) -> &E {
+        self.encoder
+    }
+}
+
+impl<'a, E: ByteEncoder> CobsEncoder<'a, E> {
+    /// Writes the current block, prefixed with its length plus one.
+    #[inline]
+    fn flush_block(&mut self) -> Result<(), E::Error> {
+        // The block never holds more than 254 bytes, so the code fits in a byte.
+        #[allow(clippy::cast_possible_truncation)]
+        let code = self.len as u8 + 1;
+        self.encoder.put_byte(code)?;
+        self.encoder.put_slice(&self.block[..self.len])?;
+        self.after_full_block = self.len == MAX_BLOCK;
+        self.len = 0;
+        Ok(())
+    }
+    /// Writes the last block and the `0x00` delimiter, and returns the inner
+    /// encoder.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the inner encoder cannot write the last block or
+    /// the delimiter.
+    #[inline]
+    pub fn finish(mut self) -> Result<&'a mut E, E::Error> {
+        // A full block has no implicit zero after it, so it can end the data
+        // without an empty block following it.
+        if self.len > 0 || !self.after_full_block {
+            self.flush_block()?;
+        }
+        self.encoder.put_byte(0)?;
+        Ok(self.encoder)
+    }
+}
+
+impl<E: BaseEncoder> BaseEncoder for CobsEncoder<'_, E> {
+    type Error = E::Error;
+}
+
+impl<E: ByteEncoder> ByteEncoder for CobsEncoder<'_, E> {
+    #[inline]
+    fn put_slice(&mut self, slice: &[u8]) -> Result<(), Self::Error> {
+        for &byte in slice {
+            self.put_byte(byte)?;
+        }
+        Ok(())
+    }
+
+    #[inline]
+    fn put_byte(&mut self, byte: u8) -> Result<(), Self::Error> {
+        if byte == 0 {
+            return self.flush_block();
+        }
+        self.block[self.len] = byte;
+        self.len += 1;
+        if self.len == MAX_BLOCK {
+            self.flush_block()?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+    use crate::encoders::InsufficientSpace;
+    use crate::Encodable;
+
+    const BUF_SIZE: usize = 1024;
+
+    fn cobs(data: &[u8], mut buf: &mut [u8]) -> usize {
+        let size = buf.len();
+        let mut encoder = CobsEncoder::new(&mut buf);
+        data.encode(&mut encoder).unwrap();
+        size - encoder.finish().unwrap().len()
+    }
+
+    // The examples from https://en.wikipedia.org/wiki/Consistent_Overhead_Byte_Stuffing
+    #[rstest]
+    #[case::empty(b"", b"\x01\x00")]
+    #[case::zero(b"\x00", b"\x01\x01\x00")]
+    #[case::zeros(b"\x00\x00", b"\x01\x01\x01\x00")]
+    #[case::surrounded_zero(b"\x00\x11\x00", b"\x01\x02\x11\x01\x00")]
+    #[case::inner_zero(b"\x11\x22\x00\x33", b"\x03\x11\x22\x02\x33\x00")]
+    #[case::no_zero(b"\x11\x22\x33\x44", b"\x05\x11\x22\x33\x44\x00")]
+    #[case::trailing_zeros(b"\x11\x00\x00\x00", b"\x02\x11\x01\x01\x01\x00")]
+    fn assert_that_zeros_are_stuffed(#[case] data: &[u8], #[case] expected: &[u8]) {
+        let mut buf = [0u8; BUF_SIZE];
+        let written = cobs(data, &mut buf);
+
+        assert_eq!(&buf[..written], expected);
+    }
+
+    #[rstest]
+    // 01 02 ... FE
+    #[case::full_block(1, 254, &[0xFF], &[0x00])]
+    // 00 01 02 ... FE
+    #[case::zero_then_full_block(0, 255, &[0x01, 0xFF], &[0x00])]
+    // 01 02 ... FE FF
+    #[case::full_block_then_byte(1, 255, &[0xFF], &[0x02, 0xFF, 0x00])]
+    // 02 03 ... FF 00
+    #[case::full_block_then_zero(2, 255, &[0xFF], &[0x01, 0x01, 0x00])]
+    // 02 03 ... FF 00 01
+    #[case::full_block_then_zero_and_byte(2, 256, &[0xFF], &[0x01, 0x02, 0x01, 0x00])]
+    fn assert_that_long_runs_are_split_into_blocks(
+        #[case] start: u8,
+        #[case] len: usize,
+        #[case] head: &[u8],
+        #[case] tail: &[u8],
+    ) {
+        #[allow(clippy::cast_possible_truncation)]
+        let data: [u8; 256] = core::array::from_fn(|i| start.wrapping_add(i as u8));
+        let data = &data[..len];
+
+        let mut buf = [0u8; BUF_SIZE];
+        let written = cobs(data, &mut buf);
+
+        let full_block: &[u8] = if data[0] == 0 {
+            &data[1..255]
+        } else {
+            &data[..254]
+        };
+        assert_eq!(&buf[..head.len()], head);
+        assert_eq!(&buf[head.len()..head.len() + 254], full_block);
+        assert_eq!(&buf[head.len() + 254..written], tail);
+    }
+
+    #[test]
+    fn assert_that_the_stuffed_size_is_exact() {
+        let mut counter = crate::encoders::SizeEncoder::new();
+        let mut encoder = CobsEncoder::new(&mut counter);
+        [0xAAu8; 1000].encode(&mut encoder).unwrap();
+        let size = encoder.finish().unwrap().size();
+
+        // Three full blocks, a block of 238 bytes and the delimiter.
+        assert_eq!(size, 3 * 255 + 239 + 1);
+    }
+
+    #[test]
+    fn assert_that_encoder_errors_are_propagated() {
+        let mut buf = [0u8; 2];
+        let mut output = &mut buf as &mut [u8];
+        let mut encoder = CobsEncoder::new(&mut output);
+        "abc".encode(&mut encoder).unwrap();
+
+        assert_eq!(encoder.finish(), Err(InsufficientSpace));
+    }
+}
@@ -0,0 +1,179 @@
+use core::fmt::Formatter;
+
+use crate::BaseEncoder;
+use crate::ByteEncoder;
+use crate::PositionedEncoder;
+use crate::StrEncoder;
+
+/// An encoder that counts the bytes written through it into another encoder.
+///
+/// Unlike [`SizeEncoder`](super::SizeEncoder), the bytes are also written
+/// into the inner encoder. This gives a uniform way of finding out how many
+/// bytes were written, regardless of the encoder. Only the bytes accepted by
+/// the inner encoder are counted.
+///
+/// [`Counting`] implements [`PositionedEncoder`], so it can be used with
+/// encodables that depend on their position in the output.
+///
+/// # Example
+///
+/// ```
+/// use encode::Encodable;
+/// use encode::encoders::Counting;
+///
+/// let mut buf = [0u8; 16];
+/// let mut encoder = Counting::new(&mut buf as &mut [u8]);
+/// ("hello", b' ', "world").encode(&mut encoder).unwrap();
+/// let written = encoder.written();
+/// assert_eq!(&buf[..written], b"hello world");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Counting<E> {
+    encoder: E,
+    written: usize,
+}
+
+impl<E> Counting<E> {
+    /// Creates a new [`Counting`] encoder.
+    #[inline]
+    #[must_use]
+    pub const fn new(encoder: E) -> Self {
+        Self {
+            encoder,
+            written: 0,
+        }
+    }
+    /// Returns the number of bytes written into the inner encoder.
+    #[inline]
+    #[must_use]
+    pub const fn written(&self) -> usize {
+        self.written
+    }
+    /// Returns a reference to the inner encoder.
+    #[inline]
+    #[must_use]
+    pub const fn get_ref(&self) -> &E {
+        &self.encoder
+    }
+    /// Consumes the encoder and returns the inner encoder.
+    #[inline]
+    #[must_use]
+    pub fn into_inner(self) -> E {
+        self.encoder
+    }
+}
+
+impl<E: BaseEncoder> BaseEncoder for Counting<E> {
+    type Error = E::Error;
+}
+
+impl<E: BaseEncoder> PositionedEncoder for Counting<E> {
+    #[inline]
+    fn position(&self) -> usize {
+        self.written
+    }
+}
+
+impl<E: ByteEncoder> ByteEncoder for Counting<E> {
+    #[inline]
+    fn put_slice(&mut self, slice: &[u8]) -> Result<(), Self::Error> {
+        self.encoder.put_slice(slice)?;
+        self.written += slice.len();
+        Ok(())
+    }
+
+    #[inline]
+    fn put_byte(&mut self, byte: u8) -> Result<(), Self::Error> {
+        self.encoder.put_byte(byte)?;
+        self.written += 1;
+        Ok(())
+    }
+}
+
+// Encoders that only implement `StrEncoder` are listed one by one, because a
+// generic implementation would conflict with the `StrEncoder` implementation
+// of every `ByteEncoder`.
+macro_rules! impl_str_encoder_for_counting {
+    ($($(#[$meta:meta])* [$($generics:tt)*] $T:ty,)*) => {
+        $(
+            $(#[$meta])*
+            impl<$($generics)*> StrEncoder for Counting<$T> {
+                #[inline]
+                fn put_str(&mut self, string: &str) -> Result<(), Self::Error> {
+                    self.encoder.put_str(string)?;
+                    self.written += string.len();
+                    Ok(())
+                }
+            }
+        )*
+    };
+}
+
+impl_str_encoder_for_counting! {
+    [] Formatter<'_>,
+    #[cfg(feature = "alloc")]
+    [] alloc::string::String,
+    #[cfg(feature = "arrayvec")]
+    [const SIZE: usize] arrayvec::ArrayString<SIZE>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::encoders::InsufficientSpace;
+    use crate::Encodable;
+
+    const BUF_SIZE: usize = 16;
+
+    #[test]
+    fn assert_that_written_bytes_are_counted() {
+        let mut buf = [0u8; BUF_SIZE];
+        let mut encoder = Counting::new(&mut buf as &mut [u8]);
+        ("abc", b'd').encode(&mut encoder).unwrap();
+
+        assert_eq!(encoder.written(), 4);
+        assert_eq!(encoder.position(), 4);
+        assert_eq!(encoder.into_inner().len(), BUF_SIZE - 4);
+    }
+
+    #[test]
+    fn assert_that_rejected_bytes_are_not_counted() {
+        let mut buf = [0u8; 2];
+        let mut encoder = Counting::new(&mut buf as &mut [u8]);
+        b'a'.encode(&mut encoder).unwrap();
+
+        assert_eq!("bc".encode(&mut encoder), Err(InsufficientSpace));
+        assert_eq!(encoder.written(), 1);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn assert_that_str_encoders_can_be_counted() {
+        let mut encoder = Counting::new(alloc::string::String::new());
+        ("hello", ' ', format_args!("{}", 42))
+            .encode(&mut encoder)
+            .unwrap();
+
+        assert_eq!(encoder.written(), 8);
+        assert_eq!(encoder.get_ref(), "hello 42");
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn assert_that_io_encoders_can_be_counted() {
+        let mut encoder = Counting::new(crate::encoders::IoEncoder(std::io::sink()));
+        "hello".encode(&mut encoder).unwrap();
+
+        assert_eq!(encoder.written(), 5);
+    }
+
+    #[cfg(feature = "arrayvec")]
+    #[test]
+    fn assert_that_arrayvecs_can_be_counted() {
+        let mut encoder = Counting::new(arrayvec::ArrayVec::<u8, 4>::new());
+        "abc".encode(&mut encoder).unwrap();
+
+        assert_eq!("de".encode(&mut encoder), Err(InsufficientSpace));
+        assert_eq!(encoder.written(), 3);
+    }
+}
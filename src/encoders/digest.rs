@@ -0,0 +1,89 @@
+use digest::Update;
+
+use crate::BaseEncoder;
+use crate::ByteEncoder;
+
+/// An encoder that feeds the encoded data into a cryptographic hash function
+/// from the [`digest`] crate, such as SHA-256.
+///
+/// This is the [`digest`] counterpart of [`HashEncoder`](super::HashEncoder).
+/// Any type implementing [`digest::Update`] can be used, including every
+/// [`digest::Digest`].
+///
+/// # Example
+///
+/// ```
+/// use encode::Encodable;
+/// use encode::encoders::DigestEncoder;
+/// use sha2::{Digest, Sha256};
+///
+/// let mut encoder = DigestEncoder::new(Sha256::new());
+/// "hello".encode(&mut encoder).unwrap();
+/// let hash = encoder.into_inner().finalize();
+/// assert_eq!(hash, Sha256::digest(b"hello"));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[repr(transparent)]
+pub struct DigestEncoder<D> {
+    digest: D,
+}
+
+impl<D> DigestEncoder<D> {
+    /// Creates a new [`DigestEncoder`].
+    #[inline]
+    #[must_use]
+    pub const fn new(digest: D) -> Self {
+        Self { digest }
+    }
+    /// Returns a reference to the inner digest.
+    #[inline]
+    #[must_use]
+    pub const fn get_ref(&self) -> &D {
+        &self.digest
+    }
+    /// Consumes the encoder and returns the inner digest.
+    #[inline]
+    #[must_use]
+    pub fn into_inner(self) -> D {
+        self.digest
+    }
+}
+
+impl<D> BaseEncoder for DigestEncoder<D> {
+    type Error = core::convert::Infallible;
+}
+
+impl<D: Update> ByteEncoder for DigestEncoder<D> {
+    #[inline]
+    fn put_slice(&mut self, slice: &[u8]) -> Result<(), Self::Error> {
+        self.digest.update(slice);
+        Ok(())
+    }
+
+    #[inline]
+    fn put_byte(&mut self, byte: u8) -> Result<(), Self::Error> {
+        self.digest.update(&[byte]);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use sha2::Digest;
+    use sha2::Sha256;
+
+    use super::*;
+    use crate::combinators::LE;
+    use crate::Encodable;
+
+    #[test]
+    fn assert_that_encoded_bytes_are_digested() {
+        let mut encoder = DigestEncoder::new(Sha256::new());
+        ("abc", LE::new(1u16)).encode(&mut encoder).unwrap();
+
+        assert_eq!(
+            encoder.into_inner().finalize(),
+            Sha256::digest(b"abc\x01\x00")
+        );
+    }
+}
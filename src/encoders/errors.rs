@@ -14,6 +14,146 @@ impl core::fmt::Display for InsufficientSpace {
     }
 }
 
+/// An error that occurs when patching a reserved region of a
+/// [`PatchableEncoder`](crate::PatchableEncoder).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum PatchError<E> {
+    /// The inner encoder failed.
+    Encoder(E),
+    /// The patch is not the same size as the reserved region.
+    SizeMismatch,
+}
+
+impl<E> From<E> for PatchError<E> {
+    #[inline]
+    fn from(err: E) -> Self {
+        Self::Encoder(err)
+    }
+}
+
+impl<E: core::error::Error + 'static> core::error::Error for PatchError<E> {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match self {
+            Self::Encoder(err) => Some(err),
+            Self::SizeMismatch => None,
+        }
+    }
+}
+impl<E: core::fmt::Display> core::fmt::Display for PatchError<E> {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        match self {
+            Self::Encoder(err) => core::fmt::Display::fmt(err, f),
+            Self::SizeMismatch => write!(
+                f,
+                "The patch does not match the size of the reserved region"
+            ),
+        }
+    }
+}
+
+/// An error that occurs when writing into a
+/// [`Base58Encoder`](super::Base58Encoder).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Base58Error<E> {
+    /// The inner encoder failed.
+    Encoder(E),
+    /// The value has more digits than the buffer of the encoder can hold.
+    CapacityExceeded,
+}
+
+impl<E> From<E> for Base58Error<E> {
+    #[inline]
+    fn from(err: E) -> Self {
+        Self::Encoder(err)
+    }
+}
+
+impl<E: core::error::Error + 'static> core::error::Error for Base58Error<E> {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match self {
+            Self::Encoder(err) => Some(err),
+            Self::CapacityExceeded => None,
+        }
+    }
+}
+impl<E: core::fmt::Display> core::fmt::Display for Base58Error<E> {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        match self {
+            Self::Encoder(err) => core::fmt::Display::fmt(err, f),
+            Self::CapacityExceeded => {
+                write!(f, "The value has too many digits for the Base58 buffer")
+            }
+        }
+    }
+}
+
+/// An error that occurs when one of the encoders of a [`Tee`](super::Tee)
+/// fails.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum TeeError<A, B> {
+    /// The first encoder failed.
+    First(A),
+    /// The second encoder failed.
+    Second(B),
+}
+
+impl<A, B> core::error::Error for TeeError<A, B>
+where
+    A: core::error::Error + 'static,
+    B: core::error::Error + 'static,
+{
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match self {
+            Self::First(err) => Some(err),
+            Self::Second(err) => Some(err),
+        }
+    }
+}
+impl<A: core::fmt::Display, B: core::fmt::Display> core::fmt::Display for TeeError<A, B> {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        match self {
+            Self::First(err) => write!(f, "The first encoder failed: {err}"),
+            Self::Second(err) => write!(f, "The second encoder failed: {err}"),
+        }
+    }
+}
+
+/// An error that occurs when writing into a [`Limit`](super::Limit) encoder.
+///
+/// Either the inner encoder failed, or the write would have exceeded the
+/// limit.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum LimitError<E> {
+    /// The inner encoder failed.
+    Encoder(E),
+    /// The write would have exceeded the limit.
+    LimitExceeded,
+}
+
+impl<E> From<E> for LimitError<E> {
+    #[inline]
+    fn from(err: E) -> Self {
+        Self::Encoder(err)
+    }
+}
+
+impl<E: core::error::Error + 'static> core::error::Error for LimitError<E> {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match self {
+            Self::Encoder(err) => Some(err),
+            Self::LimitExceeded => None,
+        }
+    }
+}
+impl<E: core::fmt::Display> core::fmt::Display for LimitError<E> {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        match self {
+            Self::Encoder(err) => core::fmt::Display::fmt(err, f),
+            Self::LimitExceeded => write!(f, "The encoded data exceeds the size limit"),
+        }
+    }
+}
+
 #[cfg(all(test, feature = "alloc"))]
 mod tests {
     use alloc::string::ToString;
@@ -26,4 +166,57 @@ mod tests {
         let msg = err.to_string();
         assert_eq!(msg, "The provided buffer has no space left for encoding");
     }
+
+    #[test]
+    fn assert_that_patch_error_displays_correctly() {
+        let err = PatchError::<InsufficientSpace>::SizeMismatch;
+        assert_eq!(
+            err.to_string(),
+            "The patch does not match the size of the reserved region"
+        );
+        let err = PatchError::from(InsufficientSpace);
+        assert_eq!(
+            err.to_string(),
+            "The provided buffer has no space left for encoding"
+        );
+    }
+
+    #[test]
+    fn assert_that_base58_error_displays_correctly() {
+        let err = Base58Error::<InsufficientSpace>::CapacityExceeded;
+        assert_eq!(
+            err.to_string(),
+            "The value has too many digits for the Base58 buffer"
+        );
+        let err = Base58Error::from(InsufficientSpace);
+        assert_eq!(
+            err.to_string(),
+            "The provided buffer has no space left for encoding"
+        );
+    }
+
+    #[test]
+    fn assert_that_tee_error_displays_correctly() {
+        let err = TeeError::<_, InsufficientSpace>::First(InsufficientSpace);
+        assert_eq!(
+            err.to_string(),
+            "The first encoder failed: The provided buffer has no space left for encoding"
+        );
+        let err = TeeError::<InsufficientSpace, _>::Second(InsufficientSpace);
+        assert_eq!(
+            err.to_string(),
+            "The second encoder failed: The provided buffer has no space left for encoding"
+        );
+    }
+
+    #[test]
+    fn assert_that_limit_error_displays_correctly() {
+        let err = LimitError::<InsufficientSpace>::LimitExceeded;
+        assert_eq!(err.to_string(), "The encoded data exceeds the size limit");
+        let err = LimitError::from(InsufficientSpace);
+        assert_eq!(
+            err.to_string(),
+            "The provided buffer has no space left for encoding"
+        );
+    }
 }
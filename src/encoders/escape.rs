@@ -0,0 +1,297 @@
+use crate::BaseEncoder;
+use crate::ByteEncoder;
+
+/// Writes `slice` into the encoder, replacing the bytes `escape` maps to an
+/// escape sequence.
+#[inline]
+fn put_escaped<E: ByteEncoder>(
+    encoder: &mut E,
+    slice: &[u8],
+    escape: impl Fn(u8) -> Option<[u8; 2]>,
+) -> Result<(), E::Error> {
+    let mut start = 0;
+    for (i, &byte) in slice.iter().enumerate() {
+        if let Some(sequence) = escape(byte) {
+            if start < i {
+                encoder.put_slice(&slice[start..i])?;
+            }
+            encoder.put_slice(&sequence)?;
+            start = i + 1;
+        }
+    }
+    if start < slice.len() {
+        encoder.put_slice(&slice[start..])?;
+    }
+    Ok(())
+}
+
+/// The SLIP frame delimiter.
+const SLIP_END: u8 = 0xC0;
+/// The SLIP escape byte.
+const SLIP_ESC: u8 = 0xDB;
+
+/// Returns the SLIP escape sequence of a byte, if it needs one.
+#[inline]
+const fn slip_escape(byte: u8) -> Option<[u8; 2]> {
+    match byte {
+        SLIP_END => Some([SLIP_ESC, 0xDC]),
+        SLIP_ESC => Some([SLIP_ESC, 0xDD]),
+        _ => None,
+    }
+}
+
+/// An encoder that escapes the bytes written through it as described in
+/// [RFC 1055](https://www.rfc-editor.org/rfc/rfc1055) (SLIP).
+///
+/// The frame delimiter `0xC0` is replaced by `0xDB 0xDC`, and the escape byte
+/// `0xDB` by `0xDB 0xDD`. Nothing is buffered. The encoder does not write the
+/// leading delimiter recommended by the RFC, but
+/// [`finish`](SlipEncoder::finish) writes the trailing one.
+///
+/// # Example
+///
+/// ```
+/// use encode::Encodable;
+/// use encode::encoders::SlipEncoder;
+///
+/// let mut buf = [0u8; 8];
+/// let mut output = &mut buf as &mut [u8];
+/// let mut encoder = SlipEncoder::new(&mut output);
+/// b"\x01\xC0\xDB".encode(&mut encoder).unwrap();
+/// encoder.finish().unwrap();
+/// assert_eq!(&buf[..6], b"\x01\xDB\xDC\xDB\xDD\xC0");
+/// ```
+#[derive(Debug)]
+pub struct SlipEncoder<'a, E> {
+    encoder: &'a mut E,
+}
+
+impl<'a, E> SlipEncoder<'a, E> {
+    /// The byte that delimits SLIP frames.
+    pub const END: u8 = SLIP_END;
+
+    /// Creates a new [`SlipEncoder`].
+    #[inline]
+    #[must_use]
+    pub fn new(encoder: &'a mut E) -> Self {
+        Self { encoder }
+    }
+    /// Returns a reference to the inner encoder.
+    #[inline]
+    #[must_use]
+    pub fn get_ref(&self) -> &E {
+        self.encoder
+    }
+}
+
+impl<'a, E: ByteEncoder> SlipEncoder<'a, E> {
+    /// Writes the trailing delimiter and returns the inner encoder.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the inner encoder cannot write the delimiter.
+    #[inline]
+    pub fn finish(self) -> Result<&'a mut E, E::Error> {
+        self.encoder.put_byte(SLIP_END)?;
+        Ok(self.encoder)
+    }
+}
+
+impl<E: BaseEncoder> BaseEncoder for SlipEncoder<'_, E> {
+    type Error = E::Error;
+}
+
+impl<E: ByteEncoder> ByteEncoder for SlipEncoder<'_, E> {
+    #[inline]
+    fn put_slice(&mut self, slice: &[u8]) -> Result<(), Self::Error> {
+        put_escaped(self.encoder, slice, slip_escape)
+    }
+
+    #[inline]
+    fn put_byte(&mut self, byte: u8) -> Result<(), Self::Error> {
+        match slip_escape(byte) {
+            Some(sequence) => self.encoder.put_slice(&sequence),
+            None => self.encoder.put_byte(byte),
+        }
+    }
+}
+
+/// The HDLC flag sequence, which delimits frames.
+const HDLC_FLAG: u8 = 0x7E;
+/// The HDLC control escape byte.
+const HDLC_ESC: u8 = 0x7D;
+
+/// Returns the HDLC escape sequence of a byte, if it needs one.
+#[inline]
+const fn hdlc_escape(byte: u8) -> Option<[u8; 2]> {
+    match byte {
+        HDLC_FLAG | HDLC_ESC => Some([HDLC_ESC, byte ^ 0x20]),
+        _ => None,
+    }
+}
+
+/// An encoder that escapes the bytes written through it with the
+/// asynchronous HDLC byte stuffing described in
+/// [RFC 1662](https://www.rfc-editor.org/rfc/rfc1662#section-4.2).
+///
+/// The flag sequence `0x7E` is replaced by `0x7D 0x5E`, and the control
+/// escape `0x7D` by `0x7D 0x5D`. Nothing is buffered. The encoder does not
+/// write the opening flag, but [`finish`](HdlcEncoder::finish) writes the
+/// closing one.
+///
+/// # Example
+///
+/// ```
+/// use encode::Encodable;
+/// use encode::encoders::HdlcEncoder;
+///
+/// let mut buf = [0u8; 8];
+/// let mut output = &mut buf as &mut [u8];
+/// let mut encoder = HdlcEncoder::new(&mut output);
+/// b"\x01\x7E\x7D".encode(&mut encoder).unwrap();
+/// encoder.finish().unwrap();
+/// assert_eq!(&buf[..6], b"\x01\x7D\x5E\x7D\x5D\x7E");
+/// ```
+#[derive(Debug)]
+pub struct HdlcEncoder<'a, E> {
+    encoder: &'a mut E,
+}
+
+impl<'a, E> HdlcEncoder<'a, E> {
+    /// The flag sequence that delimits HDLC frames.
+    pub const FLAG: u8 = HDLC_FLAG;
+
+    /// Creates a new [`HdlcEncoder`].
+    #[inline]
+    #[must_use]
+    pub fn new(encoder: &'a mut E) -> Self {
+        Self { encoder }
+    }
+    /// Returns a reference to the inner encoder.
+    #[inline]
+    #[must_use]
+    pub fn get_ref(&self) -> &E {
+        self.encoder
+    }
+}
+
+impl<'a, E: ByteEncoder> HdlcEncoder<'a, E> {
+    /// Writes the closing flag and returns the inner encoder.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the inner encoder cannot write the flag.
+    #[inline]
+    pub fn finish(self) -> Result<&'a mut E, E::Error> {
+        self.encoder.put_byte(HDLC_FLAG)?;
+        Ok(self.encoder)
+    }
+}
+
+impl<E: BaseEncoder> BaseEncoder for HdlcEncoder<'_, E> {
+    type Error = E::Error;
+}
+
+impl<E: ByteEncoder> ByteEncoder for HdlcEncoder<'_, E> {
+    #[inline]
+    fn put_slice(&mut self, slice: &[u8]) -> Result<(), Self::Error> {
+        put_escaped(self.encoder, slice, hdlc_escape)
+    }
+
+    #[inline]
+    fn put_byte(&mut self, byte: u8) -> Result<(), Self::Error> {
+        match hdlc_escape(byte) {
+            Some(sequence) => self.encoder.put_slice(&sequence),
+            None => self.encoder.put_byte(byte),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+    use crate::encoders::InsufficientSpace;
+    use crate::encoders::SizeEncoder;
+    use crate::Encodable;
+
+    const BUF_SIZE: usize = 64;
+
+    #[rstest]
+    #[case::empty(b"", b"\xC0")]
+    #[case::plain(b"hello", b"hello\xC0")]
+    #[case::end(b"\xC0", b"\xDB\xDC\xC0")]
+    #[case::esc(b"\xDB", b"\xDB\xDD\xC0")]
+    #[case::mixed(b"a\xC0\xC0b\xDBc", b"a\xDB\xDC\xDB\xDCb\xDB\xDDc\xC0")]
+    #[case::hdlc_bytes(b"\x7E\x7D", b"\x7E\x7D\xC0")]
+    fn assert_that_slip_escapes_special_bytes(#[case] data: &[u8], #[case] expected: &[u8]) {
+        let mut buf = [0u8; BUF_SIZE];
+        let mut output = &mut buf as &mut [u8];
+        let mut encoder = SlipEncoder::new(&mut output);
+        data.encode(&mut encoder).unwrap();
+        let written = BUF_SIZE - encoder.finish().unwrap().len();
+        assert_eq!(&buf[..written], expected);
+
+        let mut buf = [0u8; BUF_SIZE];
+        let mut output = &mut buf as &mut [u8];
+        let mut encoder = SlipEncoder::new(&mut output);
+        for &byte in data {
+            encoder.put_byte(byte).unwrap();
+        }
+        let written = BUF_SIZE - encoder.finish().unwrap().len();
+        assert_eq!(&buf[..written], expected);
+    }
+
+    #[rstest]
+    #[case::empty(b"", b"\x7E")]
+    #[case::plain(b"hello", b"hello\x7E")]
+    #[case::flag(b"\x7E", b"\x7D\x5E\x7E")]
+    #[case::esc(b"\x7D", b"\x7D\x5D\x7E")]
+    #[case::mixed(b"a\x7E\x7Eb\x7Dc", b"a\x7D\x5E\x7D\x5Eb\x7D\x5Dc\x7E")]
+    #[case::slip_bytes(b"\xC0\xDB", b"\xC0\xDB\x7E")]
+    fn assert_that_hdlc_escapes_special_bytes(#[case] data: &[u8], #[case] expected: &[u8]) {
+        let mut buf = [0u8; BUF_SIZE];
+        let mut output = &mut buf as &mut [u8];
+        let mut encoder = HdlcEncoder::new(&mut output);
+        data.encode(&mut encoder).unwrap();
+        let written = BUF_SIZE - encoder.finish().unwrap().len();
+        assert_eq!(&buf[..written], expected);
+
+        let mut buf = [0u8; BUF_SIZE];
+        let mut output = &mut buf as &mut [u8];
+        let mut encoder = HdlcEncoder::new(&mut output);
+        for &byte in data {
+            encoder.put_byte(byte).unwrap();
+        }
+        let written = BUF_SIZE - encoder.finish().unwrap().len();
+        assert_eq!(&buf[..written], expected);
+    }
+
+    #[test]
+    fn assert_that_the_escaped_size_is_exact() {
+        let mut counter = SizeEncoder::new();
+        let mut encoder = SlipEncoder::new(&mut counter);
+        b"\xC0\x00\xDB".encode(&mut encoder).unwrap();
+        assert_eq!(encoder.finish().unwrap().size(), 6);
+
+        let mut counter = SizeEncoder::new();
+        let mut encoder = HdlcEncoder::new(&mut counter);
+        b"\x7E\x00\x7D".encode(&mut encoder).unwrap();
+        assert_eq!(encoder.finish().unwrap().size(), 6);
+    }
+
+    #[test]
+    fn assert_that_encoder_errors_are_propagated() {
+        let mut buf = [0u8; 2];
+        let mut output = &mut buf as &mut [u8];
+        let mut encoder = SlipEncoder::new(&mut output);
+        assert_eq!(encoder.put_slice(b"a\xC0"), Err(InsufficientSpace));
+
+        let mut buf = [0u8; 1];
+        let mut output = &mut buf as &mut [u8];
+        let mut encoder = HdlcEncoder::new(&mut output);
+        encoder.put_byte(b'a').unwrap();
+        assert_eq!(encoder.finish(), Err(InsufficientSpace));
+    }
+}
@@ -42,7 +42,7 @@ mod tests {
             }
         }
         let mut output = String::new();
-        writeln!(&mut output, "{}", MyEncodable).expect("Failed to write to output");
+        writeln!(&mut output, "{MyEncodable}").expect("Failed to write to output");
 
         assert_eq!(output, "Hello world\n");
     }
@@ -0,0 +1,161 @@
+use core::hash::Hasher;
+
+use crate::BaseEncoder;
+use crate::ByteEncoder;
+
+/// An encoder that feeds the encoded data into a [`Hasher`] instead of
+/// storing it.
+///
+/// This encoder is useful for computing content hashes or cache keys of an
+/// encodable without allocating its encoded form. See the
+/// [`EncodableHash`](crate::EncodableHash) trait for a shorthand.
+///
+/// Note that some hashers produce different hashes depending on how the
+/// bytes are split across calls to [`Hasher::write`], so the same encodable
+/// may only be relied on to hash the same way if it is encoded the same way.
+///
+/// # Example
+///
+/// ```
+/// use encode::Encodable;
+/// use encode::encoders::HashEncoder;
+/// use std::collections::hash_map::DefaultHasher;
+///
+/// let mut encoder = HashEncoder::new(DefaultHasher::new());
+/// ("hello", 0u8).encode(&mut encoder).unwrap();
+/// let hash = encoder.finish();
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[repr(transparent)]
+pub struct HashEncoder<H> {
+    hasher: H,
+}
+
+impl<H> HashEncoder<H> {
+    /// Creates a new [`HashEncoder`].
+    #[inline]
+    #[must_use]
+    pub const fn new(hasher: H) -> Self {
+        Self { hasher }
+    }
+    /// Returns a reference to the inner hasher.
+    #[inline]
+    #[must_use]
+    pub const fn get_ref(&self) -> &H {
+        &self.hasher
+    }
+    /// Consumes the encoder and returns the inner hasher.
+    #[inline]
+    #[must_use]
+    pub fn into_inner(self) -> H {
+        self.hasher
+    }
+}
+
+impl<H: Hasher> HashEncoder<H> {
+    /// Returns the hash of the encoded data.
+    #[inline]
+    #[must_use]
+    pub fn finish(&self) -> u64 {
+        self.hasher.finish()
+    }
+}
+
+impl<H> BaseEncoder for HashEncoder<H> {
+    type Error = core::convert::Infallible;
+}
+
+impl<H: Hasher> ByteEncoder for HashEncoder<H> {
+    #[inline]
+    fn put_slice(&mut self, slice: &[u8]) -> Result<(), Self::Error> {
+        self.hasher.write(slice);
+        Ok(())
+    }
+
+    #[inline]
+    fn put_byte(&mut self, byte: u8) -> Result<(), Self::Error> {
+        self.hasher.write(&[byte]);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Encodable;
+
+    /// A hasher that records the sum and the number of the bytes it sees.
+    #[derive(Debug, Default)]
+    struct SumHasher {
+        sum: u64,
+        len: u64,
+    }
+
+    impl Hasher for SumHasher {
+        fn finish(&self) -> u64 {
+            (self.len << 32) | self.sum
+        }
+
+        fn write(&mut self, bytes: &[u8]) {
+            self.sum += bytes.iter().map(|&byte| u64::from(byte)).sum::<u64>();
+            self.len += bytes.len() as u64;
+        }
+    }
+
+    /// A hasher that, like many fast hashers, mixes integers differently from
+    /// byte slices.
+    #[derive(Debug, Default)]
+    struct MixingHasher(u64);
+
+    impl Hasher for MixingHasher {
+        fn finish(&self) -> u64 {
+            self.0
+        }
+
+        fn write(&mut self, bytes: &[u8]) {
+            for &byte in bytes {
+                self.0 = self.0.rotate_left(8) ^ u64::from(byte);
+            }
+        }
+
+        fn write_u8(&mut self, i: u8) {
+            self.0 = self.0.rotate_left(5) ^ u64::from(i);
+        }
+    }
+
+    #[test]
+    fn assert_that_the_hash_does_not_depend_on_how_bytes_are_written() {
+        let mut bytes = HashEncoder::new(MixingHasher::default());
+        (1u8, 2u8, 3u8).encode(&mut bytes).unwrap();
+        let mut slice = HashEncoder::new(MixingHasher::default());
+        [1u8, 2, 3].encode(&mut slice).unwrap();
+
+        assert_eq!(bytes.finish(), slice.finish());
+    }
+
+    #[test]
+    fn assert_that_encoded_bytes_are_hashed() {
+        let mut encoder = HashEncoder::new(SumHasher::default());
+        ([1u8, 2, 3], 4u8).encode(&mut encoder).unwrap();
+
+        assert_eq!(encoder.finish(), 0x0000_0004_0000_000A);
+        assert_eq!(encoder.get_ref().len, 4);
+        assert_eq!(encoder.into_inner().sum, 10);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn assert_that_equal_encodings_hash_equally() {
+        use std::collections::hash_map::DefaultHasher;
+
+        let mut a = HashEncoder::new(DefaultHasher::new());
+        "hello".encode(&mut a).unwrap();
+        let mut b = HashEncoder::new(DefaultHasher::new());
+        [b'h', b'e', b'l', b'l', b'o'].encode(&mut b).unwrap();
+        let mut c = HashEncoder::new(DefaultHasher::new());
+        "world".encode(&mut c).unwrap();
+
+        assert_eq!(a.finish(), b.finish());
+        assert_ne!(a.finish(), c.finish());
+    }
+}
@@ -0,0 +1,144 @@
+use crate::BaseEncoder;
+use crate::ByteEncoder;
+use crate::StrEncoder;
+
+/// The lowercase hexadecimal digits.
+const LOWER: &[u8; 16] = b"0123456789abcdef";
+/// The uppercase hexadecimal digits.
+const UPPER: &[u8; 16] = b"0123456789ABCDEF";
+
+/// An encoder that writes the bytes written through it as hexadecimal text
+/// into a [`StrEncoder`].
+///
+/// Every byte becomes two hexadecimal digits, lowercase unless
+/// [`uppercase`](HexEncoder::uppercase) is called. The digits are written in
+/// chunks from a small stack buffer, so nothing is allocated and the inner
+/// encoder can be a [`String`](alloc::string::String), a
+/// [`Formatter`](core::fmt::Formatter) or any [`ByteEncoder`].
+///
+/// # Example
+///
+/// ```
+/// # #[cfg(feature = "alloc")] {
+/// use encode::Encodable;
+/// use encode::encoders::HexEncoder;
+///
+/// let mut text = String::new();
+/// b"\x01\xAB".encode(&mut HexEncoder::new(&mut text)).unwrap();
+/// assert_eq!(text, "01ab");
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct HexEncoder<'a, E> {
+    encoder: &'a mut E,
+    digits: &'static [u8; 16],
+}
+
+impl<'a, E> HexEncoder<'a, E> {
+    /// Creates a new [`HexEncoder`] that writes lowercase digits.
+    #[inline]
+    #[must_use]
+    pub fn new(encoder: &'a mut E) -> Self {
+        Self {
+            encoder,
+            digits: LOWER,
+        }
+    }
+    /// Makes the encoder write uppercase digits.
+    #[inline]
+    #[must_use]
+    pub const fn uppercase(mut self) -> Self {
+        self.digits = UPPER;
+        self
+    }
+    /// Returns a reference to the inner encoder.
+    #[inline]
+    #[must_use]
+    pub fn get_ref(&self) -> &E {
+        self.encoder
+    }
+}
+
+impl<E: BaseEncoder> BaseEncoder for HexEncoder<'_, E> {
+    type Error = E::Error;
+}
+
+impl<E: StrEncoder> ByteEncoder for HexEncoder<'_, E> {
+    #[inline]
+    fn put_slice(&mut self, slice: &[u8]) -> Result<(), Self::Error> {
+        let mut buf = [0u8; 128];
+        for chunk in slice.chunks(buf.len() / 2) {
+            for (pair, &byte) in buf.chunks_exact_mut(2).zip(chunk) {
+                pair[0] = self.digits[usize::from(byte >> 4)];
+                pair[1] = self.digits[usize::from(byte & 0x0F)];
+            }
+            // Hexadecimal digits are ASCII, so this never fails.
+            let text = core::str::from_utf8(&buf[..chunk.len() * 2]).unwrap_or_default();
+            self.encoder.put_str(text)?;
+        }
+        Ok(())
+    }
+
+    #[inline]
+    fn put_byte(&mut self, byte: u8) -> Result<(), Self::Error> {
+        self.put_slice(&[byte])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+    use crate::encoders::InsufficientSpace;
+    use crate::Encodable;
+
+    const BUF_SIZE: usize = 512;
+
+    #[rstest]
+    #[case::empty(b"", false, b"")]
+    #[case::lower(b"\x00\x0F\xA5\xFF", false, b"000fa5ff")]
+    #[case::upper(b"\x00\x0F\xA5\xFF", true, b"000FA5FF")]
+    fn assert_that_bytes_are_written_as_hex(
+        #[case] data: &[u8],
+        #[case] uppercase: bool,
+        #[case] expected: &[u8],
+    ) {
+        let mut buf = [0u8; BUF_SIZE];
+        let mut encoder = &mut buf as &mut [u8];
+        let mut hex = HexEncoder::new(&mut encoder);
+        if uppercase {
+            hex = hex.uppercase();
+        }
+        data.encode(&mut hex).unwrap();
+        let written = BUF_SIZE - encoder.len();
+
+        assert_eq!(&buf[..written], expected);
+    }
+
+    #[test]
+    fn assert_that_long_slices_are_written_in_chunks() {
+        #[allow(clippy::cast_possible_truncation)]
+        let data: [u8; 200] = core::array::from_fn(|i| i as u8);
+
+        let mut buf = [0u8; BUF_SIZE];
+        let mut encoder = &mut buf as &mut [u8];
+        data.encode(&mut HexEncoder::new(&mut encoder)).unwrap();
+        let written = BUF_SIZE - encoder.len();
+
+        assert_eq!(written, 400);
+        assert_eq!(&buf[126..134], b"3f404142");
+        assert_eq!(&buf[392..written], b"c4c5c6c7");
+    }
+
+    #[test]
+    fn assert_that_encoder_errors_are_propagated() {
+        let mut buf = [0u8; 3];
+        let mut encoder = &mut buf as &mut [u8];
+
+        assert_eq!(
+            b"\x01\x02".encode(&mut HexEncoder::new(&mut encoder)),
+            Err(InsufficientSpace)
+        );
+    }
+}
@@ -0,0 +1,263 @@
+use super::LimitError;
+use crate::BaseEncoder;
+use crate::ByteEncoder;
+
+/// An encoder that fails once more than a given number of bytes would be
+/// written into the inner encoder.
+///
+/// Use it to enforce a maximum frame size, failing as soon as the limit is
+/// reached instead of encoding the whole value and rejecting it afterwards.
+/// A write that would exceed the limit fails with
+/// [`LimitError::LimitExceeded`] and writes nothing.
+///
+/// See [`Truncate`] for an encoder that silently drops the extra bytes
+/// instead.
+///
+/// # Example
+///
+/// ```
+/// # #[cfg(feature = "alloc")] {
+/// use encode::Encodable;
+/// use encode::encoders::{Limit, LimitError};
+///
+/// let mut encoder = Limit::new(Vec::new(), 4);
+/// assert!("abcd".encode(&mut encoder).is_ok());
+/// assert_eq!("e".encode(&mut encoder), Err(LimitError::LimitExceeded));
+/// assert_eq!(encoder.into_inner(), b"abcd");
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Limit<E> {
+    encoder: E,
+    remaining: usize,
+}
+
+impl<E> Limit<E> {
+    /// Creates a new [`Limit`] encoder that accepts at most `limit` bytes.
+    #[inline]
+    #[must_use]
+    pub const fn new(encoder: E, limit: usize) -> Self {
+        Self {
+            encoder,
+            remaining: limit,
+        }
+    }
+    /// Returns the number of bytes that can still be written.
+    #[inline]
+    #[must_use]
+    pub const fn remaining(&self) -> usize {
+        self.remaining
+    }
+    /// Returns a reference to the inner encoder.
+    #[inline]
+    #[must_use]
+    pub const fn get_ref(&self) -> &E {
+        &self.encoder
+    }
+    /// Consumes the encoder and returns the inner encoder.
+    #[inline]
+    #[must_use]
+    pub fn into_inner(self) -> E {
+        self.encoder
+    }
+}
+
+impl<E: BaseEncoder> BaseEncoder for Limit<E> {
+    type Error = LimitError<E::Error>;
+}
+
+impl<E: ByteEncoder> ByteEncoder for Limit<E> {
+    #[inline]
+    fn put_slice(&mut self, slice: &[u8]) -> Result<(), Self::Error> {
+        let remaining = self
+            .remaining
+            .checked_sub(slice.len())
+            .ok_or(LimitError::LimitExceeded)?;
+        self.encoder.put_slice(slice)?;
+        self.remaining = remaining;
+        Ok(())
+    }
+
+    #[inline]
+    fn put_byte(&mut self, byte: u8) -> Result<(), Self::Error> {
+        let remaining = self
+            .remaining
+            .checked_sub(1)
+            .ok_or(LimitError::LimitExceeded)?;
+        self.encoder.put_byte(byte)?;
+        self.remaining = remaining;
+        Ok(())
+    }
+}
+
+/// An encoder that silently drops the bytes past a given number of bytes.
+///
+/// Works like [`Limit`], but instead of failing, the bytes that do not fit
+/// are discarded. Use [`is_truncated`](Self::is_truncated) to find out if any
+/// bytes were dropped.
+///
+/// # Example
+///
+/// ```
+/// # #[cfg(feature = "alloc")] {
+/// use encode::Encodable;
+/// use encode::encoders::Truncate;
+///
+/// let mut encoder = Truncate::new(Vec::new(), 4);
+/// "hello".encode(&mut encoder).unwrap();
+/// assert!(encoder.is_truncated());
+/// assert_eq!(encoder.into_inner(), b"hell");
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Truncate<E> {
+    encoder: E,
+    remaining: usize,
+    truncated: bool,
+}
+
+impl<E> Truncate<E> {
+    /// Creates a new [`Truncate`] encoder that keeps at most `limit` bytes.
+    #[inline]
+    #[must_use]
+    pub const fn new(encoder: E, limit: usize) -> Self {
+        Self {
+            encoder,
+            remaining: limit,
+            truncated: false,
+        }
+    }
+    /// Returns the number of bytes that can still be written.
+    #[inline]
+    #[must_use]
+    pub const fn remaining(&self) -> usize {
+        self.remaining
+    }
+    /// Returns `true` if any bytes have been dropped.
+    #[inline]
+    #[must_use]
+    pub const fn is_truncated(&self) -> bool {
+        self.truncated
+    }
+    /// Returns a reference to the inner encoder.
+    #[inline]
+    #[must_use]
+    pub const fn get_ref(&self) -> &E {
+        &self.encoder
+    }
+    /// Consumes the encoder and returns the inner encoder.
+    #[inline]
+    #[must_use]
+    pub fn into_inner(self) -> E {
+        self.encoder
+    }
+}
+
+impl<E: BaseEncoder> BaseEncoder for Truncate<E> {
+    type Error = E::Error;
+}
+
+impl<E: ByteEncoder> ByteEncoder for Truncate<E> {
+    #[inline]
+    fn put_slice(&mut self, slice: &[u8]) -> Result<(), Self::Error> {
+        let len = slice.len().min(self.remaining);
+        if len < slice.len() {
+            self.truncated = true;
+        }
+        if len > 0 {
+            self.encoder.put_slice(&slice[..len])?;
+            self.remaining -= len;
+        }
+        Ok(())
+    }
+
+    #[inline]
+    fn put_byte(&mut self, byte: u8) -> Result<(), Self::Error> {
+        if self.remaining == 0 {
+            self.truncated = true;
+            return Ok(());
+        }
+        self.encoder.put_byte(byte)?;
+        self.remaining -= 1;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+    use crate::encoders::InsufficientSpace;
+    use crate::encoders::SizeEncoder;
+    use crate::Encodable;
+
+    const BUF_SIZE: usize = 16;
+
+    #[rstest]
+    #[case::below(4, Ok(()), 0)]
+    #[case::exact(3, Ok(()), 0)]
+    #[case::above(2, Err(LimitError::LimitExceeded), 2)]
+    fn assert_that_limit_fails_once_exceeded(
+        #[case] limit: usize,
+        #[case] expected: Result<(), LimitError<core::convert::Infallible>>,
+        #[case] size: usize,
+    ) {
+        let mut encoder = Limit::new(SizeEncoder::new(), limit);
+        let result = (b"ab", b'c').encode(&mut encoder);
+
+        assert_eq!(result, expected);
+        if result.is_ok() {
+            assert_eq!(encoder.remaining(), limit - 3);
+            assert_eq!(encoder.get_ref().size(), 3);
+        } else {
+            assert_eq!(encoder.get_ref().size(), size);
+        }
+    }
+
+    #[test]
+    fn assert_that_exceeding_writes_write_nothing() {
+        let mut buf = [0u8; BUF_SIZE];
+        let mut encoder = Limit::new(&mut buf as &mut [u8], 4);
+        "abc".encode(&mut encoder).unwrap();
+
+        assert_eq!("de".encode(&mut encoder), Err(LimitError::LimitExceeded));
+        assert_eq!(encoder.remaining(), 1);
+        assert_eq!(encoder.into_inner().len(), BUF_SIZE - 3);
+    }
+
+    #[test]
+    fn assert_that_limit_forwards_inner_errors() {
+        let mut buf = [0u8; 2];
+        let mut encoder = Limit::new(&mut buf as &mut [u8], 4);
+
+        assert_eq!(
+            "abc".encode(&mut encoder),
+            Err(LimitError::Encoder(InsufficientSpace))
+        );
+        assert_eq!(encoder.remaining(), 4);
+    }
+
+    #[test]
+    fn assert_that_truncate_drops_the_extra_bytes() {
+        let mut buf = [0u8; BUF_SIZE];
+        let mut encoder = Truncate::new(&mut buf as &mut [u8], 4);
+        "abc".encode(&mut encoder).unwrap();
+        assert!(!encoder.is_truncated());
+        ("de", b'f').encode(&mut encoder).unwrap();
+
+        assert!(encoder.is_truncated());
+        assert_eq!(encoder.remaining(), 0);
+        let written = BUF_SIZE - encoder.into_inner().len();
+        assert_eq!(&buf[..written], b"abcd");
+    }
+
+    #[test]
+    fn assert_that_truncate_forwards_inner_errors() {
+        let mut buf = [0u8; 2];
+        let mut encoder = Truncate::new(&mut buf as &mut [u8], 4);
+
+        assert_eq!("abc".encode(&mut encoder), Err(InsufficientSpace));
+        assert!(!encoder.is_truncated());
+    }
+}
@@ -18,7 +18,24 @@
 //! | [`()`](unit) | A no-op encoder. Useful for testing combinators or skipping output. | ✅ | ✅ | ✅ | - |
 //! | [`Formatter`](core::fmt::Formatter) | Writes data into a Rust [`core::fmt::Write`]. Useful for implementing [`Display`] or [`Debug`]. | ✅ | ✅ | ❌ | - |
 //! | [`SizeEncoder`] | Counts how many bytes would be encoded. Useful for sizing buffers. | ✅ | ✅ | ✅ | - |
+//! | [`HashEncoder`] | Feeds bytes into a [`Hasher`](core::hash::Hasher) instead of storing them. Useful for content hashes. | ✅ | ✅ | ✅ | - |
 //! | [`&mut [u8]`](slice) | Writes bytes into a fixed-size mutable slice. Fails if full. | ✅ | ✅ | ✅ | - |
+//! | [`&mut E`](reference) | Forwards to the borrowed encoder, so wrapping encoders can borrow their inner encoder. | ✅ | ✅ | ✅ | - |
+//! | [`BitEncoder`] | Packs individual bits into bytes before writing them into another [`ByteEncoder`]. | ✅ | ✅ | ✅ | - |
+//! | [`BitSizeEncoder`] | Counts how many bits would be encoded. Useful for sizing bit-packed data. | ✅ | ✅ | ✅ | - |
+//! | [`Counting`] | Counts the bytes written into another encoder. Useful for knowing the position in the output. | ✅ | ✅ | ✅ | - |
+//! | [`Tee`] | Writes the same bytes into two encoders at once. Useful for logging or measuring output. | ✅ | ✅ | ✅ | - |
+//! | [`Limit`] | Fails once more than a given number of bytes would be written into another [`ByteEncoder`]. | ✅ | ✅ | ✅ | - |
+//! | [`Truncate`] | Drops the bytes past a given number of bytes instead of writing them into another [`ByteEncoder`]. | ✅ | ✅ | ✅ | - |
+//! | [`CobsEncoder`] | Frames the bytes with [COBS](https://en.wikipedia.org/wiki/Consistent_Overhead_Byte_Stuffing) before writing them into another [`ByteEncoder`]. | ✅ | ✅ | ✅ | - |
+//! | [`SlipEncoder`] | Escapes the bytes as described in [RFC 1055](https://www.rfc-editor.org/rfc/rfc1055) (SLIP) before writing them into another [`ByteEncoder`]. | ✅ | ✅ | ✅ | - |
+//! | [`HdlcEncoder`] | Escapes the bytes with HDLC-like byte stuffing before writing them into another [`ByteEncoder`]. | ✅ | ✅ | ✅ | - |
+//! | [`HexEncoder`] | Writes the bytes as hexadecimal text into a [`StrEncoder`]. | ✅ | ✅ | ✅ | - |
+//! | [`Base32Encoder`] | Writes the bytes as Base32 text into a [`StrEncoder`]. | ✅ | ✅ | ✅ | - |
+//! | [`Base58Encoder`] | Writes the bytes as Base58 text into a [`StrEncoder`], buffering the digits. `Base58Check` requires the `sha2` feature. | ✅ | ✅ | ✅ | - |
+//! | [`Base64Encoder`] | Writes the bytes as Base64 text into a [`StrEncoder`]. | ✅ | ✅ | ✅ | - |
+//! | [`PercentEncoder`] | Writes the bytes into a [`StrEncoder`], percent-encoding the bytes of an [`AsciiSet`]. | ✅ | ✅ | ✅ | - |
+//! | [`ChecksumEncoder`] | Computes a [`Checksum`](crate::checksums::Checksum) of the bytes passed on to another [`ByteEncoder`]. | ✅ | ✅ | ✅ | - |
 #![cfg_attr(
     feature = "alloc",
     doc = "| [`Vec<u8>`](::alloc::vec::Vec) | Dynamically growing encoder that appends to a `Vec<u8>`. | ✅ | ✅ | ✅ | `alloc` OR `std` |"
@@ -39,6 +56,10 @@
     feature = "arrayvec",
     doc = "| [`ArrayString`](::arrayvec::ArrayString) | Encodes UTF-8 strings into a fixed-capacity `ArrayString`. Fails if full. | ✅ | ✅ | ❌ | `arrayvec` |"
 )]
+#![cfg_attr(
+    feature = "digest",
+    doc = "| [`DigestEncoder`] | Feeds bytes into a cryptographic hash function from the `digest` crate. | ✅ | ✅ | ✅ | `digest` |"
+)]
 #![cfg_attr(
     feature = "bytes",
     doc = "| [`BufMut`](::bytes::BufMut) | Writes to any `BufMut` (e.g., from `bytes` crate). Note that preallocating the buffer improves performance. | ✅ | ✅ | ✅ | `bytes` |"
@@ -48,17 +69,60 @@
 mod alloc;
 #[cfg(feature = "arrayvec")]
 mod arrayvec;
+mod base32;
+mod base58;
+mod base64;
+mod bits;
 #[cfg(feature = "bytes")]
 mod bytes;
+mod checksum;
+mod cobs;
+mod counting;
+#[cfg(feature = "digest")]
+mod digest;
 mod errors;
+mod escape;
 mod fmt;
+mod hash;
+mod hex;
+mod limit;
+mod percent;
 mod primitives;
 mod size;
 mod slices;
 #[cfg(feature = "std")]
 mod std_io;
+mod tee;
 
+pub use base32::Base32Alphabet;
+pub use base32::Base32Encoder;
+pub use base58::Base58Encoder;
+pub use base64::Base64Alphabet;
+pub use base64::Base64Encoder;
+pub use bits::BitEncoder;
+pub use bits::BitOrder;
+pub use bits::BitSizeEncoder;
+pub use bits::Lsb0;
+pub use bits::Msb0;
+pub use checksum::ChecksumEncoder;
+pub use cobs::CobsEncoder;
+pub use counting::Counting;
+#[cfg(feature = "digest")]
+pub use digest::DigestEncoder;
+pub use errors::Base58Error;
 pub use errors::InsufficientSpace;
+pub use errors::LimitError;
+pub use errors::PatchError;
+pub use errors::TeeError;
+pub use escape::HdlcEncoder;
+pub use escape::SlipEncoder;
+pub use hash::HashEncoder;
+pub use hex::HexEncoder;
+pub use limit::Limit;
+pub use limit::Truncate;
+pub use percent::AsciiSet;
+pub use percent::PercentEncoder;
 pub use size::SizeEncoder;
 #[cfg(feature = "std")]
 pub use std_io::IoEncoder;
+pub use tee::Tee;
@@ -0,0 +1,325 @@
+use crate::BaseEncoder;
+use crate::ByteEncoder;
+use crate::StrEncoder;
+
+/// A set of ASCII bytes that a [`PercentEncoder`] escapes.
+///
+/// Bytes outside of ASCII are always escaped. The predefined sets follow the
+/// percent-encode sets of the [URL Standard](https://url.spec.whatwg.org/#percent-encoded-bytes),
+/// with `%` added, since they are meant for raw values that are not encoded
+/// yet. Custom sets are built from them with [`add`](AsciiSet::add) and
+/// [`remove`](AsciiSet::remove).
+///
+/// # Example
+///
+/// ```
+/// use encode::encoders::AsciiSet;
+///
+/// const MATRIX_PARAM: AsciiSet = AsciiSet::PATH_SEGMENT.add(b';').add(b',');
+/// assert!(MATRIX_PARAM.contains(b';'));
+/// assert!(!MATRIX_PARAM.contains(b'a'));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct AsciiSet {
+    mask: u128,
+    space_as_plus: bool,
+}
+
+impl AsciiSet {
+    /// The set that escapes no ASCII byte.
+    pub const EMPTY: Self = Self {
+        mask: 0,
+        space_as_plus: false,
+    };
+    /// The C0 controls and DEL.
+    pub const CONTROLS: Self = Self {
+        mask: 0xFFFF_FFFF | 1 << 0x7F,
+        space_as_plus: false,
+    };
+    /// Every ASCII byte except letters and digits.
+    pub const NON_ALPHANUMERIC: Self = Self {
+        mask: !(0x3FF << b'0' | 0x03FF_FFFF << b'A' | 0x03FF_FFFF << b'a'),
+        space_as_plus: false,
+    };
+    /// The set for a single segment of a URL path.
+    ///
+    /// It escapes the controls, space, `"`, `#`, `%`, `/`, `<`, `>`, `?`,
+    /// `` ` ``, `{` and `}`.
+    pub const PATH_SEGMENT: Self = Self::CONTROLS
+        .add(b' ')
+        .add(b'"')
+        .add(b'#')
+        .add(b'%')
+        .add(b'/')
+        .add(b'<')
+        .add(b'>')
+        .add(b'?')
+        .add(b'`')
+        .add(b'{')
+        .add(b'}');
+    /// The set for a key or a value of a URL query.
+    ///
+    /// It escapes the controls, space, `"`, `#`, `%`, `&`, `+`, `<`, `=` and
+    /// `>`, so a key or value cannot change the structure of the query.
+    pub const QUERY: Self = Self::CONTROLS
+        .add(b' ')
+        .add(b'"')
+        .add(b'#')
+        .add(b'%')
+        .add(b'&')
+        .add(b'+')
+        .add(b'<')
+        .add(b'=')
+        .add(b'>');
+    /// The set for the username or password of a URL.
+    ///
+    /// It escapes everything [`PATH_SEGMENT`](AsciiSet::PATH_SEGMENT) does,
+    /// as well as `:`, `;`, `=`, `@`, `[`, `\`, `]`, `^` and `|`.
+    pub const USERINFO: Self = Self::PATH_SEGMENT
+        .add(b':')
+        .add(b';')
+        .add(b'=')
+        .add(b'@')
+        .add(b'[')
+        .add(b'\\')
+        .add(b']')
+        .add(b'^')
+        .add(b'|');
+    /// The set for keys and values of an `application/x-www-form-urlencoded`
+    /// body.
+    ///
+    /// It escapes every byte except letters, digits, `*`, `-`, `.` and `_`,
+    /// and writes space as `+`.
+    pub const FORM: Self = Self::NON_ALPHANUMERIC
+        .remove(b'*')
+        .remove(b'-')
+        .remove(b'.')
+        .remove(b'_')
+        .space_as_plus();
+
+    /// Returns a copy of the set that also escapes `byte`.
+    ///
+    /// Bytes outside of ASCII are always escaped, so adding them has no
+    /// effect.
+    #[inline]
+    #[must_use]
+    pub const fn add(mut self, byte: u8) -> Self {
+        if byte.is_ascii() {
+            self.mask |= 1 << byte;
+        }
+        self
+    }
+    /// Returns a copy of the set that does not escape `byte`.
+    ///
+    /// Bytes outside of ASCII are always escaped, so removing them has no
+    /// effect.
+    #[inline]
+    #[must_use]
+    pub const fn remove(mut self, byte: u8) -> Self {
+        if byte.is_ascii() {
+            self.mask &= !(1 << byte);
+        }
+        self
+    }
+    /// Returns a copy of the set that escapes space as `+` instead of `%20`.
+    #[inline]
+    #[must_use]
+    pub const fn space_as_plus(mut self) -> Self {
+        self.space_as_plus = true;
+        self.add(b' ')
+    }
+    /// Returns whether the set escapes `byte`.
+    #[inline]
+    #[must_use]
+    pub const fn contains(&self, byte: u8) -> bool {
+        !byte.is_ascii() || self.mask & 1 << byte != 0
+    }
+}
+
+impl Default for AsciiSet {
+    #[inline]
+    fn default() -> Self {
+        Self::EMPTY
+    }
+}
+
+/// An encoder that writes the bytes written through it into a [`StrEncoder`],
+/// [percent-encoding](https://en.wikipedia.org/wiki/Percent-encoding) the
+/// bytes of an [`AsciiSet`].
+///
+/// Escaped bytes are written as `%` followed by two uppercase hexadecimal
+/// digits. Runs of bytes that are not escaped are passed on as they are, so
+/// nothing is buffered or allocated.
+///
+/// # Example
+///
+/// ```
+/// # #[cfg(feature = "alloc")] {
+/// use encode::Encodable;
+/// use encode::encoders::{AsciiSet, PercentEncoder};
+///
+/// let mut text = String::new();
+/// "a b&c=ü".encode(&mut PercentEncoder::new(&mut text, AsciiSet::QUERY)).unwrap();
+/// assert_eq!(text, "a%20b%26c%3D%C3%BC");
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct PercentEncoder<'a, E> {
+    encoder: &'a mut E,
+    set: AsciiSet,
+}
+
+impl<'a, E> PercentEncoder<'a, E> {
+    /// Creates a new [`PercentEncoder`] that escapes the bytes of `set`.
+    #[inline]
+    #[must_use]
+    pub fn new(encoder: &'a mut E, set: AsciiSet) -> Self {
+        Self { encoder, set }
+    }
+    /// Returns a reference to the inner encoder.
+    #[inline]
+    #[must_use]
+    pub fn get_ref(&self) -> &E {
+        self.encoder
+    }
+    /// Returns the set of escaped bytes.
+    #[inline]
+    #[must_use]
+    pub const fn set(&self) -> &AsciiSet {
+        &self.set
+    }
+}
+
+impl<E: StrEncoder> PercentEncoder<'_, E> {
+    /// Writes a run of bytes that are not escaped.
+    #[inline]
+    fn put_run(&mut self, run: &[u8]) -> Result<(), E::Error> {
+        if run.is_empty() {
+            return Ok(());
+        }
+        // Bytes outside of ASCII are always escaped, so runs are valid UTF-8.
+        self.encoder
+            .put_str(core::str::from_utf8(run).unwrap_or_default())
+    }
+    /// Writes the escaped form of `byte`.
+    #[inline]
+    fn put_escaped(&mut self, byte: u8) -> Result<(), E::Error> {
+        const DIGITS: &[u8; 16] = b"0123456789ABCDEF";
+
+        if byte == b' ' && self.set.space_as_plus {
+            return self.encoder.put_str("+");
+        }
+        let escaped = [
+            b'%',
+            DIGITS[usize::from(byte >> 4)],
+            DIGITS[usize::from(byte & 0x0F)],
+        ];
+        self.encoder
+            .put_str(core::str::from_utf8(&escaped).unwrap_or_default())
+    }
+}
+
+impl<E: BaseEncoder> BaseEncoder for PercentEncoder<'_, E> {
+    type Error = E::Error;
+}
+
+impl<E: StrEncoder> ByteEncoder for PercentEncoder<'_, E> {
+    #[inline]
+    fn put_slice(&mut self, slice: &[u8]) -> Result<(), Self::Error> {
+        let mut start = 0;
+        for (i, &byte) in slice.iter().enumerate() {
+            if self.set.contains(byte) {
+                self.put_run(&slice[start..i])?;
+                self.put_escaped(byte)?;
+                start = i + 1;
+            }
+        }
+        self.put_run(&slice[start..])
+    }
+
+    #[inline]
+    fn put_byte(&mut self, byte: u8) -> Result<(), Self::Error> {
+        self.put_slice(&[byte])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+    use crate::encoders::InsufficientSpace;
+    use crate::Encodable;
+
+    const BUF_SIZE: usize = 128;
+
+    const PRINTABLE: &str =
+        " !\"#$%&'()*+,-./0123456789:;<=>?@ABCDEFGHIJKLMNOPQRSTUVWXYZ[\\]^_`abcdefghijklmnopqrstuvwxyz{|}~";
+
+    #[rstest]
+    #[case::empty(AsciiSet::EMPTY, b"")]
+    #[case::controls(AsciiSet::CONTROLS, b"")]
+    #[case::non_alphanumeric(AsciiSet::NON_ALPHANUMERIC, b" !\"#$%&'()*+,-./:;<=>?@[\\]^_`{|}~")]
+    #[case::path_segment(AsciiSet::PATH_SEGMENT, b" \"#%/<>?`{}")]
+    #[case::query(AsciiSet::QUERY, b" \"#%&+<=>")]
+    #[case::userinfo(AsciiSet::USERINFO, b" \"#%/:;<=>?@[\\]^`{|}")]
+    #[case::form(AsciiSet::FORM, b" !\"#$%&'()+,/:;<=>?@[\\]^`{|}~")]
+    fn assert_that_sets_contain_the_expected_printable_bytes(
+        #[case] set: AsciiSet,
+        #[case] expected: &[u8],
+    ) {
+        for byte in PRINTABLE.bytes() {
+            assert_eq!(
+                set.contains(byte),
+                expected.contains(&byte),
+                "{:?}",
+                char::from(byte)
+            );
+        }
+    }
+
+    #[test]
+    fn assert_that_controls_and_non_ascii_bytes_are_always_escaped() {
+        for byte in (0x00..0x20).chain([0x7F]) {
+            assert!(AsciiSet::CONTROLS.contains(byte));
+            assert!(!AsciiSet::EMPTY.contains(byte));
+        }
+        for byte in 0x80..=0xFF {
+            assert!(AsciiSet::EMPTY.contains(byte));
+            assert!(AsciiSet::EMPTY.remove(byte).contains(byte));
+        }
+    }
+
+    #[rstest]
+    #[case::plain(AsciiSet::QUERY, b"abc", b"abc")]
+    #[case::query(AsciiSet::QUERY, b"a b&c=d+e#", b"a%20b%26c%3Dd%2Be%23")]
+    #[case::path_segment(AsciiSet::PATH_SEGMENT, b"a/b c?", b"a%2Fb%20c%3F")]
+    #[case::form(AsciiSet::FORM, b"a b&c~", b"a+b%26c%7E")]
+    #[case::non_ascii(AsciiSet::EMPTY, b"\xC3\xA9\0", b"%C3%A9\0")]
+    #[case::custom(AsciiSet::EMPTY.add(b'x').space_as_plus(), b"x y", b"%78+y")]
+    fn assert_that_bytes_are_percent_encoded(
+        #[case] set: AsciiSet,
+        #[case] value: &[u8],
+        #[case] expected: &[u8],
+    ) {
+        let mut buf = [0u8; BUF_SIZE];
+        let mut encoder = &mut buf as &mut [u8];
+        value
+            .encode(&mut PercentEncoder::new(&mut encoder, set))
+            .unwrap();
+        let written = BUF_SIZE - encoder.len();
+
+        assert_eq!(&buf[..written], expected);
+    }
+
+    #[test]
+    fn assert_that_encoder_errors_are_propagated() {
+        let mut buf = [0u8; 4];
+        let mut encoder = &mut buf as &mut [u8];
+
+        assert_eq!(
+            "ab cd".encode(&mut PercentEncoder::new(&mut encoder, AsciiSet::QUERY)),
+            Err(InsufficientSpace)
+        );
+    }
+}
@@ -1,5 +1,7 @@
+use super::PatchError;
 use crate::BaseEncoder;
 use crate::ByteEncoder;
+use crate::PatchableEncoder;
 use core::convert::Infallible;
 
 impl BaseEncoder for () {
@@ -18,8 +20,36 @@ impl ByteEncoder for () {
     }
 }
 
+impl PatchableEncoder for () {
+    /// The number of reserved bytes.
+    type Placeholder = usize;
+
+    #[inline]
+    fn reserve_placeholder(&mut self, len: usize) -> Result<Self::Placeholder, Self::Error> {
+        Ok(len)
+    }
+
+    #[inline]
+    fn written_since(&self, _: &Self::Placeholder) -> usize {
+        0
+    }
+
+    #[inline]
+    fn patch(
+        &mut self,
+        placeholder: Self::Placeholder,
+        bytes: &[u8],
+    ) -> Result<(), PatchError<Self::Error>> {
+        if placeholder != bytes.len() {
+            return Err(PatchError::SizeMismatch);
+        }
+        Ok(())
+    }
+}
+
 #[cfg(test)]
 mod tests {
+    use super::*;
     use crate::Encodable;
 
     #[test]
@@ -28,4 +58,15 @@ mod tests {
         let encodable = ("hello", 0u8);
         encodable.encode(&mut encoder).unwrap();
     }
+
+    #[test]
+    fn assert_that_unit_rejects_patches_of_the_wrong_size() {
+        let mut encoder = ();
+        let placeholder = encoder.reserve_placeholder(2).unwrap();
+        assert_eq!(encoder.patch(placeholder, &[0, 0]), Ok(()));
+        assert_eq!(
+            encoder.patch(placeholder, &[0]),
+            Err(PatchError::SizeMismatch)
+        );
+    }
 }
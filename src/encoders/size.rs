@@ -1,7 +1,10 @@
 use core::fmt::Write;
+use core::ops::Range;
 
+use super::PatchError;
 use crate::BaseEncoder;
 use crate::ByteEncoder;
+use crate::PatchableEncoder;
 
 /// An encoder that counts the size of the encoded data.
 ///
@@ -82,6 +85,35 @@ impl ByteEncoder for SizeEncoder {
     }
 }
 
+impl PatchableEncoder for SizeEncoder {
+    /// The offsets of the reserved bytes.
+    type Placeholder = Range<usize>;
+
+    #[inline]
+    fn reserve_placeholder(&mut self, len: usize) -> Result<Self::Placeholder, Self::Error> {
+        let start = self.size;
+        self.size += len;
+        Ok(start..self.size)
+    }
+
+    #[inline]
+    fn written_since(&self, placeholder: &Self::Placeholder) -> usize {
+        self.size - placeholder.end
+    }
+
+    #[inline]
+    fn patch(
+        &mut self,
+        placeholder: Self::Placeholder,
+        bytes: &[u8],
+    ) -> Result<(), PatchError<Self::Error>> {
+        if placeholder.len() != bytes.len() {
+            return Err(PatchError::SizeMismatch);
+        }
+        Ok(())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -114,4 +146,24 @@ mod tests {
         let size: usize = encoder.into();
         assert_eq!(size, 5);
     }
+
+    #[test]
+    fn assert_that_reserved_bytes_are_counted() {
+        let mut encoder = SizeEncoder::new();
+        let placeholder = encoder.reserve_placeholder(4).unwrap();
+        encoder.put_slice(b"hello").unwrap();
+        assert_eq!(encoder.written_since(&placeholder), 5);
+        encoder.patch(placeholder, &[0; 4]).unwrap();
+        assert_eq!(encoder.size(), 9);
+    }
+
+    #[test]
+    fn assert_that_patches_of_the_wrong_size_are_rejected() {
+        let mut encoder = SizeEncoder::new();
+        let placeholder = encoder.reserve_placeholder(4).unwrap();
+        assert_eq!(
+            encoder.patch(placeholder, &[0; 2]),
+            Err(PatchError::SizeMismatch)
+        );
+    }
 }
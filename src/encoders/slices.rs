@@ -1,6 +1,8 @@
 use super::InsufficientSpace;
+use super::PatchError;
 use crate::BaseEncoder;
 use crate::ByteEncoder;
+use crate::PatchableEncoder;
 
 impl BaseEncoder for &mut [u8] {
     type Error = InsufficientSpace;
@@ -23,9 +25,44 @@ impl ByteEncoder for &mut [u8] {
     }
 }
 
+impl<'a> PatchableEncoder for &'a mut [u8] {
+    /// The reserved bytes and the space that was left after them.
+    type Placeholder = (&'a mut [u8], usize);
+
+    #[inline]
+    fn reserve_placeholder(&mut self, len: usize) -> Result<Self::Placeholder, Self::Error> {
+        let (a, b) = core::mem::take(self)
+            .split_at_mut_checked(len)
+            .ok_or(InsufficientSpace)?;
+        *self = b;
+        Ok((a, self.len()))
+    }
+
+    #[inline]
+    fn written_since(&self, placeholder: &Self::Placeholder) -> usize {
+        placeholder.1 - self.len()
+    }
+
+    #[inline]
+    fn patch(
+        &mut self,
+        placeholder: Self::Placeholder,
+        bytes: &[u8],
+    ) -> Result<(), PatchError<Self::Error>> {
+        if placeholder.0.len() != bytes.len() {
+            return Err(PatchError::SizeMismatch);
+        }
+        placeholder.0.copy_from_slice(bytes);
+        Ok(())
+    }
+}
+
 #[cfg(test)]
 mod test {
+    use super::PatchError;
+    use crate::ByteEncoder;
     use crate::Encodable;
+    use crate::PatchableEncoder;
 
     #[test]
     fn assert_that_encoding_something_into_an_empty_slice_always_fails() {
@@ -54,4 +91,38 @@ mod test {
             "The buffer should contain the encoded string"
         );
     }
+
+    #[test]
+    fn assert_that_slice_reserved_bytes_can_be_patched() {
+        const BUF_SIZE: usize = 64;
+        let mut buf = [0u8; BUF_SIZE];
+        let mut encoder = &mut buf as &mut [u8];
+
+        let placeholder = encoder.reserve_placeholder(1).unwrap();
+        encoder.put_slice(b"hello").unwrap();
+        assert_eq!(encoder.written_since(&placeholder), 5);
+        encoder.patch(placeholder, &[5]).unwrap();
+        let written = BUF_SIZE - encoder.len();
+
+        assert_eq!(&buf[..written], b"\x05hello");
+    }
+
+    #[test]
+    fn assert_that_slice_patches_of_the_wrong_size_are_rejected() {
+        let mut buf = [0u8; 4];
+        let mut encoder = &mut buf as &mut [u8];
+
+        let placeholder = encoder.reserve_placeholder(2).unwrap();
+        assert_eq!(
+            encoder.patch(placeholder, &[5]),
+            Err(PatchError::SizeMismatch)
+        );
+    }
+
+    #[test]
+    fn assert_that_reserving_more_than_the_remaining_space_fails() {
+        let mut buf = [0u8; 4];
+        let mut encoder = &mut buf as &mut [u8];
+        assert!(encoder.reserve_placeholder(5).is_err());
+    }
 }
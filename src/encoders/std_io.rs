@@ -88,7 +88,7 @@ where
 
 #[cfg(test)]
 mod tests {
-    use std::borrow::BorrowMut;
+    use core::borrow::BorrowMut;
     use std::io::Cursor;
 
     use super::*;
@@ -129,7 +129,7 @@ mod tests {
 
     #[test]
     fn assert_that_io_encoder_borrow_works() {
-        use std::borrow::Borrow;
+        use core::borrow::Borrow;
         let encoder = IoEncoder(Cursor::new(Vec::new()));
         let _: &Cursor<Vec<u8>> = encoder.borrow();
     }
@@ -143,12 +143,12 @@ mod tests {
     #[test]
     fn assert_that_io_encoder_deref_works() {
         let encoder = IoEncoder(Cursor::new(Vec::new()));
-        let _: &Cursor<Vec<u8>> = &*encoder;
+        let _: &Cursor<Vec<u8>> = &encoder;
     }
 
     #[test]
     fn assert_that_io_encoder_deref_mut_works() {
         let mut encoder = IoEncoder(Cursor::new(Vec::new()));
-        let _: &mut Cursor<Vec<u8>> = &mut *encoder;
+        let _: &mut Cursor<Vec<u8>> = &mut encoder;
     }
 }
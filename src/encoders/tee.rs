@@ -0,0 +1,135 @@
+use super::TeeError;
+use crate::BaseEncoder;
+use crate::ByteEncoder;
+
+/// An encoder that writes everything into two encoders at once.
+///
+/// Every byte is written into the first encoder and then into the second one.
+/// If the first encoder fails, the byte is not written into the second one.
+/// Errors are reported as a [`TeeError`] that tells which encoder failed.
+///
+/// Combine it with a [`SizeEncoder`](super::SizeEncoder) or a
+/// [`HashEncoder`](super::HashEncoder) to measure or hash the encoded data
+/// while writing it.
+///
+/// # Example
+///
+/// ```
+/// # #[cfg(feature = "std")] {
+/// use encode::Encodable;
+/// use encode::encoders::{IoEncoder, Tee};
+///
+/// let socket = IoEncoder(std::io::sink());
+/// let mut encoder = Tee::new(socket, Vec::new());
+/// "hello".encode(&mut encoder).unwrap();
+/// let (_, log) = encoder.into_parts();
+/// assert_eq!(log, b"hello");
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Tee<A, B> {
+    first: A,
+    second: B,
+}
+
+impl<A, B> Tee<A, B> {
+    /// Creates a new [`Tee`] encoder.
+    #[inline]
+    #[must_use]
+    pub const fn new(first: A, second: B) -> Self {
+        Self { first, second }
+    }
+    /// Returns a reference to the first encoder.
+    #[inline]
+    #[must_use]
+    pub const fn first(&self) -> &A {
+        &self.first
+    }
+    /// Returns a reference to the second encoder.
+    #[inline]
+    #[must_use]
+    pub const fn second(&self) -> &B {
+        &self.second
+    }
+    /// Consumes the encoder and returns both inner encoders.
+    #[inline]
+    #[must_use]
+    pub fn into_parts(self) -> (A, B) {
+        (self.first, self.second)
+    }
+}
+
+impl<A: BaseEncoder, B: BaseEncoder> BaseEncoder for Tee<A, B> {
+    type Error = TeeError<A::Error, B::Error>;
+}
+
+impl<A: ByteEncoder, B: ByteEncoder> ByteEncoder for Tee<A, B> {
+    #[inline]
+    fn put_slice(&mut self, slice: &[u8]) -> Result<(), Self::Error> {
+        self.first.put_slice(slice).map_err(TeeError::First)?;
+        self.second.put_slice(slice).map_err(TeeError::Second)
+    }
+
+    #[inline]
+    fn put_byte(&mut self, byte: u8) -> Result<(), Self::Error> {
+        self.first.put_byte(byte).map_err(TeeError::First)?;
+        self.second.put_byte(byte).map_err(TeeError::Second)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::encoders::InsufficientSpace;
+    use crate::encoders::SizeEncoder;
+    use crate::Encodable;
+
+    const BUF_SIZE: usize = 16;
+
+    #[test]
+    fn assert_that_both_encoders_receive_the_same_bytes() {
+        let mut a = [0u8; BUF_SIZE];
+        let mut b = [0u8; BUF_SIZE];
+        let mut encoder = Tee::new(&mut a as &mut [u8], &mut b as &mut [u8]);
+        ("hello", b' ', b"world").encode(&mut encoder).unwrap();
+        let (a_rest, b_rest) = encoder.into_parts();
+        let (a_written, b_written) = (BUF_SIZE - a_rest.len(), BUF_SIZE - b_rest.len());
+
+        assert_eq!(&a[..a_written], b"hello world");
+        assert_eq!(&b[..b_written], b"hello world");
+    }
+
+    #[test]
+    fn assert_that_tee_composes_with_size_encoder() {
+        let mut buf = [0u8; BUF_SIZE];
+        let mut encoder = Tee::new(&mut buf as &mut [u8], SizeEncoder::new());
+        "hello".encode(&mut encoder).unwrap();
+
+        assert_eq!(encoder.second().size(), 5);
+        assert_eq!(encoder.first().len(), BUF_SIZE - 5);
+    }
+
+    #[test]
+    fn assert_that_errors_report_the_failing_encoder() {
+        let mut small = [0u8; 2];
+        let mut large = [0u8; BUF_SIZE];
+
+        let mut encoder = Tee::new(&mut small as &mut [u8], &mut large as &mut [u8]);
+        assert_eq!(
+            "hello".encode(&mut encoder),
+            Err(TeeError::First(InsufficientSpace))
+        );
+        assert_eq!(
+            encoder.second().len(),
+            BUF_SIZE,
+            "Nothing reached the second encoder"
+        );
+
+        let mut small = [0u8; 2];
+        let mut encoder = Tee::new(SizeEncoder::new(), &mut small as &mut [u8]);
+        assert_eq!(
+            encoder.put_byte(0).and_then(|()| encoder.put_slice(b"ab")),
+            Err(TeeError::Second(InsufficientSpace))
+        );
+    }
+}
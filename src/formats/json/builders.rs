@@ -0,0 +1,272 @@
+use core::iter::Map;
+
+use super::JsonEncoder;
+use super::JsonError;
+use super::JsonString;
+use crate::combinators::Separated;
+use crate::Encodable;
+
+/// Encodes an iterator of encodables as a JSON array.
+///
+/// The elements are streamed straight from the iterator, which is cloned
+/// once to find out whether it is empty and once more to encode it. Each
+/// element must itself encode as JSON, such as a [`JsonNumber`] or another
+/// [`JsonArray`], and whitespace is decided by the [`JsonEncoder`].
+///
+/// [`JsonNumber`]: super::JsonNumber
+///
+/// # Example
+///
+/// ```
+/// # #[cfg(feature = "alloc")] {
+/// use encode::Encodable;
+/// use encode::formats::json::{CompactJson, JsonArray, JsonString};
+///
+/// let names = ["alice", "bob"];
+/// let mut text = String::new();
+/// JsonArray::new(names.iter().map(JsonString::new))
+///     .encode(&mut CompactJson::new(&mut text))
+///     .unwrap();
+/// assert_eq!(text, r#"["alice","bob"]"#);
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct JsonArray<I> {
+    elements: I,
+}
+
+impl<I> JsonArray<I> {
+    /// Creates a new [`JsonArray`].
+    #[inline]
+    #[must_use]
+    pub const fn new(elements: I) -> Self {
+        Self { elements }
+    }
+    /// Consumes the [`JsonArray`] and returns the inner iterator.
+    #[inline]
+    #[must_use]
+    pub fn into_inner(self) -> I {
+        self.elements
+    }
+}
+
+impl<I, Encoder> Encodable<Encoder> for JsonArray<I>
+where
+    I: IntoIterator + Clone,
+    I::Item: Encodable<Encoder, Error = JsonError<Encoder::Error>>,
+    Encoder: JsonEncoder,
+{
+    type Error = JsonError<Encoder::Error>;
+
+    #[inline]
+    fn encode(&self, encoder: &mut Encoder) -> Result<(), Self::Error> {
+        let empty = self.elements.clone().into_iter().next().is_none();
+        encoder.begin_container("[", empty)?;
+        Separated::new(self.elements.clone(), ValueSeparator).encode(encoder)?;
+        encoder.end_container("]", empty)?;
+        Ok(())
+    }
+}
+
+/// Encodes an iterator of name and value pairs as a JSON object.
+///
+/// Names are escaped with [`JsonString`] and values must encode as JSON. Like
+/// [`JsonArray`], the members are streamed straight from the iterator, so
+/// references to maps work as they are, and slices of pairs only need
+/// [`copied`](Iterator::copied) or a [`map`](Iterator::map).
+///
+/// Duplicate names are written as given; JSON leaves their meaning up to the
+/// reader.
+///
+/// # Example
+///
+/// ```
+/// # #[cfg(feature = "alloc")] {
+/// use std::collections::BTreeMap;
+///
+/// use encode::Encodable;
+/// use encode::formats::json::{CompactJson, JsonBool, JsonObject};
+///
+/// let flags = BTreeMap::from([("debug", JsonBool::new(true)), ("quiet", JsonBool::new(false))]);
+/// let mut text = String::new();
+/// JsonObject::new(&flags)
+///     .encode(&mut CompactJson::new(&mut text))
+///     .unwrap();
+/// assert_eq!(text, r#"{"debug":true,"quiet":false}"#);
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct JsonObject<I> {
+    members: I,
+}
+
+impl<I> JsonObject<I> {
+    /// Creates a new [`JsonObject`].
+    #[inline]
+    #[must_use]
+    pub const fn new(members: I) -> Self {
+        Self { members }
+    }
+    /// Consumes the [`JsonObject`] and returns the inner iterator.
+    #[inline]
+    #[must_use]
+    pub fn into_inner(self) -> I {
+        self.members
+    }
+}
+
+impl<I, K, V, Encoder> Encodable<Encoder> for JsonObject<I>
+where
+    I: IntoIterator<Item = (K, V)> + Clone,
+    K: AsRef<str>,
+    V: Encodable<Encoder, Error = JsonError<Encoder::Error>>,
+    Encoder: JsonEncoder,
+{
+    type Error = JsonError<Encoder::Error>;
+
+    #[inline]
+    fn encode(&self, encoder: &mut Encoder) -> Result<(), Self::Error> {
+        let empty = self.members.clone().into_iter().next().is_none();
+        encoder.begin_container("{", empty)?;
+        Separated::new(Members(self.members.clone()), ValueSeparator).encode(encoder)?;
+        encoder.end_container("}", empty)?;
+        Ok(())
+    }
+}
+
+/// Turns name and value pairs into `name: value` encodables.
+#[derive(Debug, Clone)]
+struct Members<I>(I);
+
+impl<I, K, V> IntoIterator for Members<I>
+where
+    I: IntoIterator<Item = (K, V)>,
+{
+    type Item = (JsonString<K>, NameSeparator, V);
+    type IntoIter = Map<I::IntoIter, fn((K, V)) -> Self::Item>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.0
+            .into_iter()
+            .map(|(name, value)| (JsonString::new(name), NameSeparator, value))
+    }
+}
+
+/// Writes [`JsonEncoder::value_separator`] between elements.
+#[derive(Debug, Clone, Copy)]
+struct ValueSeparator;
+
+impl<Encoder: JsonEncoder> Encodable<Encoder> for ValueSeparator {
+    type Error = JsonError<Encoder::Error>;
+
+    #[inline]
+    fn encode(&self, encoder: &mut Encoder) -> Result<(), Self::Error> {
+        encoder.value_separator()?;
+        Ok(())
+    }
+}
+
+/// Writes [`JsonEncoder::name_separator`] between a name and its value.
+#[derive(Debug, Clone, Copy)]
+struct NameSeparator;
+
+impl<Encoder: JsonEncoder> Encodable<Encoder> for NameSeparator {
+    type Error = JsonError<Encoder::Error>;
+
+    #[inline]
+    fn encode(&self, encoder: &mut Encoder) -> Result<(), Self::Error> {
+        encoder.name_separator()?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+    use crate::encoders::InsufficientSpace;
+    use crate::formats::json::CompactJson;
+    use crate::formats::json::JsonNull;
+    use crate::formats::json::JsonNumber;
+    use crate::formats::json::PrettyJson;
+
+    const BUF_SIZE: usize = 128;
+
+    #[rstest]
+    #[case::empty(&[], b"[]")]
+    #[case::single(&[1], b"[1]")]
+    #[case::multiple(&[1, 2, 3], b"[1,2,3]")]
+    fn assert_that_arrays_are_encoded(#[case] numbers: &[i32], #[case] expected: &[u8]) {
+        let mut buf = [0u8; BUF_SIZE];
+        let mut encoder = &mut buf as &mut [u8];
+        JsonArray::new(numbers.iter().copied().map(JsonNumber::new))
+            .encode(&mut CompactJson::new(&mut encoder))
+            .unwrap();
+        let written = BUF_SIZE - encoder.len();
+
+        assert_eq!(&buf[..written], expected);
+    }
+
+    #[rstest]
+    #[case::empty(&[], b"{}")]
+    #[case::single(&[("a", 1)], br#"{"a":1}"#)]
+    #[case::multiple(&[("a", 1), ("b\n", 2)], br#"{"a":1,"b\n":2}"#)]
+    fn assert_that_objects_are_encoded(#[case] members: &[(&str, i32)], #[case] expected: &[u8]) {
+        let mut buf = [0u8; BUF_SIZE];
+        let mut encoder = &mut buf as &mut [u8];
+        JsonObject::new(
+            members
+                .iter()
+                .map(|&(name, value)| (name, JsonNumber::new(value))),
+        )
+        .encode(&mut CompactJson::new(&mut encoder))
+        .unwrap();
+        let written = BUF_SIZE - encoder.len();
+
+        assert_eq!(&buf[..written], expected);
+    }
+
+    #[test]
+    fn assert_that_nested_containers_are_pretty_printed() {
+        let rows = [[1, 2], [3, 4]].map(|row| JsonArray::new(row.map(JsonNumber::new)));
+        let object = JsonObject::new([
+            ("rows", JsonArray::new(rows.as_slice())),
+            ("none", JsonArray::new(&rows[..0])),
+        ]);
+
+        let mut buf = [0u8; BUF_SIZE];
+        let mut encoder = &mut buf as &mut [u8];
+        object.encode(&mut PrettyJson::new(&mut encoder)).unwrap();
+        let written = BUF_SIZE - encoder.len();
+
+        assert_eq!(
+            &buf[..written],
+            b"{\n  \"rows\": [\n    [\n      1,\n      2\n    ],\n    [\n      3,\n      4\n    ]\n  ],\n  \"none\": []\n}"
+        );
+    }
+
+    #[test]
+    fn assert_that_element_errors_are_propagated() {
+        let mut buf = [0u8; BUF_SIZE];
+        let mut encoder = &mut buf as &mut [u8];
+
+        assert_eq!(
+            JsonArray::new([1.0, f64::NAN].map(JsonNumber::new))
+                .encode(&mut CompactJson::new(&mut encoder)),
+            Err(JsonError::NonFiniteNumber)
+        );
+    }
+
+    #[test]
+    fn assert_that_encoder_errors_are_propagated() {
+        let mut buf = [0u8; 6];
+        let mut encoder = &mut buf as &mut [u8];
+
+        assert_eq!(
+            JsonObject::new([("key", JsonNull)]).encode(&mut CompactJson::new(&mut encoder)),
+            Err(JsonError::Encoder(InsufficientSpace))
+        );
+    }
+}
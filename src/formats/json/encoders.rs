@@ -0,0 +1,277 @@
+use crate::BaseEncoder;
+use crate::StrEncoder;
+
+/// A [`StrEncoder`] that decides the whitespace written between JSON tokens.
+///
+/// [`JsonArray`](super::JsonArray) and [`JsonObject`](super::JsonObject) call
+/// these methods around their elements instead of writing the structural
+/// characters themselves, so the same encodables can produce either
+/// [`CompactJson`] or [`PrettyJson`] output. The method names follow the
+/// grammar of [RFC 8259](https://www.rfc-editor.org/rfc/rfc8259#section-2).
+///
+/// Implement this trait by hand to write JSON objects with members of
+/// different types, or to produce a custom layout.
+pub trait JsonEncoder: StrEncoder {
+    /// Writes the opening bracket of an array or object and enters it.
+    ///
+    /// `empty` tells whether the container has no elements.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the inner encoder fails.
+    fn begin_container(&mut self, bracket: &str, empty: bool) -> Result<(), Self::Error>;
+    /// Writes the separator between two elements of an array or object.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the inner encoder fails.
+    fn value_separator(&mut self) -> Result<(), Self::Error>;
+    /// Writes the separator between the name and the value of an object
+    /// member.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the inner encoder fails.
+    fn name_separator(&mut self) -> Result<(), Self::Error>;
+    /// Leaves an array or object and writes its closing bracket.
+    ///
+    /// `empty` must match the value passed to
+    /// [`begin_container`](JsonEncoder::begin_container).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the inner encoder fails.
+    fn end_container(&mut self, bracket: &str, empty: bool) -> Result<(), Self::Error>;
+}
+
+/// A [`JsonEncoder`] that writes JSON without any whitespace.
+///
+/// # Example
+///
+/// ```
+/// # #[cfg(feature = "alloc")] {
+/// use encode::Encodable;
+/// use encode::formats::json::{CompactJson, JsonArray, JsonBool};
+///
+/// let mut text = String::new();
+/// JsonArray::new([true, false].map(JsonBool::new))
+///     .encode(&mut CompactJson::new(&mut text))
+///     .unwrap();
+/// assert_eq!(text, "[true,false]");
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct CompactJson<'a, E> {
+    encoder: &'a mut E,
+}
+
+impl<'a, E> CompactJson<'a, E> {
+    /// Creates a new [`CompactJson`].
+    #[inline]
+    #[must_use]
+    pub fn new(encoder: &'a mut E) -> Self {
+        Self { encoder }
+    }
+    /// Returns a reference to the inner encoder.
+    #[inline]
+    #[must_use]
+    pub fn get_ref(&self) -> &E {
+        self.encoder
+    }
+}
+
+impl<E: BaseEncoder> BaseEncoder for CompactJson<'_, E> {
+    type Error = E::Error;
+}
+
+impl<E: StrEncoder> StrEncoder for CompactJson<'_, E> {
+    #[inline]
+    fn put_str(&mut self, string: &str) -> Result<(), Self::Error> {
+        self.encoder.put_str(string)
+    }
+}
+
+impl<E: StrEncoder> JsonEncoder for CompactJson<'_, E> {
+    #[inline]
+    fn begin_container(&mut self, bracket: &str, _empty: bool) -> Result<(), Self::Error> {
+        self.encoder.put_str(bracket)
+    }
+
+    #[inline]
+    fn value_separator(&mut self) -> Result<(), Self::Error> {
+        self.encoder.put_str(",")
+    }
+
+    #[inline]
+    fn name_separator(&mut self) -> Result<(), Self::Error> {
+        self.encoder.put_str(":")
+    }
+
+    #[inline]
+    fn end_container(&mut self, bracket: &str, _empty: bool) -> Result<(), Self::Error> {
+        self.encoder.put_str(bracket)
+    }
+}
+
+/// A [`JsonEncoder`] that writes every array element and object member on its
+/// own line, indented by its depth.
+///
+/// Indentation defaults to two spaces per level and can be changed with
+/// [`with_indent`](PrettyJson::with_indent). Empty arrays and objects are
+/// written as `[]` and `{}`.
+///
+/// # Example
+///
+/// ```
+/// # #[cfg(feature = "alloc")] {
+/// use encode::Encodable;
+/// use encode::formats::json::{JsonNumber, JsonObject, PrettyJson};
+///
+/// let mut text = String::new();
+/// JsonObject::new([("x", JsonNumber::new(1)), ("y", JsonNumber::new(2))])
+///     .encode(&mut PrettyJson::new(&mut text).with_indent("\t"))
+///     .unwrap();
+/// assert_eq!(text, "{\n\t\"x\": 1,\n\t\"y\": 2\n}");
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct PrettyJson<'a, E> {
+    encoder: &'a mut E,
+    indent: &'a str,
+    depth: usize,
+}
+
+impl<'a, E> PrettyJson<'a, E> {
+    /// Creates a new [`PrettyJson`] that indents with two spaces.
+    #[inline]
+    #[must_use]
+    pub fn new(encoder: &'a mut E) -> Self {
+        Self {
+            encoder,
+            indent: "  ",
+            depth: 0,
+        }
+    }
+    /// Sets the string written once per nesting level at the start of each
+    /// line.
+    #[inline]
+    #[must_use]
+    pub const fn with_indent(mut self, indent: &'a str) -> Self {
+        self.indent = indent;
+        self
+    }
+    /// Returns a reference to the inner encoder.
+    #[inline]
+    #[must_use]
+    pub fn get_ref(&self) -> &E {
+        self.encoder
+    }
+}
+
+impl<E: StrEncoder> PrettyJson<'_, E> {
+    /// Starts a new line at the current depth.
+    fn newline(&mut self) -> Result<(), E::Error> {
+        self.encoder.put_str("\n")?;
+        for _ in 0..self.depth {
+            self.encoder.put_str(self.indent)?;
+        }
+        Ok(())
+    }
+}
+
+impl<E: BaseEncoder> BaseEncoder for PrettyJson<'_, E> {
+    type Error = E::Error;
+}
+
+impl<E: StrEncoder> StrEncoder for PrettyJson<'_, E> {
+    #[inline]
+    fn put_str(&mut self, string: &str) -> Result<(), Self::Error> {
+        self.encoder.put_str(string)
+    }
+}
+
+impl<E: StrEncoder> JsonEncoder for PrettyJson<'_, E> {
+    #[inline]
+    fn begin_container(&mut self, bracket: &str, empty: bool) -> Result<(), Self::Error> {
+        self.encoder.put_str(bracket)?;
+        if !empty {
+            self.depth += 1;
+            self.newline()?;
+        }
+        Ok(())
+    }
+
+    #[inline]
+    fn value_separator(&mut self) -> Result<(), Self::Error> {
+        self.encoder.put_str(",")?;
+        self.newline()
+    }
+
+    #[inline]
+    fn name_separator(&mut self) -> Result<(), Self::Error> {
+        self.encoder.put_str(": ")
+    }
+
+    #[inline]
+    fn end_container(&mut self, bracket: &str, empty: bool) -> Result<(), Self::Error> {
+        if !empty {
+            self.depth = self.depth.saturating_sub(1);
+            self.newline()?;
+        }
+        self.encoder.put_str(bracket)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    const BUF_SIZE: usize = 64;
+
+    /// Writes a two element array holding an empty array and a one element
+    /// object, driving the encoder by hand.
+    fn write_nested<E: JsonEncoder>(encoder: &mut E) -> Result<(), E::Error> {
+        encoder.begin_container("[", false)?;
+        encoder.begin_container("[", true)?;
+        encoder.end_container("]", true)?;
+        encoder.value_separator()?;
+        encoder.begin_container("{", false)?;
+        encoder.put_str("\"a\"")?;
+        encoder.name_separator()?;
+        encoder.put_str("1")?;
+        encoder.end_container("}", false)?;
+        encoder.end_container("]", false)
+    }
+
+    #[test]
+    fn assert_that_compact_json_writes_no_whitespace() {
+        let mut buf = [0u8; BUF_SIZE];
+        let mut encoder = &mut buf as &mut [u8];
+        write_nested(&mut CompactJson::new(&mut encoder)).unwrap();
+        let written = BUF_SIZE - encoder.len();
+
+        assert_eq!(&buf[..written], br#"[[],{"a":1}]"#);
+    }
+
+    #[rstest]
+    #[case::default_indent(None, b"[\n  [],\n  {\n    \"a\": 1\n  }\n]")]
+    #[case::tabs(Some("\t"), b"[\n\t[],\n\t{\n\t\t\"a\": 1\n\t}\n]")]
+    #[case::no_indent(Some(""), b"[\n[],\n{\n\"a\": 1\n}\n]")]
+    fn assert_that_pretty_json_indents_by_depth(
+        #[case] indent: Option<&str>,
+        #[case] expected: &[u8],
+    ) {
+        let mut buf = [0u8; BUF_SIZE];
+        let mut encoder = &mut buf as &mut [u8];
+        let mut pretty = PrettyJson::new(&mut encoder);
+        if let Some(indent) = indent {
+            pretty = pretty.with_indent(indent);
+        }
+        write_nested(&mut pretty).unwrap();
+        let written = BUF_SIZE - encoder.len();
+
+        assert_eq!(&buf[..written], expected);
+    }
+}
@@ -0,0 +1,51 @@
+/// An error that occurred while encoding JSON.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum JsonError<E> {
+    /// The inner encoder failed.
+    Encoder(E),
+    /// A number was `NaN` or infinite, which JSON cannot represent.
+    NonFiniteNumber,
+}
+
+impl<E> From<E> for JsonError<E> {
+    #[inline]
+    fn from(err: E) -> Self {
+        Self::Encoder(err)
+    }
+}
+
+impl<E: core::error::Error + 'static> core::error::Error for JsonError<E> {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match self {
+            Self::Encoder(err) => Some(err),
+            Self::NonFiniteNumber => None,
+        }
+    }
+}
+impl<E: core::fmt::Display> core::fmt::Display for JsonError<E> {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        match self {
+            Self::Encoder(err) => core::fmt::Display::fmt(err, f),
+            Self::NonFiniteNumber => write!(f, "JSON numbers cannot be NaN or infinite"),
+        }
+    }
+}
+
+#[cfg(all(test, feature = "alloc"))]
+mod tests {
+    use alloc::string::ToString;
+
+    use super::*;
+    use crate::encoders::InsufficientSpace;
+
+    #[test]
+    fn assert_that_json_error_displays_correctly() {
+        let err = JsonError::<InsufficientSpace>::NonFiniteNumber;
+        assert_eq!(err.to_string(), "JSON numbers cannot be NaN or infinite");
+        let err = JsonError::from(InsufficientSpace);
+        assert_eq!(
+            err.to_string(),
+            "The provided buffer has no space left for encoding"
+        );
+    }
+}
@@ -0,0 +1,124 @@
+use super::JsonError;
+use crate::StrEncoder;
+
+/// Encodes a [`bool`] as the JSON literal `true` or `false`.
+///
+/// # Example
+///
+/// ```
+/// # #[cfg(feature = "alloc")] {
+/// use encode::Encodable;
+/// use encode::formats::json::JsonBool;
+///
+/// let mut text = String::new();
+/// JsonBool::new(false).encode(&mut text).unwrap();
+/// assert_eq!(text, "false");
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct JsonBool {
+    value: bool,
+}
+
+impl JsonBool {
+    /// Creates a new [`JsonBool`].
+    #[inline]
+    #[must_use]
+    pub const fn new(value: bool) -> Self {
+        Self { value }
+    }
+    /// Consumes the [`JsonBool`] and returns the inner value.
+    #[inline]
+    #[must_use]
+    pub const fn into_inner(self) -> bool {
+        self.value
+    }
+}
+
+impl From<bool> for JsonBool {
+    #[inline]
+    fn from(value: bool) -> Self {
+        Self::new(value)
+    }
+}
+
+impl<Encoder: StrEncoder> crate::Encodable<Encoder> for JsonBool {
+    type Error = JsonError<Encoder::Error>;
+
+    #[inline]
+    fn encode(&self, encoder: &mut Encoder) -> Result<(), Self::Error> {
+        encoder.put_str(if self.value { "true" } else { "false" })?;
+        Ok(())
+    }
+}
+
+/// Encodes the JSON literal `null`.
+///
+/// # Example
+///
+/// ```
+/// # #[cfg(feature = "alloc")] {
+/// use encode::Encodable;
+/// use encode::formats::json::JsonNull;
+///
+/// let mut text = String::new();
+/// JsonNull.encode(&mut text).unwrap();
+/// assert_eq!(text, "null");
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct JsonNull;
+
+impl<Encoder: StrEncoder> crate::Encodable<Encoder> for JsonNull {
+    type Error = JsonError<Encoder::Error>;
+
+    #[inline]
+    fn encode(&self, encoder: &mut Encoder) -> Result<(), Self::Error> {
+        encoder.put_str("null")?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+    use crate::encoders::InsufficientSpace;
+    use crate::Encodable;
+
+    const BUF_SIZE: usize = 8;
+
+    #[rstest]
+    #[case::enabled(true, b"true")]
+    #[case::disabled(false, b"false")]
+    fn assert_that_booleans_are_encoded(#[case] value: bool, #[case] expected: &[u8]) {
+        let mut buf = [0u8; BUF_SIZE];
+        let mut encoder = &mut buf as &mut [u8];
+        JsonBool::from(value).encode(&mut encoder).unwrap();
+        let written = BUF_SIZE - encoder.len();
+
+        assert_eq!(&buf[..written], expected);
+    }
+
+    #[test]
+    fn assert_that_null_is_encoded() {
+        let mut buf = [0u8; BUF_SIZE];
+        let mut encoder = &mut buf as &mut [u8];
+        JsonNull.encode(&mut encoder).unwrap();
+        let written = BUF_SIZE - encoder.len();
+
+        assert_eq!(&buf[..written], b"null");
+    }
+
+    #[test]
+    fn assert_that_encoder_errors_are_propagated() {
+        let mut buf = [0u8; 3];
+        let mut encoder = &mut buf as &mut [u8];
+
+        assert_eq!(
+            JsonNull.encode(&mut encoder),
+            Err(JsonError::Encoder(InsufficientSpace))
+        );
+    }
+}
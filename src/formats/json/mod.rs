@@ -0,0 +1,58 @@
+//! Encodables for [JSON](https://www.rfc-editor.org/rfc/rfc8259) text.
+//!
+//! JSON values are written straight into any [`StrEncoder`](crate::StrEncoder),
+//! so this module works on `no_std` targets without an allocator:
+//!
+//! - [`JsonString`] writes a string with all the required escapes.
+//! - [`JsonNumber`] writes an integer or a finite float, rejecting `NaN` and
+//!   infinities with [`JsonError::NonFiniteNumber`].
+//! - [`JsonBool`] and [`JsonNull`] write the literal values.
+//! - [`JsonArray`] and [`JsonObject`] stream their elements from an iterator
+//!   using the [`Separated`](crate::combinators::Separated) combinator, so
+//!   nothing needs to be collected before encoding.
+//!
+//! Arrays and objects are written into a [`JsonEncoder`], which decides the
+//! whitespace between tokens. [`CompactJson`] writes no whitespace at all,
+//! while [`PrettyJson`] writes one element per line, indented by depth.
+//!
+//! All encodables in this module fail with [`JsonError`], so they can be
+//! freely nested inside each other.
+//!
+//! # Example
+//!
+//! ```
+//! # #[cfg(feature = "alloc")] {
+//! use encode::Encodable;
+//! use encode::formats::json::{CompactJson, JsonArray, JsonNumber, JsonObject, PrettyJson};
+//!
+//! let scores = [("alice", [1, 2]), ("bob", [3, 4])].map(|(name, points)| {
+//!     (name, JsonArray::new(points.map(JsonNumber::new)))
+//! });
+//!
+//! let mut compact = String::new();
+//! JsonObject::new(scores).encode(&mut CompactJson::new(&mut compact)).unwrap();
+//! assert_eq!(compact, r#"{"alice":[1,2],"bob":[3,4]}"#);
+//!
+//! let mut pretty = String::new();
+//! JsonObject::new(scores).encode(&mut PrettyJson::new(&mut pretty)).unwrap();
+//! assert_eq!(pretty, "{\n  \"alice\": [\n    1,\n    2\n  ],\n  \"bob\": [\n    3,\n    4\n  ]\n}");
+//! # }
+//! ```
+
+mod builders;
+mod encoders;
+mod errors;
+mod literals;
+mod number;
+mod string;
+
+pub use builders::JsonArray;
+pub use builders::JsonObject;
+pub use encoders::CompactJson;
+pub use encoders::JsonEncoder;
+pub use encoders::PrettyJson;
+pub use errors::JsonError;
+pub use literals::JsonBool;
+pub use literals::JsonNull;
+pub use number::JsonNumber;
+pub use string::JsonString;
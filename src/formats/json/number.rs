@@ -0,0 +1,158 @@
+use super::JsonError;
+use crate::Encodable;
+use crate::StrEncoder;
+
+/// Encodes a number as a JSON number.
+///
+/// Integers of any width are always valid. Floats are written in their
+/// shortest decimal form that reads back to the same value, without an
+/// exponent, but JSON has no way to represent `NaN` or infinities, so those
+/// fail with [`JsonError::NonFiniteNumber`] instead of producing invalid JSON.
+///
+/// # Example
+///
+/// ```
+/// # #[cfg(feature = "alloc")] {
+/// use encode::Encodable;
+/// use encode::formats::json::{JsonError, JsonNumber};
+///
+/// let mut text = String::new();
+/// JsonNumber::new(-2.5_f64).encode(&mut text).unwrap();
+/// assert_eq!(text, "-2.5");
+///
+/// let result = JsonNumber::new(f64::NAN).encode(&mut text);
+/// assert_eq!(result, Err(JsonError::NonFiniteNumber));
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct JsonNumber<T> {
+    number: T,
+}
+
+impl<T> JsonNumber<T> {
+    /// Creates a new [`JsonNumber`].
+    #[inline]
+    #[must_use]
+    pub const fn new(number: T) -> Self {
+        Self { number }
+    }
+    /// Consumes the [`JsonNumber`] and returns the inner number.
+    #[inline]
+    #[must_use]
+    pub fn into_inner(self) -> T {
+        self.number
+    }
+}
+
+macro_rules! impl_json_number_for_integer {
+    ($($ty:ty),*) => {
+        $(
+            impl<Encoder: StrEncoder> Encodable<Encoder> for JsonNumber<$ty> {
+                type Error = JsonError<Encoder::Error>;
+
+                #[inline]
+                fn encode(&self, encoder: &mut Encoder) -> Result<(), Self::Error> {
+                    format_args!("{}", self.number).encode(encoder)?;
+                    Ok(())
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! impl_json_number_for_float {
+    ($($ty:ty),*) => {
+        $(
+            impl<Encoder: StrEncoder> Encodable<Encoder> for JsonNumber<$ty> {
+                type Error = JsonError<Encoder::Error>;
+
+                #[inline]
+                fn encode(&self, encoder: &mut Encoder) -> Result<(), Self::Error> {
+                    if !self.number.is_finite() {
+                        return Err(JsonError::NonFiniteNumber);
+                    }
+                    format_args!("{}", self.number).encode(encoder)?;
+                    Ok(())
+                }
+            }
+        )*
+    };
+}
+
+impl_json_number_for_integer!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+impl_json_number_for_float!(f32, f64);
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+    use crate::encoders::InsufficientSpace;
+
+    const BUF_SIZE: usize = 64;
+
+    fn encode_number<T>(number: T) -> Result<([u8; BUF_SIZE], usize), JsonError<InsufficientSpace>>
+    where
+        for<'a> JsonNumber<T>: Encodable<&'a mut [u8], Error = JsonError<InsufficientSpace>>,
+    {
+        let mut buf = [0u8; BUF_SIZE];
+        let mut encoder = &mut buf as &mut [u8];
+        JsonNumber::new(number).encode(&mut encoder)?;
+        let written = BUF_SIZE - encoder.len();
+        Ok((buf, written))
+    }
+
+    #[rstest]
+    #[case::zero(0, b"0")]
+    #[case::negative(-42, b"-42")]
+    #[case::min(i64::MIN, b"-9223372036854775808")]
+    #[case::max(i64::MAX, b"9223372036854775807")]
+    fn assert_that_integers_are_encoded(#[case] number: i64, #[case] expected: &[u8]) {
+        let (buf, written) = encode_number(number).unwrap();
+        assert_eq!(&buf[..written], expected);
+    }
+
+    #[test]
+    fn assert_that_wide_integers_are_encoded() {
+        let (buf, written) = encode_number(u128::MAX).unwrap();
+        assert_eq!(&buf[..written], b"340282366920938463463374607431768211455");
+    }
+
+    #[rstest]
+    #[case::integral(42.0, b"42")]
+    #[case::fraction(0.1, b"0.1")]
+    #[case::negative(-2.5, b"-2.5")]
+    #[case::negative_zero(-0.0, b"-0")]
+    #[case::large(1e21, b"1000000000000000000000")]
+    #[case::small(1.5e-7, b"0.00000015")]
+    fn assert_that_finite_floats_are_encoded(#[case] number: f64, #[case] expected: &[u8]) {
+        let (buf, written) = encode_number(number).unwrap();
+        assert_eq!(&buf[..written], expected);
+    }
+
+    #[test]
+    fn assert_that_f32_uses_its_shortest_form() {
+        let (buf, written) = encode_number(0.1_f32).unwrap();
+        assert_eq!(&buf[..written], b"0.1");
+    }
+
+    #[rstest]
+    #[case::nan(f64::NAN, f32::NAN)]
+    #[case::infinity(f64::INFINITY, f32::INFINITY)]
+    #[case::negative_infinity(f64::NEG_INFINITY, f32::NEG_INFINITY)]
+    fn assert_that_non_finite_floats_are_rejected(#[case] double: f64, #[case] single: f32) {
+        assert_eq!(encode_number(double), Err(JsonError::NonFiniteNumber));
+        assert_eq!(encode_number(single), Err(JsonError::NonFiniteNumber));
+    }
+
+    #[test]
+    fn assert_that_encoder_errors_are_propagated() {
+        let mut buf = [0u8; 2];
+        let mut encoder = &mut buf as &mut [u8];
+
+        assert_eq!(
+            JsonNumber::new(1234).encode(&mut encoder),
+            Err(JsonError::Encoder(InsufficientSpace))
+        );
+    }
+}
@@ -0,0 +1,133 @@
+use super::JsonError;
+use crate::StrEncoder;
+
+/// The lowercase hexadecimal digits, used for `\u00XX` escapes.
+const HEX_DIGITS: &[u8; 16] = b"0123456789abcdef";
+
+/// Encodes a string as a quoted JSON string.
+///
+/// Quotes, backslashes and control characters are escaped, using the short
+/// forms such as `\n` where JSON has one and `\u00XX` otherwise. Everything
+/// else is written as is, in runs as long as possible.
+///
+/// # Example
+///
+/// ```
+/// # #[cfg(feature = "alloc")] {
+/// use encode::Encodable;
+/// use encode::formats::json::JsonString;
+///
+/// let mut text = String::new();
+/// JsonString::new("say \"hi\"\n\0").encode(&mut text).unwrap();
+/// assert_eq!(text, r#""say \"hi\"\n\u0000""#);
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct JsonString<S> {
+    string: S,
+}
+
+impl<S> JsonString<S> {
+    /// Creates a new [`JsonString`].
+    #[inline]
+    #[must_use]
+    pub const fn new(string: S) -> Self {
+        Self { string }
+    }
+    /// Consumes the [`JsonString`] and returns the inner string.
+    #[inline]
+    #[must_use]
+    pub fn into_inner(self) -> S {
+        self.string
+    }
+}
+
+impl<S, Encoder> crate::Encodable<Encoder> for JsonString<S>
+where
+    S: AsRef<str>,
+    Encoder: StrEncoder,
+{
+    type Error = JsonError<Encoder::Error>;
+
+    #[inline]
+    fn encode(&self, encoder: &mut Encoder) -> Result<(), Self::Error> {
+        let string = self.string.as_ref();
+        encoder.put_str("\"")?;
+        let mut start = 0;
+        for (i, byte) in string.bytes().enumerate() {
+            let escape = match byte {
+                b'"' => "\\\"",
+                b'\\' => "\\\\",
+                0x08 => "\\b",
+                0x0C => "\\f",
+                b'\n' => "\\n",
+                b'\r' => "\\r",
+                b'\t' => "\\t",
+                0x00..=0x1F => "",
+                _ => continue,
+            };
+            // Escaped bytes are ASCII, so `i` is always a char boundary.
+            encoder.put_str(&string[start..i])?;
+            if escape.is_empty() {
+                let unicode = [
+                    b'\\',
+                    b'u',
+                    b'0',
+                    b'0',
+                    HEX_DIGITS[usize::from(byte >> 4)],
+                    HEX_DIGITS[usize::from(byte & 0x0F)],
+                ];
+                // The escape is ASCII, so this never fails.
+                encoder.put_str(core::str::from_utf8(&unicode).unwrap_or_default())?;
+            } else {
+                encoder.put_str(escape)?;
+            }
+            start = i + 1;
+        }
+        encoder.put_str(&string[start..])?;
+        encoder.put_str("\"")?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+    use crate::encoders::InsufficientSpace;
+    use crate::Encodable;
+
+    const BUF_SIZE: usize = 64;
+
+    #[rstest]
+    #[case::empty("".as_bytes(), b"\"\"")]
+    #[case::plain("Hello, World!".as_bytes(), b"\"Hello, World!\"")]
+    #[case::quotes("a\"b".as_bytes(), br#""a\"b""#)]
+    #[case::backslash("a\\b".as_bytes(), br#""a\\b""#)]
+    #[case::short_escapes("\u{8}\u{c}\n\r\t".as_bytes(), br#""\b\f\n\r\t""#)]
+    #[case::unicode_escapes("\0\u{1}\u{1f}".as_bytes(), br#""\u0000\u0001\u001f""#)]
+    #[case::delete_is_not_escaped("\u{7f}".as_bytes(), b"\"\x7f\"")]
+    #[case::multibyte("ñ€😀\n".as_bytes(), "\"ñ€😀\\n\"".as_bytes())]
+    #[case::escape_at_start("\nab".as_bytes(), br#""\nab""#)]
+    fn assert_that_strings_are_escaped(#[case] string: &[u8], #[case] expected: &[u8]) {
+        let string = core::str::from_utf8(string).unwrap();
+        let mut buf = [0u8; BUF_SIZE];
+        let mut encoder = &mut buf as &mut [u8];
+        JsonString::new(string).encode(&mut encoder).unwrap();
+        let written = BUF_SIZE - encoder.len();
+
+        assert_eq!(&buf[..written], expected);
+    }
+
+    #[test]
+    fn assert_that_encoder_errors_are_propagated() {
+        let mut buf = [0u8; 4];
+        let mut encoder = &mut buf as &mut [u8];
+
+        assert_eq!(
+            JsonString::new("hello").encode(&mut encoder),
+            Err(JsonError::Encoder(InsufficientSpace))
+        );
+    }
+}
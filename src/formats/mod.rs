@@ -0,0 +1,15 @@
+//! Encoders for complete data formats.
+//!
+//! While [`combinators`](crate::combinators) cover the building blocks shared
+//! by many formats, this module provides ready to use implementations of whole
+//! formats. Each format lives in its own module behind a cargo feature of the
+//! same name.
+//!
+//! # Available Formats
+//!
+//! | Module | Feature | Description |
+//! |--------|---------|-------------|
+//! | `json` | `json` | [JSON](https://www.rfc-editor.org/rfc/rfc8259) text in compact or pretty form |
+
+#[cfg(feature = "json")]
+pub mod json;
@@ -21,9 +21,18 @@
 #[cfg(feature = "alloc")]
 extern crate alloc;
 
+pub mod checksums;
 pub mod combinators;
+mod decodables;
+pub mod decoders;
 mod encodables;
 pub mod encoders;
+pub mod formats;
+
+#[cfg(feature = "derive")]
+pub use encode_derive::Encodable;
+
+use core::hash::Hasher;
 
 /// A trait for types that can be encoded into a specific encoder.
 ///
@@ -139,6 +148,85 @@ pub trait ByteEncoder: BaseEncoder {
     fn put_byte(&mut self, byte: u8) -> Result<(), Self::Error>;
 }
 
+/// A trait for encoders that can reserve space and fill it in later.
+///
+/// This trait extends [`ByteEncoder`] for encoders that keep their output
+/// around, such as [`Vec<u8>`] or [`&mut [u8]`](slice). It allows encoding
+/// values whose contents are only known after encoding what follows them,
+/// such as length prefixes, in a single pass.
+///
+/// Reserved bytes are part of the output, and their contents are unspecified
+/// until they are patched.
+///
+/// # Example
+///
+/// ```
+/// # #[cfg(feature = "alloc")] {
+/// use encode::{ByteEncoder, PatchableEncoder};
+///
+/// let mut buf = Vec::new();
+/// let placeholder = buf.reserve_placeholder(1).unwrap();
+/// buf.put_slice(b"hello").unwrap();
+/// let len = buf.written_since(&placeholder) as u8;
+/// buf.patch(placeholder, &[len]).unwrap();
+/// assert_eq!(&buf, b"\x05hello");
+/// # }
+/// ```
+pub trait PatchableEncoder: ByteEncoder {
+    /// A handle to a reserved region of the output.
+    type Placeholder;
+
+    /// Reserves `len` bytes at the current position of the encoder.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the encoder cannot hold `len` more bytes.
+    fn reserve_placeholder(&mut self, len: usize) -> Result<Self::Placeholder, Self::Error>;
+    /// Returns the number of bytes written after the reserved region.
+    fn written_since(&self, placeholder: &Self::Placeholder) -> usize;
+    /// Overwrites the reserved region with the given bytes.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PatchError::SizeMismatch`](encoders::PatchError::SizeMismatch)
+    /// if the length of `bytes` differs from the length of the reserved
+    /// region, or [`PatchError::Encoder`](encoders::PatchError::Encoder) if
+    /// the reserved region cannot be written.
+    fn patch(
+        &mut self,
+        placeholder: Self::Placeholder,
+        bytes: &[u8],
+    ) -> Result<(), encoders::PatchError<Self::Error>>;
+}
+
+/// A trait for encoders that know their position in the output.
+///
+/// Encodables may use this trait as bounds to compute offsets or alignment
+/// relative to the start of the output. Wrap any encoder in a
+/// [`Counting`](encoders::Counting) encoder to keep track of its position.
+pub trait PositionedEncoder: BaseEncoder {
+    /// Returns the number of bytes written into the encoder so far.
+    fn position(&self) -> usize;
+}
+
+/// A trait for encoders that can write individual bits.
+///
+/// This trait extends [`BaseEncoder`] for encoders that are not limited to
+/// whole bytes, such as [`BitEncoder`](encoders::BitEncoder). It is used by
+/// encodables such as [`Bits`](combinators::Bits) to pack fields of arbitrary
+/// bit widths.
+pub trait BitLevelEncoder: BaseEncoder {
+    /// Writes the `n` least significant bits of `value` into the encoder.
+    ///
+    /// If `n` is greater than 64, `value` is zero-extended to `n` bits.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the encoder cannot write the bits due to capacity
+    /// limits, encoding errors, or internal failures.
+    fn put_bits(&mut self, value: u64, n: u32) -> Result<(), Self::Error>;
+}
+
 /// An extension trait for types that can compute the size of their encoded form
 /// using a [`SizeEncoder`].
 ///
@@ -163,6 +251,110 @@ pub trait EncodableSize: Encodable<encoders::SizeEncoder> {
     fn encoded_size(&self) -> Result<usize, Self::Error>;
 }
 
+/// An extension trait for types that can compute a hash of their encoded form
+/// using a [`HashEncoder`].
+///
+/// Use this trait to compute content hashes or cache keys without allocating
+/// the encoded form.
+///
+/// This trait is automatically implemented for all types that implement
+/// [`Encodable`] for [`HashEncoder`].
+///
+/// ## Errors
+///
+/// Returns an error if the [`Encodable`] fails to encode.
+///
+/// [`HashEncoder`]: encoders::HashEncoder
+pub trait EncodableHash<H: Hasher>: Encodable<encoders::HashEncoder<H>> {
+    /// Computes the hash of the encoded representation of `self` with the
+    /// given hasher.
+    ///
+    /// ```
+    /// use encode::EncodableHash;
+    /// use std::collections::hash_map::DefaultHasher;
+    ///
+    /// let a = "hello".encoded_hash(DefaultHasher::new()).unwrap();
+    /// let b = b"hello".encoded_hash(DefaultHasher::new()).unwrap();
+    /// assert_eq!(a, b);
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if encoding fails internally during hashing.
+    fn encoded_hash(&self, hasher: H) -> Result<u64, Self::Error>;
+}
+
+/// A trait for types that can be decoded from a specific decoder.
+///
+/// This is the counterpart of [`Encodable`]. It defines a generic interface
+/// for reading data structures back from a decoder, so that the same crate
+/// can own both directions of a format.
+///
+/// ## A note about purity
+///
+/// The same rules that apply to [`Encodable`] apply to `Decodable`:
+/// implementations must be free of side effects, deterministic and must never
+/// panic, even when the input is malformed.
+///
+/// ## Errors
+///
+/// Implementations must return an appropriate error if decoding fails. Errors
+/// can occur if:
+/// - The decoder encounters an internal error (e.g., the input ended early).
+/// - The input is not a valid encoding of `Self`.
+pub trait Decodable<D>: Sized
+where
+    D: BaseDecoder,
+{
+    /// The error type returned by the `decode` method.
+    ///
+    /// This must include the decoder's error type via `From<D::Error>`.
+    type Error: From<D::Error>;
+
+    /// Decodes a value of type `Self` from the given decoder.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if reading fails or if the input is not a valid
+    /// encoding of `Self`.
+    fn decode(decoder: &mut D) -> Result<Self, Self::Error>;
+}
+
+/// A trait that defines common types and operations for decoders.
+///
+/// A decoder is a type that provides the input consumed by [`Decodable`]
+/// types. It is the counterpart of [`BaseEncoder`].
+pub trait BaseDecoder {
+    /// The error type returned by all decoding operations.
+    ///
+    /// For example, a decoder might return an error if the input ends before
+    /// the requested amount of bytes could be read.
+    type Error;
+}
+
+/// A trait for decoders that read from a borrowed sequence of bytes.
+///
+/// This trait extends [`BaseDecoder`] with the operations used by
+/// [`Decodable`] implementations for consuming bytes. The `'de` lifetime is
+/// the lifetime of the input, which allows decoding borrowed values such as
+/// [`&[u8]`](slice) or [`&CStr`](core::ffi::CStr) without copying.
+pub trait ByteDecoder<'de>: BaseDecoder {
+    /// Reads the next `len` bytes from the decoder.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the decoder cannot provide `len` more bytes.
+    fn take_slice(&mut self, len: usize) -> Result<&'de [u8], Self::Error>;
+    /// Reads the next byte from the decoder.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the decoder has no bytes left.
+    fn take_byte(&mut self) -> Result<u8, Self::Error>;
+    /// Returns the bytes that have not been read yet, without consuming them.
+    fn peek(&self) -> &'de [u8];
+}
+
 impl<T> StrEncoder for T
 where
     T: ByteEncoder,
@@ -185,6 +377,19 @@ where
     }
 }
 
+impl<T, H> EncodableHash<H> for T
+where
+    T: Encodable<encoders::HashEncoder<H>>,
+    H: Hasher,
+{
+    #[inline]
+    fn encoded_hash(&self, hasher: H) -> Result<u64, Self::Error> {
+        let mut encoder = encoders::HashEncoder::new(hasher);
+        self.encode(&mut encoder)?;
+        Ok(encoder.finish())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -194,4 +399,15 @@ mod tests {
         let size = "hello".encoded_size().unwrap();
         assert_eq!(size, 5);
     }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn assert_that_encoded_hash_hashes_the_encoded_bytes() {
+        use std::collections::hash_map::DefaultHasher;
+
+        let mut hasher = DefaultHasher::new();
+        hasher.write(b"hello");
+        let hash = "hello".encoded_hash(DefaultHasher::new()).unwrap();
+        assert_eq!(hash, hasher.finish());
+    }
 }